@@ -0,0 +1,121 @@
+// cargo bench --bench gemm_bench
+//
+// naive: the previous triple loop with idxr / idxc fn pointers
+// gemm: packed and tiled kernel behind Array::gemm and mmult
+// small: per call time of tiny shapes, where packing overhead shows
+
+use std::hint::black_box;
+use std::ops::{AddAssign, Mul};
+use std::time::Instant;
+
+use simple_blas::array::{Array, Transpose};
+
+fn idxr(r: usize, c: usize, dim: (usize, usize)) -> usize {
+    r * dim.1 + c
+}
+
+fn idxc(r: usize, c: usize, dim: (usize, usize)) -> usize {
+    c * dim.0 + r
+}
+
+fn naive<T>(res: &mut [T], m1: &[T], m2: &[T], new_dim: (usize, usize), ni: usize, by_row_m1: bool, by_row_m2: bool)
+where T: Copy + Default + AddAssign + Mul<Output=T>
+{
+    let index1: fn(usize, usize, (usize, usize)) -> usize = if by_row_m1 {idxr} else {idxc};
+    let index2: fn(usize, usize, (usize, usize)) -> usize = if by_row_m2 {idxr} else {idxc};
+
+    for r in 0..new_dim.0 {
+        for c in 0..new_dim.1 {
+            let mut sum = T::default();
+            for i in 0..ni {
+                sum += m1[index1(r, i, (new_dim.0, ni))] * m2[index2(i, c, (ni, new_dim.1))];
+            }
+            res[idxr(r, c, new_dim)] = sum;
+        }
+    }
+}
+
+// best of a few runs, in seconds
+fn time(runs: usize, mut f: impl FnMut()) -> f64 {
+    let mut best: f64 = f64::MAX;
+    for _ in 0..runs {
+        let start = Instant::now();
+        f();
+        best = best.min(start.elapsed().as_secs_f64());
+    }
+    best
+}
+
+macro_rules! bench_type {
+    ($t:ty) => {
+        for n in [64_usize, 128, 256, 512] {
+            let flop: f64 = 2.0 * (n * n * n) as f64;
+            let runs: usize = if n <= 128 {10} else {3};
+            let x: Vec<$t> = (0..n * n).map(|i| ((i % 17) as $t) * 0.25 - 2.0).collect();
+
+            for (by_row_a, by_row_b) in [(true, true), (true, false), (false, true), (false, false)] {
+                let a: Array<$t> = Array::new_array_2d(x.clone().into_boxed_slice(), (n as isize, n as isize), by_row_a).unwrap();
+                let b: Array<$t> = Array::new_array_2d(x.clone().into_boxed_slice(), (n as isize, n as isize), by_row_b).unwrap();
+                let mut c: Array<$t> = Array::new_array_2d(vec![0.0; n * n].into_boxed_slice(), (n as isize, n as isize), true).unwrap();
+
+                let mut res: Vec<$t> = vec![0.0; n * n];
+                let t_naive = time(runs, || {
+                    naive(&mut res, black_box(&x), black_box(&x), (n, n), n, by_row_a, by_row_b);
+                    black_box(&res);
+                });
+
+                let t_gemm = time(runs, || {
+                    Array::gemm(1.0, black_box(&a), Transpose::NoTrans, black_box(&b), Transpose::NoTrans, 0.0, &mut c).unwrap();
+                    black_box(&c);
+                });
+
+                println!(
+                    "{:>4} n={:<4} a_by_row={:<5} b_by_row={:<5} naive {:>7.2} GFLOP/s  gemm {:>7.2} GFLOP/s  x{:.1}",
+                    stringify!($t), n, by_row_a, by_row_b,
+                    flop / t_naive / 1e9, flop / t_gemm / 1e9, t_naive / t_gemm
+                );
+            }
+        }
+    };
+}
+
+macro_rules! bench_small {
+    ($t:ty) => {
+        for (m, k, n) in [(2_usize, 2_usize, 2_usize), (3, 3, 3), (4, 4, 4), (8, 8, 8), (16, 16, 16), (1, 64, 1), (64, 1, 64)] {
+            let calls: usize = 10_000;
+            let a_x: Vec<$t> = (0..m * k).map(|i| ((i % 17) as $t) * 0.25 - 2.0).collect();
+            let b_x: Vec<$t> = (0..k * n).map(|i| ((i % 13) as $t) * 0.5 - 1.0).collect();
+            let a: Array<$t> = Array::new_array_2d(a_x.clone().into_boxed_slice(), (m as isize, k as isize), true).unwrap();
+            let b: Array<$t> = Array::new_array_2d(b_x.clone().into_boxed_slice(), (k as isize, n as isize), true).unwrap();
+            let mut c: Array<$t> = Array::new_array_2d(vec![0.0; m * n].into_boxed_slice(), (m as isize, n as isize), true).unwrap();
+
+            let mut res: Vec<$t> = vec![0.0; m * n];
+            let t_naive = time(3, || {
+                for _ in 0..calls {
+                    naive(&mut res, black_box(&a_x), black_box(&b_x), (m, n), k, true, true);
+                    black_box(&res);
+                }
+            });
+
+            let t_gemm = time(3, || {
+                for _ in 0..calls {
+                    Array::gemm(1.0, black_box(&a), Transpose::NoTrans, black_box(&b), Transpose::NoTrans, 0.0, &mut c).unwrap();
+                    black_box(&c);
+                }
+            });
+
+            println!(
+                "{:>4} small {}x{}x{:<3} naive {:>9.1} ns/call  gemm {:>9.1} ns/call  x{:.2}",
+                stringify!($t), m, k, n,
+                t_naive / calls as f64 * 1e9, t_gemm / calls as f64 * 1e9, t_naive / t_gemm
+            );
+        }
+    };
+}
+
+fn main() {
+    bench_small!(f32);
+    bench_small!(f64);
+    bench_type!(f32);
+    bench_type!(f64);
+}
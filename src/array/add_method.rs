@@ -33,16 +33,28 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
         Ok(())
     }
 
+    /// element-wise add with numpy broadcasting,
+    /// Scalar is 1 x 1 and Array1D is a 1 x n row,
+    /// every axis must be equal or 1
+    /// ```
+    /// use simple_blas::array::Array;
+    ///
+    /// let mut a = Array::new_array_2d(Box::new([1, 2, 3, 4]), (2, 2), true).unwrap();
+    ///
+    /// // add the row to every row
+    /// a.madd(&Array::new_array_1d(Box::new([10, 20]))).unwrap();
+    /// assert_eq!(a, Array::new_array_2d(Box::new([11, 22, 13, 24]), (2, 2), true).unwrap());
+    /// ```
     pub fn madd(&mut self, other: &Self) -> Result<(), ListError> {
         match (self, other) {
             (Array::Array1D {arr: arr1, ..}, 
-             Array::Array1D {arr: other, ..}) => {
+             Array::Array1D {arr: other, ..}) if arr1.len() == other.len() => {
                 Array::self_add_vec_v2(arr1, other)?;
             }, 
 
             (Array::Array2D { arr: arr1, nr: nr1, nc:nc1, put_val_by_row: by_row1 },
              Array::Array2D { arr: arr2, nr: nr2, nc:nc2, put_val_by_row: by_row2, ..})
-            => {
+            if (*nr1, *nc1) == (*nr2, *nc2) => {
                 let dim1: (usize, usize) = (*nr1, *nc1);
                 let dim2: (usize, usize) = (*nr2, *nc2);
                 
@@ -52,7 +64,8 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
                 Array::self_add_mat_m(arr1, arr2, dim1, dim2, idx1, idx2)?;
             },
             
-            _ => return Err(ListError::MismatchedTypes),
+            // broadcast other shapes
+            (arr1, other) => arr1.broadcast_op(other, |x, y| {*x += y; Ok(())})?,
         }
 
         Ok(())
@@ -0,0 +1,368 @@
+// BLAS level 1, vector vector operations
+//
+// the routines work on strided views of Array1D,
+// element i of a view is arr[offset + i * inc],
+// a negative inc walks the elements backwards as in BLAS:
+//  element i is arr[offset + (n - 1 - i) * |inc|]
+
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
+
+use super::matrix_operations::Sqrt;
+use super::{Array, ListError};
+
+/// ## Strided view of an Array1D
+/// n elements from offset with step inc,
+/// inc may be zero (one element repeated) or negative (backwards)
+/// ```
+/// use simple_blas::array::Array;
+///
+/// let x = Array::new_array_1d(Box::new([1.0, 9.0, 2.0, 9.0, 3.0]));
+///
+/// // [1, 2, 3] and [3, 2, 1]
+/// let even = x.vec_view(3, 0, 2).unwrap();
+/// let back = x.vec_view(3, 0, -2).unwrap();
+/// assert_eq!(even.dot(&back).unwrap(), 10.0);
+/// assert_eq!(back.get(0), 3.0);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct VecView<'a, T> {
+    arr: &'a [T],
+    n: usize,
+    offset: usize,
+    inc: isize,
+}
+
+/// ## Mutable strided view of an Array1D
+/// same as VecView, inc must not be zero
+/// ```
+/// use simple_blas::array::Array;
+///
+/// let x = Array::new_array_1d(Box::new([1.0, 1.0]));
+/// let mut y = Array::new_array_1d(Box::new([0.0, 5.0, 0.0, 5.0]));
+///
+/// // y[0], y[2] += 2 x
+/// y.vec_view_mut(2, 0, 2).unwrap().axpy(2.0, &x.vec_view(2, 0, 1).unwrap()).unwrap();
+/// assert_eq!(y, Array::new_array_1d(Box::new([2.0, 5.0, 2.0, 5.0])));
+/// ```
+#[derive(Debug)]
+pub struct VecViewMut<'a, T> {
+    arr: &'a mut [T],
+    n: usize,
+    offset: usize,
+    inc: isize,
+}
+
+// position of element i in the buffer
+fn vec_pos(i: usize, n: usize, offset: usize, inc: isize) -> usize {
+    let step: usize = inc.unsigned_abs();
+    if inc < 0 {offset + (n - 1 - i) * step} else {offset + i * step}
+}
+
+// same length, reported like a broadcasting mismatch
+fn check_len(n1: usize, n2: usize) -> Result<(), ListError> {
+    if n1 != n2 {return Err(ListError::ShapeMismatch { lhs: vec![n1], rhs: vec![n2] });}
+    Ok(())
+}
+
+// every element of the view is in a buffer of length len
+fn check_stride(len: usize, n: usize, offset: usize, inc: isize) -> Result<(), ListError> {
+    if n == 0 {return Ok(());}
+    let last: usize = offset + (n - 1) * inc.unsigned_abs();
+    if last >= len {return Err(ListError::InvalidStride);}
+    Ok(())
+}
+
+impl<'a, T: Copy> VecView<'a, T> {
+    // view checked by the caller
+    pub(crate) fn from_parts(arr: &'a [T], n: usize, offset: usize, inc: isize) -> Self {
+        VecView { arr, n, offset, inc }
+    }
+
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// element i of the view
+    pub fn get(&self, i: usize) -> T {
+        self.arr[vec_pos(i, self.n, self.offset, self.inc)]
+    }
+
+    fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        (0..self.n).map(|i| self.get(i))
+    }
+}
+
+impl<'a, T: Copy> VecViewMut<'a, T> {
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// element i of the view
+    pub fn get(&self, i: usize) -> T {
+        self.arr[vec_pos(i, self.n, self.offset, self.inc)]
+    }
+
+    /// read only view of the same elements
+    pub fn view(&self) -> VecView<'_, T> {
+        VecView { arr: self.arr, n: self.n, offset: self.offset, inc: self.inc }
+    }
+
+    fn get_mut(&mut self, i: usize) -> &mut T {
+        &mut self.arr[vec_pos(i, self.n, self.offset, self.inc)]
+    }
+}
+
+impl<'a, T> VecView<'a, T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
++ Sub<Output=T> + PartialOrd
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default
+{
+    /// x' y
+    pub fn dot(&self, y: &VecView<'_, T>) -> Result<T, ListError> {
+        check_len(self.n, y.n)?;
+        let mut sum: T = T::default();
+        for i in 0..self.n {
+            sum += self.get(i) * y.get(i);
+        }
+        Ok(sum)
+    }
+
+    /// sum |x_i|
+    pub fn asum(&self) -> T {
+        let z: T = T::default();
+        let mut sum: T = z;
+        for val in self.iter() {
+            sum += Array::abs(val, z);
+        }
+        sum
+    }
+
+    /// first i with the largest |x_i|, None for empty view
+    pub fn iamax(&self) -> Option<usize> {
+        let z: T = T::default();
+        let mut res: Option<(usize, T)> = None;
+        for (i, val) in self.iter().enumerate() {
+            let a: T = Array::abs(val, z);
+            match res {
+                Some((_, maxv)) if a <= maxv => {},
+                _ => res = Some((i, a)),
+            }
+        }
+        res.map(|(i, _)| i)
+    }
+}
+
+impl<'a, T> VecView<'a, T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T> + Sub<Output=T>
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default + From<f32> + PartialOrd + Sqrt
+{
+    /// ||x||_2 with scaling, no overflow for large elements
+    pub fn nrm2(&self) -> T {
+        let v: Vec<T> = self.iter().collect();
+        Array::norm_2_scaled(&v)
+    }
+}
+
+impl<'a, T> VecViewMut<'a, T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
++ Sub<Output=T> + PartialOrd
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default
+{
+    /// y = alpha x + y, self is y
+    pub fn axpy(&mut self, alpha: T, x: &VecView<'_, T>) -> Result<(), ListError> {
+        check_len(self.n, x.n)?;
+        for i in 0..self.n {
+            *self.get_mut(i) += alpha * x.get(i);
+        }
+        Ok(())
+    }
+
+    /// x = alpha x, self is x
+    pub fn scal(&mut self, alpha: T) {
+        for i in 0..self.n {
+            *self.get_mut(i) *= alpha;
+        }
+    }
+
+    /// y = x, self is y
+    pub fn copy(&mut self, x: &VecView<'_, T>) -> Result<(), ListError> {
+        check_len(self.n, x.n)?;
+        for i in 0..self.n {
+            *self.get_mut(i) = x.get(i);
+        }
+        Ok(())
+    }
+
+    /// x <-> y, self is x
+    pub fn swap(&mut self, y: &mut VecViewMut<'_, T>) -> Result<(), ListError> {
+        check_len(self.n, y.n)?;
+        for i in 0..self.n {
+            std::mem::swap(self.get_mut(i), y.get_mut(i));
+        }
+        Ok(())
+    }
+
+    /// plane rotation, self is x
+    ///  x_i = c x_i + s y_i
+    ///  y_i = c y_i - s x_i
+    pub fn rot(&mut self, y: &mut VecViewMut<'_, T>, c: T, s: T) -> Result<(), ListError> {
+        check_len(self.n, y.n)?;
+        for i in 0..self.n {
+            let (xi, yi) = (self.get(i), y.get(i));
+            *self.get_mut(i) = c * xi + s * yi;
+            *y.get_mut(i) = c * yi - s * xi;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Array<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
++ Sub<Output=T> + PartialOrd
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default
+{
+    /// strided view of an Array1D,
+    /// n elements from offset with step inc
+    pub fn vec_view(&self, n: usize, offset: usize, inc: isize) -> Result<VecView<'_, T>, ListError> {
+        match self {
+            Array::Array1D { arr } => {
+                check_stride(arr.len(), n, offset, inc)?;
+                Ok(VecView { arr, n, offset, inc })
+            },
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+
+    /// mutable strided view of an Array1D, inc must not be zero
+    pub fn vec_view_mut(&mut self, n: usize, offset: usize, inc: isize) -> Result<VecViewMut<'_, T>, ListError> {
+        match self {
+            Array::Array1D { arr } => {
+                if inc == 0 && n > 1 {return Err(ListError::InvalidStride);}
+                check_stride(arr.len(), n, offset, inc)?;
+                Ok(VecViewMut { arr, n, offset, inc })
+            },
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+
+    // whole Array1D as views
+    fn full_view(&self) -> Result<VecView<'_, T>, ListError> {
+        let n: usize = match self {
+            Array::Array1D { arr } => arr.len(),
+            _ => return Err(ListError::MismatchedTypes),
+        };
+        self.vec_view(n, 0, 1)
+    }
+
+    fn full_view_mut(&mut self) -> Result<VecViewMut<'_, T>, ListError> {
+        let n: usize = match self {
+            Array::Array1D { arr } => arr.len(),
+            _ => return Err(ListError::MismatchedTypes),
+        };
+        self.vec_view_mut(n, 0, 1)
+    }
+
+    /// x' y of two Array1D,
+    /// mdot is the matrix product
+    /// ```
+    /// use simple_blas::array::Array;
+    ///
+    /// let x = Array::new_array_1d(Box::new([1, 2, 3]));
+    /// let y = Array::new_array_1d(Box::new([4, 5, 6]));
+    /// assert_eq!(x.dot(&y).unwrap(), 32);
+    /// ```
+    pub fn dot(&self, y: &Self) -> Result<T, ListError> {
+        self.full_view()?.dot(&y.full_view()?)
+    }
+
+    /// self = alpha x + self
+    pub fn axpy(&mut self, alpha: T, x: &Self) -> Result<(), ListError> {
+        self.full_view_mut()?.axpy(alpha, &x.full_view()?)
+    }
+
+    /// self = alpha self
+    pub fn scal(&mut self, alpha: T) -> Result<(), ListError> {
+        self.full_view_mut()?.scal(alpha);
+        Ok(())
+    }
+
+    /// sum |x_i|
+    pub fn asum(&self) -> Result<T, ListError> {
+        Ok(self.full_view()?.asum())
+    }
+
+    /// first i with the largest |x_i|, None for empty Array1D
+    pub fn iamax(&self) -> Result<Option<usize>, ListError> {
+        Ok(self.full_view()?.iamax())
+    }
+
+    /// self = x
+    pub fn copy(&mut self, x: &Self) -> Result<(), ListError> {
+        self.full_view_mut()?.copy(&x.full_view()?)
+    }
+
+    /// self <-> y
+    pub fn swap(&mut self, y: &mut Self) -> Result<(), ListError> {
+        self.full_view_mut()?.swap(&mut y.full_view_mut()?)
+    }
+
+    /// plane rotation of self and y,
+    /// (self_i, y_i) = (c self_i + s y_i, c y_i - s self_i)
+    pub fn rot(&mut self, y: &mut Self, c: T, s: T) -> Result<(), ListError> {
+        self.full_view_mut()?.rot(&mut y.full_view_mut()?, c, s)
+    }
+}
+
+impl<T> Array<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T> + Sub<Output=T>
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default + From<f32> + PartialOrd + Sqrt
+{
+    /// ||x||_2 with scaling, no overflow for large elements
+    pub fn nrm2(&self) -> Result<T, ListError> {
+        Ok(self.full_view()?.nrm2())
+    }
+
+    /// givens rotation which zeros b,
+    /// return (r, z, c, s) as BLAS rotg:
+    ///  | c  s | | a |   | r |
+    ///  | -s c | | b | = | 0 |
+    /// z is 1 / c when |b| >= |a| and c != 0, s otherwise
+    /// ```
+    /// use simple_blas::array::Array;
+    ///
+    /// let (r, _z, c, s) = Array::rotg(3.0, 4.0);
+    /// assert!((r - 5.0_f64).abs() < 1e-12);
+    /// assert!((c - 0.6).abs() < 1e-12 && (s - 0.8).abs() < 1e-12);
+    /// ```
+    pub fn rotg(a: T, b: T) -> (T, T, T, T) {
+        let z: T = T::default();
+        let one: T = T::from(1.0_f32);
+        let (abs_a, abs_b) = (Array::abs(a, z), Array::abs(b, z));
+
+        if abs_a == z && abs_b == z {return (z, z, one, z);}
+
+        // r takes the sign of the larger one
+        let roe: T = if abs_a > abs_b {a} else {b};
+        let mut r: T = Array::pythag(a, b);
+        if roe < z {r = z - r;}
+
+        let c: T = a / r;
+        let s: T = b / r;
+        let zr: T = if abs_a > abs_b {s} else if c != z {one / c} else {one};
+
+        (r, zr, c, s)
+    }
+}
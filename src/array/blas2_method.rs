@@ -0,0 +1,303 @@
+// BLAS level 2, matrix vector operations
+//
+// matrices are read through Strided, so both
+// put_val_by_row layouts and op(A) = A' need no copy,
+// length conflicts are ShapeMismatch like level 1
+
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
+
+use super::gemm_method::{Strided, Transpose};
+use super::{Array, ListError};
+
+/// ## Triangle of a matrix used by BLAS routines
+/// -1 Upper: A[r, c] with r <= c
+///
+/// -2 Lower: A[r, c] with r >= c
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Uplo {
+    Upper,
+    Lower,
+}
+
+/// ## Diagonal of a triangular matrix
+/// -1 NonUnit: diagonal is read from the matrix
+///
+/// -2 Unit: diagonal is taken as 1 and not read
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Diag {
+    NonUnit,
+    Unit,
+}
+
+impl Uplo {
+    // triangle of op(A)
+    pub(crate) fn op(self, trans: Transpose) -> Self {
+        match (self, trans) {
+            (uplo, Transpose::NoTrans) => uplo,
+            (Uplo::Upper, Transpose::Trans) => Uplo::Lower,
+            (Uplo::Lower, Transpose::Trans) => Uplo::Upper,
+        }
+    }
+}
+
+impl<T> Array<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
++ Sub<Output=T> + PartialOrd
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default
+{
+    /// y = alpha op(A) x + beta y, x and y are Array1D,
+    /// when beta is zero y is not read
+    /// ```
+    /// use simple_blas::array::{Array, Transpose};
+    ///
+    /// let a = Array::new_array_2d(Box::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]), (2, 3), true).unwrap();
+    /// let x = Array::new_array_1d(Box::new([1.0, 1.0]));
+    /// let mut y = Array::new_array_1d(Box::new([1.0, 1.0, 1.0]));
+    ///
+    /// // y = A' x - y
+    /// Array::gemv(1.0, &a, Transpose::Trans, &x, -1.0, &mut y).unwrap();
+    /// assert_eq!(y, Array::new_array_1d(Box::new([4.0, 6.0, 8.0])));
+    /// ```
+    pub fn gemv(
+        alpha: T,
+        a: &Self, trans: Transpose,
+        x: &Self,
+        beta: T,
+        y: &mut Self,
+    ) -> Result<(), ListError>
+    {
+        match (a, x, y) {
+            (Array::Array2D { arr, nr, nc, put_val_by_row },
+             Array::Array1D { arr: vx },
+             Array::Array1D { arr: vy })
+            => {
+                let sa: Strided = Strided::new(*nr, *nc, *put_val_by_row).op(trans);
+                if sa.nc != vx.len() {
+                    return Err(ListError::ShapeMismatch { lhs: vec![sa.nr, sa.nc], rhs: vec![vx.len()] });
+                }
+                if sa.nr != vy.len() {
+                    return Err(ListError::ShapeMismatch { lhs: vec![sa.nr, sa.nc], rhs: vec![vy.len()] });
+                }
+
+                Array::gemv_kernel(alpha, arr, sa, vx, beta, vy);
+                Ok(())
+            },
+
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+
+    /// A = alpha x y' + A, x and y are Array1D
+    /// ```
+    /// use simple_blas::array::Array;
+    ///
+    /// let x = Array::new_array_1d(Box::new([1.0, 2.0]));
+    /// let y = Array::new_array_1d(Box::new([3.0, 4.0]));
+    /// let mut a = Array::new_array_2d(Box::new([1.0, 0.0, 0.0, 1.0]), (2, 2), false).unwrap();
+    ///
+    /// Array::ger(1.0, &x, &y, &mut a).unwrap();
+    /// assert_eq!(a, Array::new_array_2d(Box::new([4.0, 4.0, 6.0, 9.0]), (2, 2), true).unwrap());
+    /// ```
+    pub fn ger(alpha: T, x: &Self, y: &Self, a: &mut Self) -> Result<(), ListError> {
+        match (x, y, a) {
+            (Array::Array1D { arr: vx },
+             Array::Array1D { arr: vy },
+             Array::Array2D { arr, nr, nc, put_val_by_row })
+            => {
+                // x y' is vx.len() x vy.len()
+                if (*nr, *nc) != (vx.len(), vy.len()) {
+                    return Err(ListError::ShapeMismatch { lhs: vec![*nr, *nc], rhs: vec![vx.len(), vy.len()] });
+                }
+                let sa: Strided = Strided::new(*nr, *nc, *put_val_by_row);
+
+                for c in 0..sa.nc {
+                    let t: T = alpha * vy[c];
+                    for r in 0..sa.nr {
+                        arr[r * sa.rs + c * sa.cs] += vx[r] * t;
+                    }
+                }
+                Ok(())
+            },
+
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+
+    /// x = op(A) x, A is triangular, only the uplo triangle is read
+    /// ```
+    /// use simple_blas::array::{Array, Diag, Transpose, Uplo};
+    ///
+    /// // [[2, 1], [*, 3]]
+    /// let a = Array::new_array_2d(Box::new([2.0, 1.0, 9.0, 3.0]), (2, 2), true).unwrap();
+    /// let mut x = Array::new_array_1d(Box::new([1.0, 1.0]));
+    ///
+    /// Array::trmv(Uplo::Upper, Transpose::NoTrans, Diag::NonUnit, &a, &mut x).unwrap();
+    /// assert_eq!(x, Array::new_array_1d(Box::new([3.0, 3.0])));
+    /// ```
+    pub fn trmv(
+        uplo: Uplo, trans: Transpose, diag: Diag,
+        a: &Self, x: &mut Self,
+    ) -> Result<(), ListError>
+    {
+        let (arr, sa, vx) = Array::tri_operands(trans, a, x)?;
+        let n: usize = sa.nr;
+        let at = |r: usize, c: usize| arr[r * sa.rs + c * sa.cs];
+
+        match uplo.op(trans) {
+            // x_i = sum_{j >= i} A_ij x_j, x_j with j > i is not written yet
+            Uplo::Upper => {
+                for i in 0..n {
+                    let mut sum: T = if diag == Diag::Unit {vx[i]} else {at(i, i) * vx[i]};
+                    for (j, &xj) in vx.iter().enumerate().skip(i + 1) {sum += at(i, j) * xj;}
+                    vx[i] = sum;
+                }
+            },
+            // x_i = sum_{j <= i} A_ij x_j, from the bottom
+            Uplo::Lower => {
+                for i in (0..n).rev() {
+                    let mut sum: T = if diag == Diag::Unit {vx[i]} else {at(i, i) * vx[i]};
+                    for (j, &xj) in vx[..i].iter().enumerate() {sum += at(i, j) * xj;}
+                    vx[i] = sum;
+                }
+            },
+        }
+
+        Ok(())
+    }
+
+    /// solve op(A) x = b, A is triangular, x is b on entry,
+    /// only the uplo triangle is read
+    /// ```
+    /// use simple_blas::array::{Array, Diag, Transpose, Uplo};
+    ///
+    /// // [[2, 0], [1, 4]] by column
+    /// let a = Array::new_array_2d(Box::new([2.0, 1.0, 0.0, 4.0]), (2, 2), false).unwrap();
+    /// let mut x = Array::new_array_1d(Box::new([2.0, 9.0]));
+    ///
+    /// Array::trsv(Uplo::Lower, Transpose::NoTrans, Diag::NonUnit, &a, &mut x).unwrap();
+    /// assert_eq!(x, Array::new_array_1d(Box::new([1.0, 2.0])));
+    /// ```
+    pub fn trsv(
+        uplo: Uplo, trans: Transpose, diag: Diag,
+        a: &Self, x: &mut Self,
+    ) -> Result<(), ListError>
+    {
+        let (arr, sa, vx) = Array::tri_operands(trans, a, x)?;
+        let n: usize = sa.nr;
+        let z: T = T::default();
+        let at = |r: usize, c: usize| arr[r * sa.rs + c * sa.cs];
+
+        if diag == Diag::NonUnit && (0..n).any(|i| at(i, i) == z) {
+            return Err(ListError::SingularMat);
+        }
+
+        match uplo.op(trans) {
+            // back substitution
+            Uplo::Upper => {
+                for i in (0..n).rev() {
+                    let mut sum: T = vx[i];
+                    for (j, &xj) in vx.iter().enumerate().skip(i + 1) {sum -= at(i, j) * xj;}
+                    vx[i] = if diag == Diag::Unit {sum} else {sum / at(i, i)};
+                }
+            },
+            // forward substitution
+            Uplo::Lower => {
+                for i in 0..n {
+                    let mut sum: T = vx[i];
+                    for (j, &xj) in vx[..i].iter().enumerate() {sum -= at(i, j) * xj;}
+                    vx[i] = if diag == Diag::Unit {sum} else {sum / at(i, i)};
+                }
+            },
+        }
+
+        Ok(())
+    }
+
+    /// y = alpha A x + beta y, A is symmetric,
+    /// only the uplo triangle is read
+    /// ```
+    /// use simple_blas::array::{Array, Uplo};
+    ///
+    /// // [[1, 2], [2, 3]] from the upper triangle
+    /// let a = Array::new_array_2d(Box::new([1.0, 2.0, 9.0, 3.0]), (2, 2), true).unwrap();
+    /// let x = Array::new_array_1d(Box::new([1.0, 1.0]));
+    /// let mut y = Array::new_array_1d(Box::new([0.0, 0.0]));
+    ///
+    /// Array::symv(Uplo::Upper, 1.0, &a, &x, 0.0, &mut y).unwrap();
+    /// assert_eq!(y, Array::new_array_1d(Box::new([3.0, 5.0])));
+    /// ```
+    pub fn symv(
+        uplo: Uplo,
+        alpha: T,
+        a: &Self,
+        x: &Self,
+        beta: T,
+        y: &mut Self,
+    ) -> Result<(), ListError>
+    {
+        match (a, x, y) {
+            (Array::Array2D { arr, nr, nc, put_val_by_row },
+             Array::Array1D { arr: vx },
+             Array::Array1D { arr: vy })
+            => {
+                if *nr != *nc {return Err(ListError::NotSquareMat);}
+                let n: usize = *nr;
+                for len in [vx.len(), vy.len()] {
+                    if len != n {return Err(ListError::ShapeMismatch { lhs: vec![n, n], rhs: vec![len] });}
+                }
+
+                let sa: Strided = Strided::new(n, n, *put_val_by_row);
+                // A[r, c] from the stored triangle
+                let at = |r: usize, c: usize| {
+                    let (r, c) = match uplo {
+                        Uplo::Upper if r > c => (c, r),
+                        Uplo::Lower if r < c => (c, r),
+                        _ => (r, c),
+                    };
+                    arr[r * sa.rs + c * sa.cs]
+                };
+
+                let z: T = T::default();
+                for i in 0..n {
+                    let mut sum: T = z;
+                    for j in 0..n {sum += at(i, j) * vx[j];}
+                    vy[i] = if beta == z {alpha * sum} else {alpha * sum + beta * vy[i]};
+                }
+                Ok(())
+            },
+
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+
+    // y = alpha A x + beta y on a strided A
+    pub(crate) fn gemv_kernel(alpha: T, a: &[T], sa: Strided, x: &[T], beta: T, y: &mut [T]) {
+        let z: T = T::default();
+        for r in 0..sa.nr {
+            let mut sum: T = z;
+            for c in 0..sa.nc {
+                sum += a[r * sa.rs + c * sa.cs] * x[c];
+            }
+            y[r] = if beta == z {alpha * sum} else {alpha * sum + beta * y[r]};
+        }
+    }
+
+    // square A and Array1D x of the same length for trmv, trsv
+    fn tri_operands<'a>(
+        trans: Transpose, a: &'a Self, x: &'a mut Self,
+    ) -> Result<(&'a [T], Strided, &'a mut [T]), ListError>
+    {
+        match (a, x) {
+            (Array::Array2D { arr, nr, nc, put_val_by_row },
+             Array::Array1D { arr: vx })
+            => {
+                if *nr != *nc {return Err(ListError::NotSquareMat);}
+                if vx.len() != *nr {return Err(ListError::ShapeMismatch { lhs: vec![*nr, *nc], rhs: vec![vx.len()] });}
+                Ok((arr, Strided::new(*nr, *nc, *put_val_by_row).op(trans), vx))
+            },
+
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+}
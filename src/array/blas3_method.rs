@@ -0,0 +1,357 @@
+// BLAS level 3, matrix matrix operations besides gemm
+//
+// the right side forms are turned into left side ones
+// by reading B transposed:
+//  X op(A) = B  <=>  op(A)' X' = B'
+//
+// shape conflicts are ShapeMismatch like levels 1 and 2
+
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
+
+use super::blas2_method::{Diag, Uplo};
+use super::gemm_method::{Strided, Transpose};
+use super::{Array, ListError};
+
+// A buffer, op(A) strides, triangle of op(A), B buffer, B strides
+type TriOperands<'a, T> = (&'a [T], Strided, Uplo, &'a mut [T], Strided);
+
+/// ## Side of the special matrix A in BLAS routines
+/// -1 Left: op(A) B
+///
+/// -2 Right: B op(A)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+impl<T> Array<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
++ Sub<Output=T> + PartialOrd
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default
+{
+    /// solve op(A) X = alpha B (Left) or X op(A) = alpha B (Right),
+    /// A is triangular, only the uplo triangle is read, B is overwritten by X
+    /// ```
+    /// use simple_blas::array::{Array, Diag, Side, Transpose, Uplo};
+    ///
+    /// // [[2, 1], [0, 1]]
+    /// let a = Array::new_array_2d(Box::new([2.0, 1.0, 0.0, 1.0]), (2, 2), true).unwrap();
+    /// let mut b = Array::new_array_2d(Box::new([3.0, 4.0, 1.0, 2.0]), (2, 2), true).unwrap();
+    ///
+    /// Array::trsm(Side::Left, Uplo::Upper, Transpose::NoTrans, Diag::NonUnit, 1.0, &a, &mut b).unwrap();
+    /// assert_eq!(b, Array::new_array_2d(Box::new([1.0, 1.0, 1.0, 2.0]), (2, 2), true).unwrap());
+    /// ```
+    pub fn trsm(
+        side: Side, uplo: Uplo, trans: Transpose, diag: Diag,
+        alpha: T,
+        a: &Self,
+        b: &mut Self,
+    ) -> Result<(), ListError>
+    {
+        let (arr_a, sa, tri, arr_b, sb) = Array::tri_mat_operands(side, uplo, trans, a, b)?;
+
+        let z: T = T::default();
+        if diag == Diag::NonUnit && (0..sa.nr).any(|i| arr_a[i * sa.rs + i * sa.cs] == z) {
+            return Err(ListError::SingularMat);
+        }
+
+        Array::trsm_kernel(tri, diag, alpha, arr_a, sa, arr_b, sb);
+        Ok(())
+    }
+
+    /// B = alpha op(A) B (Left) or B = alpha B op(A) (Right),
+    /// A is triangular, only the uplo triangle is read
+    /// ```
+    /// use simple_blas::array::{Array, Diag, Side, Transpose, Uplo};
+    ///
+    /// // [[1, 0], [*, 1]] with unit diagonal
+    /// let a = Array::new_array_2d(Box::new([9.0, 2.0, 9.0, 9.0]), (2, 2), false).unwrap();
+    /// let mut b = Array::new_array_2d(Box::new([1.0, 2.0, 3.0, 4.0]), (2, 2), true).unwrap();
+    ///
+    /// // B = B L
+    /// Array::trmm(Side::Right, Uplo::Lower, Transpose::NoTrans, Diag::Unit, 1.0, &a, &mut b).unwrap();
+    /// assert_eq!(b, Array::new_array_2d(Box::new([5.0, 2.0, 11.0, 4.0]), (2, 2), true).unwrap());
+    /// ```
+    pub fn trmm(
+        side: Side, uplo: Uplo, trans: Transpose, diag: Diag,
+        alpha: T,
+        a: &Self,
+        b: &mut Self,
+    ) -> Result<(), ListError>
+    {
+        let (arr_a, sa, tri, arr_b, sb) = Array::tri_mat_operands(side, uplo, trans, a, b)?;
+        Array::trmm_kernel(tri, diag, alpha, arr_a, sa, arr_b, sb);
+        Ok(())
+    }
+
+    /// C = alpha A A' + beta C (NoTrans) or C = alpha A' A + beta C (Trans),
+    /// only the uplo triangle of C is written,
+    /// when beta is zero C is not read
+    /// ```
+    /// use simple_blas::array::{Array, Transpose, Uplo};
+    ///
+    /// let a = Array::new_array_2d(Box::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]), (3, 2), true).unwrap();
+    /// let mut c = Array::new_array_2d(Box::new([0.0; 4]), (2, 2), true).unwrap();
+    ///
+    /// // gram matrix A' A, lower triangle
+    /// Array::syrk(Uplo::Lower, Transpose::Trans, 1.0, &a, 0.0, &mut c).unwrap();
+    /// assert_eq!(c, Array::new_array_2d(Box::new([35.0, 0.0, 44.0, 56.0]), (2, 2), true).unwrap());
+    /// ```
+    pub fn syrk(
+        uplo: Uplo, trans: Transpose,
+        alpha: T,
+        a: &Self,
+        beta: T,
+        c: &mut Self,
+    ) -> Result<(), ListError>
+    {
+        match (a, c) {
+            (Array::Array2D { arr: arr_a, nr: nr_a, nc: nc_a, put_val_by_row: by_row_a },
+             Array::Array2D { arr: arr_c, nr: nr_c, nc: nc_c, put_val_by_row: by_row_c })
+            => {
+                let sa: Strided = Strided::new(*nr_a, *nc_a, *by_row_a).op(trans);
+                let sc: Strided = Strided::new(*nr_c, *nc_c, *by_row_c);
+                if sc.nr != sc.nc {return Err(ListError::NotSquareMat);}
+                // op(A) op(A)' is sa.nr x sa.nr
+                if sa.nr != sc.nr {
+                    return Err(ListError::ShapeMismatch { lhs: vec![sa.nr, sa.nr], rhs: vec![sc.nr, sc.nc] });
+                }
+
+                // op(A) op(A)'
+                let n: usize = sc.nr;
+                let st: Strided = Strided::new(n, n, true);
+                let mut tmp: Vec<T> = vec![T::default(); n * n];
+                Array::gemm_kernel(Some(alpha), arr_a, sa, arr_a, sa.op(Transpose::Trans), T::default(), &mut tmp, st);
+
+                Array::update_triangle(uplo, &tmp, beta, arr_c, sc);
+                Ok(())
+            },
+
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+
+    /// C = alpha (A B' + B A') + beta C (NoTrans)
+    /// or C = alpha (A' B + B' A) + beta C (Trans),
+    /// only the uplo triangle of C is written,
+    /// when beta is zero C is not read
+    pub fn syr2k(
+        uplo: Uplo, trans: Transpose,
+        alpha: T,
+        a: &Self,
+        b: &Self,
+        beta: T,
+        c: &mut Self,
+    ) -> Result<(), ListError>
+    {
+        match (a, b, c) {
+            (Array::Array2D { arr: arr_a, nr: nr_a, nc: nc_a, put_val_by_row: by_row_a },
+             Array::Array2D { arr: arr_b, nr: nr_b, nc: nc_b, put_val_by_row: by_row_b },
+             Array::Array2D { arr: arr_c, nr: nr_c, nc: nc_c, put_val_by_row: by_row_c })
+            => {
+                let sa: Strided = Strided::new(*nr_a, *nc_a, *by_row_a).op(trans);
+                let sb: Strided = Strided::new(*nr_b, *nc_b, *by_row_b).op(trans);
+                let sc: Strided = Strided::new(*nr_c, *nc_c, *by_row_c);
+                if sc.nr != sc.nc {return Err(ListError::NotSquareMat);}
+                if (sa.nr, sa.nc) != (sb.nr, sb.nc) {
+                    return Err(ListError::ShapeMismatch { lhs: vec![sa.nr, sa.nc], rhs: vec![sb.nr, sb.nc] });
+                }
+                if sa.nr != sc.nr {
+                    return Err(ListError::ShapeMismatch { lhs: vec![sa.nr, sa.nr], rhs: vec![sc.nr, sc.nc] });
+                }
+
+                // op(A) op(B)' + op(B) op(A)'
+                let n: usize = sc.nr;
+                let st: Strided = Strided::new(n, n, true);
+                let z: T = T::default();
+                let mut tmp: Vec<T> = vec![z; n * n];
+                let mut tmp2: Vec<T> = vec![z; n * n];
+                Array::gemm_kernel(Some(alpha), arr_a, sa, arr_b, sb.op(Transpose::Trans), z, &mut tmp, st);
+                Array::gemm_kernel(Some(alpha), arr_b, sb, arr_a, sa.op(Transpose::Trans), z, &mut tmp2, st);
+                for i in 0..(n * n) {tmp[i] += tmp2[i];}
+
+                Array::update_triangle(uplo, &tmp, beta, arr_c, sc);
+                Ok(())
+            },
+
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+
+    /// C = alpha A B + beta C (Left) or C = alpha B A + beta C (Right),
+    /// A is symmetric, only the uplo triangle is read,
+    /// when beta is zero C is not read
+    /// ```
+    /// use simple_blas::array::{Array, Side, Uplo};
+    ///
+    /// // [[1, 2], [2, 3]] from the lower triangle
+    /// let a = Array::new_array_2d(Box::new([1.0, 9.0, 2.0, 3.0]), (2, 2), true).unwrap();
+    /// let b = Array::new_array_2d(Box::new([1.0, 0.0, 0.0, 1.0]), (2, 2), true).unwrap();
+    /// let mut c = Array::new_array_2d(Box::new([0.0; 4]), (2, 2), false).unwrap();
+    ///
+    /// Array::symm(Side::Left, Uplo::Lower, 1.0, &a, &b, 0.0, &mut c).unwrap();
+    /// assert_eq!(c, Array::new_array_2d(Box::new([1.0, 2.0, 2.0, 3.0]), (2, 2), true).unwrap());
+    /// ```
+    pub fn symm(
+        side: Side, uplo: Uplo,
+        alpha: T,
+        a: &Self,
+        b: &Self,
+        beta: T,
+        c: &mut Self,
+    ) -> Result<(), ListError>
+    {
+        match (a, b, c) {
+            (Array::Array2D { arr: arr_a, nr: nr_a, nc: nc_a, put_val_by_row: by_row_a },
+             Array::Array2D { arr: arr_b, nr: nr_b, nc: nc_b, put_val_by_row: by_row_b },
+             Array::Array2D { arr: arr_c, nr: nr_c, nc: nc_c, put_val_by_row: by_row_c })
+            => {
+                if *nr_a != *nc_a {return Err(ListError::NotSquareMat);}
+                let n: usize = *nr_a;
+                let sa: Strided = Strided::new(n, n, *by_row_a);
+                let sb: Strided = Strided::new(*nr_b, *nc_b, *by_row_b);
+                let sc: Strided = Strided::new(*nr_c, *nc_c, *by_row_c);
+
+                let n_side: usize = if side == Side::Left {sb.nr} else {sb.nc};
+                if n_side != n {
+                    return Err(ListError::ShapeMismatch { lhs: vec![n, n], rhs: vec![sb.nr, sb.nc] });
+                }
+                if (sb.nr, sb.nc) != (sc.nr, sc.nc) {
+                    return Err(ListError::ShapeMismatch { lhs: vec![sb.nr, sb.nc], rhs: vec![sc.nr, sc.nc] });
+                }
+
+                // full A from the uplo triangle
+                let sf: Strided = Strided::new(n, n, true);
+                let mut full: Vec<T> = vec![T::default(); n * n];
+                for r in 0..n {
+                    for col in 0..n {
+                        let inside: bool = if uplo == Uplo::Upper {r <= col} else {r >= col};
+                        let (i, j) = if inside {(r, col)} else {(col, r)};
+                        full[r * n + col] = arr_a[i * sa.rs + j * sa.cs];
+                    }
+                }
+
+                if side == Side::Left {
+                    Array::gemm_kernel(Some(alpha), &full, sf, arr_b, sb, beta, arr_c, sc);
+                } else {
+                    Array::gemm_kernel(Some(alpha), arr_b, sb, &full, sf, beta, arr_c, sc);
+                }
+                Ok(())
+            },
+
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+
+    // solve A X = alpha B in place of B,
+    // A (sa, already op) is tri triangular, n x n, B is n x m
+    pub(crate) fn trsm_kernel(
+        tri: Uplo, diag: Diag,
+        alpha: T,
+        a: &[T], sa: Strided,
+        b: &mut [T], sb: Strided,
+    ) {
+        let n: usize = sa.nr;
+        let at = |r: usize, c: usize| a[r * sa.rs + c * sa.cs];
+
+        for j in 0..sb.nc {
+            let bj: usize = j * sb.cs;
+            for i in 0..n {b[bj + i * sb.rs] *= alpha;}
+
+            match tri {
+                // back substitution
+                Uplo::Upper => {
+                    for i in (0..n).rev() {
+                        let mut sum: T = b[bj + i * sb.rs];
+                        for k in (i + 1)..n {sum -= at(i, k) * b[bj + k * sb.rs];}
+                        b[bj + i * sb.rs] = if diag == Diag::Unit {sum} else {sum / at(i, i)};
+                    }
+                },
+                // forward substitution
+                Uplo::Lower => {
+                    for i in 0..n {
+                        let mut sum: T = b[bj + i * sb.rs];
+                        for k in 0..i {sum -= at(i, k) * b[bj + k * sb.rs];}
+                        b[bj + i * sb.rs] = if diag == Diag::Unit {sum} else {sum / at(i, i)};
+                    }
+                },
+            }
+        }
+    }
+
+    // B = alpha A B in place,
+    // A (sa, already op) is tri triangular, n x n, B is n x m
+    pub(crate) fn trmm_kernel(
+        tri: Uplo, diag: Diag,
+        alpha: T,
+        a: &[T], sa: Strided,
+        b: &mut [T], sb: Strided,
+    ) {
+        let n: usize = sa.nr;
+        let at = |r: usize, c: usize| a[r * sa.rs + c * sa.cs];
+
+        for j in 0..sb.nc {
+            let bj: usize = j * sb.cs;
+            // row i only needs rows not written yet
+            let mut row_i = |i: usize, ks: std::ops::Range<usize>| {
+                let mut sum: T = if diag == Diag::Unit {b[bj + i * sb.rs]} else {at(i, i) * b[bj + i * sb.rs]};
+                for k in ks {sum += at(i, k) * b[bj + k * sb.rs];}
+                b[bj + i * sb.rs] = alpha * sum;
+            };
+
+            match tri {
+                Uplo::Upper => for i in 0..n {row_i(i, (i + 1)..n)},
+                Uplo::Lower => for i in (0..n).rev() {row_i(i, 0..i)},
+            }
+        }
+    }
+
+    // C[uplo] = tmp[uplo] + beta C[uplo], tmp is row major
+    fn update_triangle(uplo: Uplo, tmp: &[T], beta: T, c: &mut [T], sc: Strided) {
+        let z: T = T::default();
+        let n: usize = sc.nr;
+        for r in 0..n {
+            let cols = if uplo == Uplo::Upper {r..n} else {0..(r + 1)};
+            for col in cols {
+                let ci: usize = r * sc.rs + col * sc.cs;
+                c[ci] = if beta == z {tmp[r * n + col]} else {tmp[r * n + col] + beta * c[ci]};
+            }
+        }
+    }
+
+    // square A and B for trsm, trmm, as the left side problem
+    fn tri_mat_operands<'a>(
+        side: Side, uplo: Uplo, trans: Transpose,
+        a: &'a Self, b: &'a mut Self,
+    ) -> Result<TriOperands<'a, T>, ListError>
+    {
+        match (a, b) {
+            (Array::Array2D { arr: arr_a, nr: nr_a, nc: nc_a, put_val_by_row: by_row_a },
+             Array::Array2D { arr: arr_b, nr: nr_b, nc: nc_b, put_val_by_row: by_row_b })
+            => {
+                if *nr_a != *nc_a {return Err(ListError::NotSquareMat);}
+                let sa: Strided = Strided::new(*nr_a, *nc_a, *by_row_a).op(trans);
+                let sb: Strided = Strided::new(*nr_b, *nc_b, *by_row_b);
+
+                let (sa, tri, sb) = match side {
+                    Side::Left => (sa, uplo.op(trans), sb),
+                    // B op(A) = (op(A)' B')'
+                    Side::Right => (
+                        sa.op(Transpose::Trans),
+                        uplo.op(trans).op(Transpose::Trans),
+                        sb.op(Transpose::Trans),
+                    ),
+                };
+                if sa.nc != sb.nr {
+                    return Err(ListError::ShapeMismatch { lhs: vec![*nr_a, *nc_a], rhs: vec![*nr_b, *nc_b] });
+                }
+
+                Ok((arr_a, sa, tri, arr_b, sb))
+            },
+
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+}
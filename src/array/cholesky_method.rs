@@ -0,0 +1,255 @@
+// cholesky and LDL' factorization for symmetric matrix
+
+use super::matrix_operations::Sqrt;
+use super::{Array, ListError};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
+
+use crate::array::{idxr, idxc};
+
+/// ## Symmetric indefinite factorization, P A P' = L D L'
+/// -1 l: unit lower triangular
+///
+/// -2 d: block diagonal of 1 x 1 and 2 x 2 symmetric blocks
+///
+/// -3 p: symmetric swaps (i, j) of P in the order they are applied,
+/// row i and j, then column i and j
+/// ```
+/// use simple_blas::array::Array;
+///
+/// // no 1 x 1 pivot is possible, one 2 x 2 block
+/// let a = Array::new_array_2d(
+///     Box::new([0.0, 1.0, 1.0, 0.0_f64]), (2, 2), true
+/// ).unwrap();
+///
+/// let ldl = a.mldl().unwrap();
+/// assert_eq!(ldl.d, a);
+/// assert!(ldl.p.is_empty());
+/// ```
+#[derive(Debug)]
+pub struct Ldl<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default
+{
+    pub l: Array<T>,
+    pub d: Array<T>,
+    pub p: Vec<(usize, usize)>,
+}
+
+impl<T> Array<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T> + Sub<Output=T>
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default + From<f32> + PartialOrd + Sqrt
+{
+    /// A = L L' for SYMMETRIC POSITIVE DEFINITE A,
+    /// return lower triangular L
+    /// ```
+    /// use simple_blas::array::Array;
+    ///
+    /// let a = Array::new_array_2d(
+    ///     Box::new([4.0, 2.0, 2.0, 5.0_f64]), (2, 2), true
+    /// ).unwrap();
+    ///
+    /// let l = a.mcholesky().unwrap();
+    /// assert_eq!(l, Array::new_array_2d(Box::new([2.0, 0.0, 1.0, 2.0]), (2, 2), true).unwrap());
+    ///
+    /// let x = Array::cholesky_solve(&l, &Array::new_array_1d(Box::new([6.0, 7.0]))).unwrap();
+    /// assert!((x[0] - 1.0).abs() < 1e-10);
+    /// assert!((x[1] - 1.0).abs() < 1e-10);
+    /// ```
+    pub fn mcholesky(&self) -> Result<Self, ListError> {
+        match self {
+            Array::Array2D { arr, nr, nc, put_val_by_row }
+            => {
+                if *nr != *nc {return Err(ListError::NotSquareMat);}
+                if !self.is_symmetric_tol() {return Err(ListError::NotSymmetricMat);}
+
+                let n: usize = *nr;
+                let dim: (usize, usize) = (*nr, *nc);
+                let idx: fn(usize, usize, (usize, usize)) -> usize = if *put_val_by_row {idxr} else {idxc};
+                let z: T = T::default();
+                let mut l: Vec<T> = vec![z; n * n];
+
+                for j in 0..n {
+                    // l[j, j]^2 = a[j, j] - sum l[j, k]^2
+                    let mut s: T = arr[idx(j, j, dim)];
+                    for k in 0..j {
+                        s -= l[idx(j, k, dim)] * l[idx(j, k, dim)];
+                    }
+                    // NaN or Inf is not positive definite either
+                    if !Array::is_finite(s, z) || s <= z {return Err(ListError::NotPositiveDefinite);}
+                    let ljj: T = s.sqrt();
+                    l[idx(j, j, dim)] = ljj;
+
+                    // l[i, j] = (a[i, j] - sum l[i, k] l[j, k]) / l[j, j]
+                    for i in (j+1)..n {
+                        let mut s: T = arr[idx(i, j, dim)];
+                        for k in 0..j {
+                            s -= l[idx(i, k, dim)] * l[idx(j, k, dim)];
+                        }
+                        l[idx(i, j, dim)] = s / ljj;
+                    }
+                }
+
+                Array::new_array_2d(l.into_boxed_slice(), (*nr as isize, *nc as isize), *put_val_by_row)
+            },
+
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+
+    /// solve L L' x = b with L from mcholesky,
+    /// b is Array1D for one right-hand side,
+    /// or Array2D for many right-hand sides in columns
+    pub fn cholesky_solve(l: &Self, b: &Self) -> Result<Self, ListError> {
+        match l {
+            Array::Array2D { arr, nr, nc, put_val_by_row }
+            => {
+                if *nr != *nc {return Err(ListError::NotSquareMat);}
+                let dim: (usize, usize) = (*nr, *nc);
+                let idx: fn(usize, usize, (usize, usize)) -> usize = if *put_val_by_row {idxr} else {idxc};
+                // read L by the other index is L'
+                let idx_t: fn(usize, usize, (usize, usize)) -> usize = if *put_val_by_row {idxc} else {idxr};
+
+                let is_lu: bool = false;
+                let mut y: Vec<T> = vec![T::default(); *nr];
+                Array::solve_rhs(b, *nr, |bc, x| {
+                    // L y = b
+                    Array::l_tri_solve(arr, bc, &mut y, dim, idx, is_lu)?;
+                    // L' x = y
+                    Array::u_tri_solve(arr, &y, x, dim, idx_t)
+                })
+            },
+
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+
+    /// P A P' = L D L' for SYMMETRIC A by bunch kaufman pivoting,
+    /// works for any symmetric A, also indefinite and singular,
+    /// only the lower triangle is read
+    pub fn mldl(&self) -> Result<Ldl<T>, ListError> {
+        match self {
+            Array::Array2D { arr, nr, nc, put_val_by_row }
+            => {
+                if *nr != *nc {return Err(ListError::NotSquareMat);}
+                if !self.is_symmetric_tol() {return Err(ListError::NotSymmetricMat);}
+
+                let n: usize = *nr;
+                let dim: (usize, usize) = (*nr, *nc);
+                let idx: fn(usize, usize, (usize, usize)) -> usize = if *put_val_by_row {idxr} else {idxc};
+                let z: T = T::default();
+                let one: T = T::from(1.0_f32);
+
+                // a 1 x 1 pivot is taken while |a[k, k]| >= alpha max |a[i, k]|,
+                // alpha = (1 + sqrt(17)) / 8 bounds the growth of the elements
+                let alpha: T = (one + T::from(17.0_f32).sqrt()) / T::from(8.0_f32);
+
+                // w is the trailing part still to be factored, kept full
+                let mut w: Box<[T]> = vec![z; n * n].into_boxed_slice();
+                for r in 0..n {
+                    for c in 0..=r {
+                        w[idx(r, c, dim)] = arr[idx(r, c, dim)];
+                        w[idx(c, r, dim)] = arr[idx(r, c, dim)];
+                    }
+                }
+                let mut l: Box<[T]> = vec![z; n * n].into_boxed_slice();
+                let mut d: Box<[T]> = vec![z; n * n].into_boxed_slice();
+                let mut p: Vec<(usize, usize)> = vec![];
+
+                let mut k: usize = 0;
+                while k < n {
+                    let absakk: T = Array::abs(w[idx(k, k, dim)], z);
+
+                    // largest element below the diagonal in column k
+                    let mut imax: usize = k;
+                    let mut colmax: T = z;
+                    for i in (k+1)..n {
+                        let v: T = Array::abs(w[idx(i, k, dim)], z);
+                        if v > colmax {colmax = v; imax = i;}
+                    }
+
+                    let (kp, size): (usize, usize) = if absakk >= alpha * colmax {
+                        (k, 1)
+                    } else {
+                        // largest element off the diagonal in row imax
+                        let mut rowmax: T = z;
+                        for j in k..n {
+                            let v: T = Array::abs(w[idx(imax, j, dim)], z);
+                            if j != imax && v > rowmax {rowmax = v;}
+                        }
+
+                        if absakk * rowmax >= alpha * colmax * colmax {(k, 1)}
+                        else if Array::abs(w[idx(imax, imax, dim)], z) >= alpha * rowmax {(imax, 1)}
+                        else {(imax, 2)}
+                    };
+
+                    // swap kp to k (1 x 1) or k+1 (2 x 2)
+                    let kk: usize = k + size - 1;
+                    if kp != kk {
+                        Array::swap_r_ij(&mut w, kk, kp, k, n, idx, dim);
+                        Array::swap_c_ij(&mut w, kk, kp, k, n, idx, dim);
+                        Array::swap_r_ij(&mut l, kk, kp, 0, k, idx, dim);
+                        p.push((kk, kp));
+                    }
+
+                    if size == 1 {
+                        let dkk: T = w[idx(k, k, dim)];
+                        d[idx(k, k, dim)] = dkk;
+                        l[idx(k, k, dim)] = one;
+
+                        // the column is zero when dkk is zero
+                        if dkk != z {
+                            for i in (k+1)..n {
+                                l[idx(i, k, dim)] = w[idx(i, k, dim)] / dkk;
+                            }
+                        }
+
+                        // w -= l[.., k] w[k, ..]
+                        for i in (k+1)..n {
+                            let lik: T = l[idx(i, k, dim)];
+                            for j in (k+1)..n {
+                                w[idx(i, j, dim)] -= lik * w[idx(j, k, dim)];
+                            }
+                        }
+                    } else {
+                        let d11: T = w[idx(k, k, dim)];
+                        let d21: T = w[idx(k+1, k, dim)];
+                        let d22: T = w[idx(k+1, k+1, dim)];
+                        d[idx(k, k, dim)] = d11;
+                        d[idx(k+1, k, dim)] = d21;
+                        d[idx(k, k+1, dim)] = d21;
+                        d[idx(k+1, k+1, dim)] = d22;
+                        l[idx(k, k, dim)] = one;
+                        l[idx(k+1, k+1, dim)] = one;
+
+                        // [l[i, k], l[i, k+1]] = [w[i, k], w[i, k+1]] D^-1,
+                        // det < 0 by the choice of the 2 x 2 pivot
+                        let det: T = d11 * d22 - d21 * d21;
+                        for i in (k+2)..n {
+                            let (wi1, wi2) = (w[idx(i, k, dim)], w[idx(i, k+1, dim)]);
+                            l[idx(i, k, dim)] = (wi1 * d22 - wi2 * d21) / det;
+                            l[idx(i, k+1, dim)] = (wi2 * d11 - wi1 * d21) / det;
+                        }
+
+                        // w -= l[.., k..k+2] w[k..k+2, ..]
+                        for i in (k+2)..n {
+                            let (li1, li2) = (l[idx(i, k, dim)], l[idx(i, k+1, dim)]);
+                            for j in (k+2)..n {
+                                w[idx(i, j, dim)] -= li1 * w[idx(j, k, dim)] + li2 * w[idx(j, k+1, dim)];
+                            }
+                        }
+                    }
+
+                    k += size;
+                }
+
+                let l: Array<T> = Array::Array2D { arr: l, nr: n, nc: n, put_val_by_row: *put_val_by_row };
+                let d: Array<T> = Array::Array2D { arr: d, nr: n, nc: n, put_val_by_row: *put_val_by_row };
+                Ok(Ldl { l, d, p })
+            },
+
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use std::{any::type_name, fmt::{self, LowerExp}, ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign}};
 use super::Array;
+use super::nd_method::c_order_pos;
 
 impl<T> fmt::Display for Array<T>
 where T: Add<Output=T> + Mul<Output=T> + Div<Output=T> 
@@ -7,6 +8,7 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
 + Default + Sub<Output=T> + PartialOrd + From<f32> + LowerExp
 + fmt::Display
 {
+    #[allow(clippy::manual_is_multiple_of, clippy::write_with_newline)]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fn print_scalar<T> (val: T, f: &mut fmt::Formatter<'_>) -> fmt::Result
         where
@@ -44,6 +46,21 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
                 write!(f, "")
             },
 
+            // rows along the last axis in C order,
+            // a blank line between the matrices of the last two axes
+            Array::ArrayND { arr, shape, strides } => {
+                let _ = write!(f, "shape {:?}", shape);
+                let n: usize = shape.len();
+                let row: usize = shape.last().map_or(1, |&len| len.max(1));
+                let plane: usize = if n < 2 {usize::MAX} else {(row * shape[n - 2]).max(1)};
+                for (i, p) in c_order_pos(shape, strides).into_iter().enumerate() {
+                    if i > 0 && i % plane == 0 {let _ = write!(f, "\n");}
+                    if i % row == 0 {let _ = write!(f, "\n");}
+                    let _ = print_scalar(arr[p], f);
+                }
+                write!(f, "")
+            },
+
             _ => {write!(f, "??")},
         }
     }
@@ -85,4 +102,15 @@ pub mod tests{
         println!("{}", arr);
     }
 
+    #[test]
+    fn display_test_arr_nd() {
+        let arr: Array<f64> = Array::new_array_nd(
+            (0..12).map(|x| x as f64).collect::<Vec<f64>>().into_boxed_slice(), &[2, 2, 3], false
+        ).unwrap();
+        let text: String = format!("{}", arr);
+        println!("{}", text);
+        assert!(text.starts_with("shape [2, 2, 3]"));
+        assert!(!text.contains("??"));
+    }
+
 }
\ No newline at end of file
@@ -1,7 +1,42 @@
-use super::Array;
+use super::{Array, ListError};
 use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
 use crate::array::matrix_operations::Sqrt;
 
+/// ## Eigen decomposition of a SQUARE matrix
+/// -1 values: Array1D, real part of eigen values,
+/// in descending order from meig and ascending order from msym_eig
+/// 
+/// -2 values_im: Array1D, imaginary part of eigen values,
+/// a conjugate pair is stored as (re, +im) then (re, -im)
+/// 
+/// -3 vectors: Array2D, the ith column is the unit eigen vector of ith eigen value,
+/// for a conjugate pair at column i and i+1, 
+/// the eigen vector of (re, +im) is column i + (column i+1) * j
+/// 
+/// -4 n_iter: number of QR iterations used to converge
+/// ```
+/// use simple_blas::array::Array;
+/// 
+/// let arr = Array::new_array_2d(
+///     Box::new([2.0, 1.0, 1.0, 2.0_f64]), (2, 2), true
+/// ).unwrap();
+/// 
+/// let eig = arr.meig().unwrap();
+/// assert!((eig.values[0] - 3.0).abs() < 1e-10);
+/// assert!((eig.values[1] - 1.0).abs() < 1e-10);
+/// ```
+#[derive(Debug)]
+pub struct Eigen<T> 
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T> 
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default
+{
+    pub values: Array<T>,
+    pub values_im: Array<T>,
+    pub vectors: Array<T>,
+    pub n_iter: usize,
+}
+
 impl<T> Array<T> 
 where T: Sqrt + 
 Add<Output=T> + Mul<Output=T> + 
@@ -9,8 +44,82 @@ Div<Output=T> + Sub<Output=T>
 + PartialEq + AddAssign + Copy + MulAssign + SubAssign
 + Default + PartialOrd + From<f32>
 {
-    pub fn meig() {
-        
+    pub fn meig(&self) -> Result<Eigen<T>, ListError> {
+        self.meig_with(Array::eigen_vectors)
+    }
+
+    // meig with eigen vectors from
+    // vectors(ma, dim, by_row, e_re, e_im)
+    pub(crate) fn meig_with(
+        &self,
+        vectors: impl FnOnce(&[T], (usize, usize), bool, &[T], &[T]) -> Result<Vec<T>, ListError>,
+    ) -> Result<Eigen<T>, ListError>
+    {
+        match self {
+            Array::Array2D { arr, nr, nc, put_val_by_row }
+            => {
+                if *nr != *nc {return Err(ListError::EigenMismatchedDim);}
+                let dim: (usize, usize) = (*nr, *nc);
+                let by_row: bool = *put_val_by_row;
+
+                let (e_re, e_im, n_iter) = Array::eigen_values_qr(arr, dim, by_row, None, None)?;
+                let e_vecs: Vec<T> = vectors(arr, dim, by_row, &e_re, &e_im)?;
+
+                let values: Array<T> = Array::new_array_1d(e_re.into_boxed_slice());
+                let values_im: Array<T> = Array::new_array_1d(e_im.into_boxed_slice());
+                let vectors: Array<T> = Array::new_array_2d(
+                    e_vecs.into_boxed_slice(), 
+                    (*nr as isize, *nc as isize), 
+                    by_row
+                )?;
+
+                Ok(Eigen { values, values_im, vectors, n_iter })
+            },
+
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+
+    /// eigen decomposition of a SYMMETRIC matrix,
+    /// eigen values are real and in ascending order,
+    /// eigen vectors are orthonormal
+    /// ```
+    /// use simple_blas::array::Array;
+    /// 
+    /// let arr = Array::new_array_2d(
+    ///     Box::new([2.0, 1.0, 1.0, 2.0_f64]), (2, 2), true
+    /// ).unwrap();
+    /// 
+    /// let eig = arr.msym_eig().unwrap();
+    /// assert!((eig.values[0] - 1.0).abs() < 1e-10);
+    /// assert!((eig.values[1] - 3.0).abs() < 1e-10);
+    /// ```
+    pub fn msym_eig(&self) -> Result<Eigen<T>, ListError> {
+        match self {
+            Array::Array2D { arr, nr, nc, put_val_by_row }
+            => {
+                if *nr != *nc {return Err(ListError::EigenMismatchedDim);}
+                let dim: (usize, usize) = (*nr, *nc);
+                let by_row: bool = *put_val_by_row;
+
+                if !self.is_symmetric_tol() {return Err(ListError::NotSymmetricMat);}
+
+                let (e_vals, e_vecs, n_iter) = Array::sym_eigen(arr, dim, by_row, None)?;
+                let n: usize = e_vals.len();
+
+                let values: Array<T> = Array::new_array_1d(e_vals.into_boxed_slice());
+                let values_im: Array<T> = Array::new_array_1d(vec![T::default(); n].into_boxed_slice());
+                let vectors: Array<T> = Array::new_array_2d(
+                    e_vecs.into_boxed_slice(), 
+                    (*nr as isize, *nc as isize), 
+                    true
+                )?;
+
+                Ok(Eigen { values, values_im, vectors, n_iter })
+            },
+
+            _ => Err(ListError::MismatchedTypes),
+        }
     }
 }
 
@@ -28,6 +137,7 @@ pub mod tests {
             2.0, 1.0, -2.0, 1.0
         ];
 
+        let a_mat = ma.clone();
         let dim: (usize, usize) = (4, 4);
 
         let qf = Array::hessenberg(&mut ma, dim, true)?;
@@ -35,6 +145,16 @@ pub mod tests {
         println!("{:?}", ma);
         println!("{:?}", qf);
 
+        // A = Q H Q'
+        let idx: fn(usize, usize, (usize, usize)) -> usize = idxr;
+        let mut qf_t = qf.clone();
+        qf_t.reverse();
+        Array::q_factor_dot_ma(&qf, &mut ma, dim, idx);
+        Array::ma_dot_q_factor(&mut ma, dim, idx, &qf_t);
+
+        let d: f64 = Array::dist_n2_vec_v1_v2(&a_mat, &ma)?;
+        assert!(d < 1e-10);
+
         Ok(())
     }
 
@@ -148,7 +268,7 @@ pub mod tests {
         let e_vals: Vec<f64> = Array::eigen_values(&ma, dim, true, None, None)?;
         println!("{:?}", e_vals);
 
-        let d: f64 = Array::dist_n2_vec_v1_v2(&e_vals, &vec![6.07, 5.20, 1.01])?;
+        let d: f64 = Array::dist_n2_vec_v1_v2(&e_vals, &[6.07, 5.20, 1.01])?;
 
         assert!(d < 1e-10);
 
@@ -169,7 +289,8 @@ pub mod tests {
         let e_vals: Vec<f64> = Array::eigen_values(&ma, dim, true, None, None)?;
         println!("{:?}", e_vals);
 
-        let e_vecs: Vec<f64> = Array::eigen_vectors(&ma, dim, true, &e_vals)?;
+        let e_im: Vec<f64> = vec![0.0; e_vals.len()];
+        let e_vecs: Vec<f64> = Array::eigen_vectors(&ma, dim, true, &e_vals, &e_im)?;
         println!("{:?}", e_vecs);
 
         Ok(())
@@ -188,7 +309,8 @@ pub mod tests {
         let e_vals: Vec<f64> = Array::eigen_values(&ma, dim, true, None, None)?;
         println!("{:?}", e_vals);
 
-        let e_vecs: Vec<f64> = Array::eigen_vectors(&ma, dim, true, &e_vals)?;
+        let e_im: Vec<f64> = vec![0.0; e_vals.len()];
+        let e_vecs: Vec<f64> = Array::eigen_vectors(&ma, dim, true, &e_vals, &e_im)?;
         println!("{:?}", e_vecs);
 
         Ok(())
@@ -0,0 +1,123 @@
+// where the *_speed methods run
+
+use std::sync::Arc;
+
+use rayon::prelude::*;
+use rayon::ThreadPool;
+
+use super::ListError;
+
+/// ## Where parallel work runs
+/// -1 Global: rayon global pool
+///
+/// -2 Pool: a user supplied rayon pool
+///
+/// -3 Serial: current thread only
+#[derive(Debug, Clone)]
+pub enum Parallelism {
+    Global,
+    Pool(Arc<ThreadPool>),
+    Serial,
+}
+
+/// ## Execution context of the *_speed_with methods
+/// work below min_work (about the number of multiply-adds)
+/// runs serially, the thread start up costs more than it saves
+///
+/// the *_speed methods run in ExecContext::global()
+/// ```
+/// use std::sync::Arc;
+/// use simple_blas::array::{Array, ExecContext};
+///
+/// let pool = rayon::ThreadPoolBuilder::new().num_threads(2).build().unwrap();
+/// let ctx = ExecContext::with_pool(Arc::new(pool)).min_work(1 << 12);
+///
+/// let mut a = Array::new_array_2d(Box::new([1.0, 2.0, 3.0, 4.0]), (2, 2), true).unwrap();
+/// let b = Array::new_array_2d(Box::new([1.0, 0.0, 0.0, 1.0]), (2, 2), true).unwrap();
+/// a.mmult_speed_with(&ctx, &b).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ExecContext {
+    parallelism: Parallelism,
+    min_work: usize,
+}
+
+const DEFAULT_MIN_WORK: usize = 1 << 15;
+
+impl Default for ExecContext {
+    fn default() -> Self {
+        ExecContext::global()
+    }
+}
+
+impl ExecContext {
+    /// use the rayon global pool, the context of the *_speed methods
+    pub fn global() -> Self {
+        ExecContext { parallelism: Parallelism::Global, min_work: DEFAULT_MIN_WORK }
+    }
+
+    /// use a user supplied pool
+    pub fn with_pool(pool: Arc<ThreadPool>) -> Self {
+        ExecContext { parallelism: Parallelism::Pool(pool), min_work: DEFAULT_MIN_WORK }
+    }
+
+    /// run everything on the current thread
+    pub fn serial() -> Self {
+        ExecContext { parallelism: Parallelism::Serial, min_work: DEFAULT_MIN_WORK }
+    }
+
+    /// work below min_work runs serially
+    pub fn min_work(mut self, min_work: usize) -> Self {
+        self.min_work = min_work;
+        self
+    }
+
+    pub fn parallelism(&self) -> &Parallelism {
+        &self.parallelism
+    }
+
+    pub fn min_work_threshold(&self) -> usize {
+        self.min_work
+    }
+
+    // op(true) runs inside the pool, op(false) serially
+    pub(crate) fn run<R: Send>(&self, work: usize, op: impl FnOnce(bool) -> R + Send) -> R {
+        if work < self.min_work {return op(false);}
+        match &self.parallelism {
+            Parallelism::Serial => op(false),
+            Parallelism::Global => op(true),
+            Parallelism::Pool(pool) => pool.install(|| op(true)),
+        }
+    }
+
+    // f(i, chunk) for every chunk of length len
+    pub(crate) fn for_each_chunk<T: Send>(
+        &self, data: &mut [T], len: usize, work: usize,
+        f: impl Fn(usize, &mut [T]) -> Result<(), ListError> + Send + Sync,
+    ) -> Result<(), ListError>
+    {
+        if data.is_empty() {return Ok(());}
+        self.run(work, |parallel| {
+            if parallel {
+                data.par_chunks_mut(len).enumerate().try_for_each(|(i, chunk)| f(i, chunk))
+            } else {
+                data.chunks_mut(len).enumerate().try_for_each(|(i, chunk)| f(i, chunk))
+            }
+        })
+    }
+
+    // [f(0), f(1), ..., f(n-1)]
+    pub(crate) fn map<R: Send>(
+        &self, n: usize, work: usize,
+        f: impl Fn(usize) -> R + Send + Sync,
+    ) -> Vec<R>
+    {
+        self.run(work, |parallel| {
+            if parallel {
+                (0..n).into_par_iter().map(&f).collect()
+            } else {
+                (0..n).map(&f).collect()
+            }
+        })
+    }
+}
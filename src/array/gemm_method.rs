@@ -0,0 +1,448 @@
+// general matrix multiply
+// C = alpha op(A) op(B) + beta C
+//
+// packed and tiled like BLIS:
+//  B is packed to kc x NR panels, A to MR x kc panels,
+//  a MR x NR micro kernel works on the packed panels only,
+//  so the inner loops run over contiguous memory
+
+use std::any::type_name;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
+
+use super::{Array, ExecContext, ListError};
+
+/// ## op(X) in BLAS routines
+/// -1 NoTrans: op(X) = X
+///
+/// -2 Trans: op(X) = X'
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Transpose {
+    NoTrans,
+    Trans,
+}
+
+// block sizes along k, and along m, n in micro tiles
+const KC: usize = 256;
+const MC_TILES: usize = 16;
+const NC_TILES: usize = 128;
+
+// micro tiles along m (or n) in a block of gemm_kernel_speed
+const SPLIT_TILES: usize = 4;
+
+// micro tile is 8 x 8 for f32 and 4 x 8 for every other type,
+// type_name needs no T: 'static bound as TypeId would
+fn is_f32<T>() -> bool {
+    type_name::<T>() == type_name::<f32>()
+}
+
+// matrix in a buffer by strides
+//  x[r, c] = buf[r * rs + c * cs]
+#[derive(Clone, Copy)]
+pub(crate) struct Strided {
+    pub(crate) nr: usize,
+    pub(crate) nc: usize,
+    pub(crate) rs: usize,
+    pub(crate) cs: usize,
+}
+
+impl Strided {
+    // nr x nc matrix put by row or by column
+    pub(crate) fn new(nr: usize, nc: usize, by_row: bool) -> Self {
+        if by_row {Strided { nr, nc, rs: nc, cs: 1 }}
+        else {Strided { nr, nc, rs: 1, cs: nr }}
+    }
+
+    // read as op(X)
+    pub(crate) fn op(self, trans: Transpose) -> Self {
+        match trans {
+            Transpose::NoTrans => self,
+            Transpose::Trans => Strided { nr: self.nc, nc: self.nr, rs: self.cs, cs: self.rs },
+        }
+    }
+}
+
+impl<T> Array<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
++ Sub<Output=T> + PartialOrd
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default
+{
+    /// C = alpha op(A) op(B) + beta C, C keeps its layout,
+    /// when beta is zero C is not read
+    /// ```
+    /// use simple_blas::array::{Array, Transpose};
+    ///
+    /// let a = Array::new_array_2d(Box::new([1.0, 2.0, 3.0, 4.0]), (2, 2), true).unwrap();
+    /// let b = Array::new_array_2d(Box::new([1.0, 0.0, 1.0, 1.0]), (2, 2), true).unwrap();
+    /// let mut c = Array::new_array_2d(Box::new([1.0, 1.0, 1.0, 1.0]), (2, 2), false).unwrap();
+    ///
+    /// // C = 2 A' B - C
+    /// Array::gemm(2.0, &a, Transpose::Trans, &b, Transpose::NoTrans, -1.0, &mut c).unwrap();
+    /// assert_eq!(c, Array::new_array_2d(Box::new([7.0, 5.0, 11.0, 7.0]), (2, 2), true).unwrap());
+    /// ```
+    pub fn gemm(
+        alpha: T,
+        a: &Self, trans_a: Transpose,
+        b: &Self, trans_b: Transpose,
+        beta: T,
+        c: &mut Self,
+    ) -> Result<(), ListError>
+    {
+        match (a, b, c) {
+            (Array::Array2D { arr: arr_a, nr: nr_a, nc: nc_a, put_val_by_row: by_row_a },
+             Array::Array2D { arr: arr_b, nr: nr_b, nc: nc_b, put_val_by_row: by_row_b },
+             Array::Array2D { arr: arr_c, nr: nr_c, nc: nc_c, put_val_by_row: by_row_c })
+            => {
+                let sa: Strided = Strided::new(*nr_a, *nc_a, *by_row_a).op(trans_a);
+                let sb: Strided = Strided::new(*nr_b, *nc_b, *by_row_b).op(trans_b);
+                let sc: Strided = Strided::new(*nr_c, *nc_c, *by_row_c);
+
+                if sa.nc != sb.nr {
+                    return Err(ListError::ShapeMismatch { lhs: vec![sa.nr, sa.nc], rhs: vec![sb.nr, sb.nc] });
+                }
+                if (sa.nr, sb.nc) != (sc.nr, sc.nc) {
+                    return Err(ListError::ShapeMismatch { lhs: vec![sa.nr, sb.nc], rhs: vec![sc.nr, sc.nc] });
+                }
+
+                Array::gemm_kernel(Some(alpha), arr_a, sa, arr_b, sb, beta, arr_c, sc);
+                Ok(())
+            },
+
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+
+    // C = alpha A B + beta C on strided buffers,
+    // alpha None is 1, beta zero overwrites C
+    //
+    // micro tile is 8 x 8 for f32, 4 x 8 otherwise (f64)
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn gemm_kernel(
+        alpha: Option<T>,
+        a: &[T], sa: Strided,
+        b: &[T], sb: Strided,
+        beta: T,
+        c: &mut [T], sc: Strided,
+    ) {
+        Array::scale_c(beta, c, sc);
+        if sa.nc == 0 || alpha == Some(T::default()) {return;}
+
+        if is_f32::<T>() {
+            Array::gemm_dispatch::<8, 8>(alpha, a, sa, b, sb, c, sc);
+        } else {
+            Array::gemm_dispatch::<4, 8>(alpha, a, sa, b, sb, c, sc);
+        }
+    }
+
+    // C = beta C, beta zero overwrites C
+    fn scale_c(beta: T, c: &mut [T], sc: Strided) {
+        let z: T = T::default();
+        for r in 0..sc.nr {
+            for col in 0..sc.nc {
+                let ci: usize = r * sc.rs + col * sc.cs;
+                c[ci] = if beta == z {z} else {beta * c[ci]};
+            }
+        }
+    }
+
+    // C shorter than the micro tile on a side is mostly padding
+    // once packed, a plain loop is faster there
+    fn gemm_dispatch<const MR: usize, const NR: usize>(
+        alpha: Option<T>,
+        a: &[T], sa: Strided,
+        b: &[T], sb: Strided,
+        c: &mut [T], sc: Strided,
+    ) {
+        if sc.nr < MR || sc.nc < NR {
+            Array::gemm_small(alpha, a, sa, b, sb, c, sc);
+        } else {
+            Array::gemm_blocked::<MR, NR>(alpha, a, sa, b, sb, c, sc);
+        }
+    }
+
+    // C += alpha A B, one dot product per element
+    fn gemm_small(
+        alpha: Option<T>,
+        a: &[T], sa: Strided,
+        b: &[T], sb: Strided,
+        c: &mut [T], sc: Strided,
+    ) {
+        for r in 0..sc.nr {
+            for col in 0..sc.nc {
+                let mut acc: T = T::default();
+                for p in 0..sa.nc {
+                    acc += a[r * sa.rs + p * sa.cs] * b[p * sb.rs + col * sb.cs];
+                }
+                c[r * sc.rs + col * sc.cs] += match alpha {
+                    Some(alpha) => alpha * acc,
+                    None => acc,
+                };
+            }
+        }
+    }
+
+    fn gemm_blocked<const MR: usize, const NR: usize>(
+        alpha: Option<T>,
+        a: &[T], sa: Strided,
+        b: &[T], sb: Strided,
+        c: &mut [T], sc: Strided,
+    ) {
+        let z: T = T::default();
+        let (m, n, k) = (sc.nr, sc.nc, sa.nc);
+        let mc_max: usize = MR * MC_TILES;
+        let nc_max: usize = NR * NC_TILES;
+
+        // pack buffers as large as the problem needs,
+        // rounded up to whole panels
+        let kc_max: usize = KC.min(k);
+        let mut pa: Vec<T> = vec![z; mc_max.min(m).div_ceil(MR) * MR * kc_max];
+        let mut pb: Vec<T> = vec![z; kc_max * nc_max.min(n).div_ceil(NR) * NR];
+
+        for jc in (0..n).step_by(nc_max) {
+            let nc: usize = nc_max.min(n - jc);
+
+            for pc in (0..k).step_by(KC) {
+                let kc: usize = KC.min(k - pc);
+                Array::pack_b::<NR>(b, sb, pc, jc, kc, nc, &mut pb);
+
+                for ic in (0..m).step_by(mc_max) {
+                    let mc: usize = mc_max.min(m - ic);
+                    Array::pack_a::<MR>(a, sa, ic, pc, mc, kc, &mut pa);
+
+                    for jr in (0..nc).step_by(NR) {
+                        let nr: usize = NR.min(nc - jr);
+                        let pb_panel: &[T] = &pb[jr * kc..(jr + NR) * kc];
+
+                        for ir in (0..mc).step_by(MR) {
+                            let mr: usize = MR.min(mc - ir);
+                            let pa_panel: &[T] = &pa[ir * kc..(ir + MR) * kc];
+
+                            let acc: [[T; NR]; MR] = Array::micro_kernel::<MR, NR>(kc, pa_panel, pb_panel);
+
+                            // C += alpha acc
+                            for (i, row) in acc.iter().enumerate().take(mr) {
+                                for (j, &val) in row.iter().enumerate().take(nr) {
+                                    let ci: usize = (ic + ir + i) * sc.rs + (jc + jr + j) * sc.cs;
+                                    c[ci] += match alpha {
+                                        Some(alpha) => alpha * val,
+                                        None => val,
+                                    };
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+}
+
+impl<T> Array<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
++ Sub<Output=T> + PartialOrd
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default + Sync + Send
+{
+    // gemm_kernel with blocks of C in parallel,
+    // blocks of rows (ic loop) when C is put by row,
+    // blocks of columns (jc loop) when C is put by column,
+    // a block is contiguous in C and has the same sums as gemm_kernel
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn gemm_kernel_speed(
+        ctx: &ExecContext,
+        alpha: Option<T>,
+        a: &[T], sa: Strided,
+        b: &[T], sb: Strided,
+        beta: T,
+        c: &mut [T], sc: Strided,
+    ) -> Result<(), ListError>
+    {
+        if is_f32::<T>() {
+            Array::gemm_split::<8, 8>(ctx, alpha, a, sa, b, sb, beta, c, sc)
+        } else {
+            Array::gemm_split::<4, 8>(ctx, alpha, a, sa, b, sb, beta, c, sc)
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn gemm_split<const MR: usize, const NR: usize>(
+        ctx: &ExecContext,
+        alpha: Option<T>,
+        a: &[T], sa: Strided,
+        b: &[T], sb: Strided,
+        beta: T,
+        c: &mut [T], sc: Strided,
+    ) -> Result<(), ListError>
+    {
+        let (m, n, k) = (sc.nr, sc.nc, sa.nc);
+        let by_row: bool = sc.cs == 1 && sc.rs == n;
+        let by_col: bool = sc.rs == 1 && sc.cs == m;
+
+        // tiny C runs the plain loop, keep it serial
+        if m < MR || n < NR || !(by_row || by_col) {
+            Array::gemm_kernel(alpha, a, sa, b, sb, beta, c, sc);
+            return Ok(());
+        }
+
+        let skip: bool = k == 0 || alpha == Some(T::default());
+        let work: usize = m * n * k;
+
+        if by_row {
+            let rows: usize = MR * SPLIT_TILES;
+            ctx.for_each_chunk(c, rows * n, work, |i, block| {
+                let sa_i: Strided = Strided { nr: block.len() / n, ..sa };
+                let sc_i: Strided = Strided { nr: block.len() / n, ..sc };
+                Array::scale_c(beta, block, sc_i);
+                if !skip {Array::gemm_blocked::<MR, NR>(alpha, &a[i * rows * sa.rs..], sa_i, b, sb, block, sc_i);}
+                Ok(())
+            })
+        } else {
+            let cols: usize = NR * SPLIT_TILES;
+            ctx.for_each_chunk(c, cols * m, work, |j, block| {
+                let sb_j: Strided = Strided { nc: block.len() / m, ..sb };
+                let sc_j: Strided = Strided { nc: block.len() / m, ..sc };
+                Array::scale_c(beta, block, sc_j);
+                if !skip {Array::gemm_blocked::<MR, NR>(alpha, a, sa, &b[j * cols * sb.cs..], sb_j, block, sc_j);}
+                Ok(())
+            })
+        }
+    }
+}
+
+impl<T> Array<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
++ Sub<Output=T> + PartialOrd
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default
+{
+    // acc = pa pb
+    // pa: kc columns of MR, pb: kc rows of NR
+    #[inline(always)]
+    fn micro_kernel<const MR: usize, const NR: usize>(
+        kc: usize, pa: &[T], pb: &[T],
+    ) -> [[T; NR]; MR] {
+        let mut acc: [[T; NR]; MR] = [[T::default(); NR]; MR];
+        for p in 0..kc {
+            let a_col: &[T] = &pa[p * MR..(p + 1) * MR];
+            let b_row: &[T] = &pb[p * NR..(p + 1) * NR];
+            for i in 0..MR {
+                let a_ip: T = a_col[i];
+                for j in 0..NR {
+                    acc[i][j] += a_ip * b_row[j];
+                }
+            }
+        }
+        acc
+    }
+
+    // A[ic.., pc..] (mc x kc) to panels of MR rows,
+    //  panel ir: pa[ir * kc + p * MR + i] = A[ic + ir + i, pc + p]
+    // zero padded at the bottom
+    fn pack_a<const MR: usize>(
+        a: &[T], sa: Strided,
+        ic: usize, pc: usize, mc: usize, kc: usize,
+        pa: &mut [T],
+    ) {
+        let z: T = T::default();
+        for ir in (0..mc).step_by(MR) {
+            let mr: usize = MR.min(mc - ir);
+            let panel: &mut [T] = &mut pa[ir * kc..(ir + MR) * kc];
+
+            if sa.rs == 1 {
+                // column of A is contiguous
+                for p in 0..kc {
+                    let start: usize = (ic + ir) + (pc + p) * sa.cs;
+                    let dst: &mut [T] = &mut panel[p * MR..(p + 1) * MR];
+                    dst[..mr].copy_from_slice(&a[start..start + mr]);
+                    dst[mr..].fill(z);
+                }
+            } else {
+                for i in 0..MR {
+                    if i < mr {
+                        let start: usize = (ic + ir + i) * sa.rs + pc * sa.cs;
+                        for p in 0..kc {
+                            panel[p * MR + i] = a[start + p * sa.cs];
+                        }
+                    } else {
+                        for p in 0..kc {panel[p * MR + i] = z;}
+                    }
+                }
+            }
+        }
+    }
+
+    // B[pc.., jc..] (kc x nc) to panels of NR columns,
+    //  panel jr: pb[jr * kc + p * NR + j] = B[pc + p, jc + jr + j]
+    // zero padded at the right
+    fn pack_b<const NR: usize>(
+        b: &[T], sb: Strided,
+        pc: usize, jc: usize, kc: usize, nc: usize,
+        pb: &mut [T],
+    ) {
+        let z: T = T::default();
+        for jr in (0..nc).step_by(NR) {
+            let nr: usize = NR.min(nc - jr);
+            let panel: &mut [T] = &mut pb[jr * kc..(jr + NR) * kc];
+
+            if sb.cs == 1 {
+                // row of B is contiguous
+                for p in 0..kc {
+                    let start: usize = (pc + p) * sb.rs + (jc + jr);
+                    let dst: &mut [T] = &mut panel[p * NR..(p + 1) * NR];
+                    dst[..nr].copy_from_slice(&b[start..start + nr]);
+                    dst[nr..].fill(z);
+                }
+            } else {
+                for j in 0..NR {
+                    if j < nr {
+                        let start: usize = pc * sb.rs + (jc + jr + j) * sb.cs;
+                        for p in 0..kc {
+                            panel[p * NR + j] = b[start + p * sb.rs];
+                        }
+                    } else {
+                        for p in 0..kc {panel[p * NR + j] = z;}
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{is_f32, Strided};
+    use crate::array::{Array, ExecContext};
+
+    #[test]
+    fn micro_tile_by_type() {
+        // same width as f32 or f64 is not enough
+        assert!(is_f32::<f32>());
+        assert!(!is_f32::<f64>());
+        assert!(!is_f32::<i32>());
+        assert!(!is_f32::<u32>());
+        assert!(!is_f32::<i64>());
+    }
+
+    #[test]
+    fn gemm_kernel_speed_blocks() {
+        // blocks of rows for C by row, blocks of columns for C by column
+        let (m, k, n) = (37, 19, 45);
+        let a: Vec<f64> = (0..m * k).map(|i| ((i % 11) as f64) - 5.0).collect();
+        let b: Vec<f64> = (0..k * n).map(|i| ((i % 7) as f64) * 0.5).collect();
+        let ctx: ExecContext = ExecContext::global().min_work(0);
+
+        for by_row_c in [true, false] {
+            let sa: Strided = Strided::new(m, k, true);
+            let sb: Strided = Strided::new(k, n, false);
+            let sc: Strided = Strided::new(m, n, by_row_c);
+
+            let mut c1: Vec<f64> = vec![1.0; m * n];
+            let mut c2: Vec<f64> = c1.clone();
+            Array::gemm_kernel(Some(2.0), &a, sa, &b, sb, -1.0, &mut c1, sc);
+            Array::gemm_kernel_speed(&ctx, Some(2.0), &a, sa, &b, sb, -1.0, &mut c2, sc).unwrap();
+
+            assert_eq!(c1, c2);
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use std::ops::{Index, IndexMut};
 use std::ops::{Add, AddAssign, Div, Mul, MulAssign, SubAssign};
 
-use super::Array;
+use super::{Array, ArrayView, ArrayViewMut};
 
 impl<T> Index<usize> for Array<T> 
 where T:
@@ -95,4 +95,54 @@ Add<Output=T> + Mul<Output=T> + Div<Output=T>
             _ => panic!("Mismatched Index"),
         }
     }
-}
\ No newline at end of file
+}
+impl<T, const N: usize> Index<[usize; N]> for Array<T>
+where T:
+Add<Output=T> + Mul<Output=T> + Div<Output=T>
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default
+{
+    type Output = T;
+    fn index(&self, index: [usize; N]) -> &Self::Output {
+        match (self, self.nd_pos(&index)) {
+            (Array::ArrayND { arr, .. }, Some(p)) => &arr[p],
+            (Array::ArrayND { .. }, None) => panic!("index out of bound"),
+            _ => panic!("Mismatched Index"),
+        }
+    }
+}
+
+impl<T, const N: usize> IndexMut<[usize; N]> for Array<T>
+where T:
+Add<Output=T> + Mul<Output=T> + Div<Output=T>
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default
+{
+    fn index_mut(&mut self, index: [usize; N]) -> &mut Self::Output {
+        match (self.nd_pos(&index), self) {
+            (Some(p), Array::ArrayND { arr, .. }) => &mut arr[p],
+            (None, Array::ArrayND { .. }) => panic!("index out of bound"),
+            _ => panic!("Mismatched Index"),
+        }
+    }
+}
+
+impl<T: Copy> Index<(usize, usize)> for ArrayView<'_, T> {
+    type Output = T;
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        self.at(index.0, index.1)
+    }
+}
+
+impl<T: Copy> Index<(usize, usize)> for ArrayViewMut<'_, T> {
+    type Output = T;
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        self.at(index.0, index.1)
+    }
+}
+
+impl<T: Copy> IndexMut<(usize, usize)> for ArrayViewMut<'_, T> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        self.at_mut(index.0, index.1)
+    }
+}
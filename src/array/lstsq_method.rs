@@ -0,0 +1,153 @@
+// least squares
+
+use super::matrix_operations::Sqrt;
+use super::{Array, ListError};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
+
+use crate::array::{idxr, idxc};
+
+/// ## Least squares solution of min ||A x - b||
+/// -1 x: Array1D (or Array2D for many right-hand sides in columns)
+///
+/// -2 residuals: Array1D, ||A x - b|| for each right-hand side
+///
+/// -3 rank: numerical rank of A
+/// ```
+/// use simple_blas::array::Array;
+///
+/// // fit y = c0 + c1 t on t = 0, 1, 2
+/// let a = Array::new_array_2d(
+///     Box::new([1.0, 0.0, 1.0, 1.0, 1.0, 2.0_f64]), (3, 2), true
+/// ).unwrap();
+/// let b = Array::new_array_1d(Box::new([1.0, 3.0, 5.0_f64]));
+///
+/// let res = a.mlstsq(&b).unwrap();
+/// assert!((res.x[0] - 1.0).abs() < 1e-10);
+/// assert!((res.x[1] - 2.0).abs() < 1e-10);
+/// assert!(res.residuals[0] < 1e-10);
+/// assert_eq!(res.rank, 2);
+/// ```
+#[derive(Debug)]
+pub struct Lstsq<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default
+{
+    pub x: Array<T>,
+    pub residuals: Array<T>,
+    pub rank: usize,
+}
+
+impl<T> Array<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T> + Sub<Output=T>
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default + From<f32> + PartialOrd + Sqrt
+{
+    /// minimize ||A x - b||,
+    /// column pivoted householder QR for full column rank A,
+    /// otherwise minimum norm solution by SVD
+    pub fn mlstsq(&self, b: &Self) -> Result<Lstsq<T>, ListError> {
+        match self {
+            Array::Array2D { arr, nr, nc, put_val_by_row }
+            => {
+                let (m, n) = (*nr, *nc);
+                let dim: (usize, usize) = (m, n);
+                let by_row: bool = *put_val_by_row;
+                let idx: fn(usize, usize, (usize, usize)) -> usize = if by_row {idxr} else {idxc};
+                let z: T = T::default();
+                let eps: T = Array::epsilon();
+                let big: T = T::from(if m < n {n as f32} else {m as f32});
+
+                let mut residuals: Vec<T> = vec![];
+
+                // A P = Q R, the pivoted diagonal of R reveals the rank
+                if m >= n {
+                    let (q_factor, rm, p) = Array::qr_householder_pivoted(arr, dim, by_row)?;
+
+                    let mut rmax: T = z;
+                    for i in 0..n {
+                        let val = Array::abs(rm[idx(i, i, dim)], z);
+                        if val > rmax {rmax = val;}
+                    }
+                    let tol: T = big * eps * rmax;
+
+                    // full column rank
+                    if rmax > z && (0..n).all(|i| Array::abs(rm[idx(i, i, dim)], z) > tol) {
+                        // upper n x n block of R, row major
+                        let dimr: (usize, usize) = (n, n);
+                        let mut r_top: Vec<T> = vec![z; n * n];
+                        for r in 0..n {
+                            for c in r..n {
+                                r_top[idxr(r, c, dimr)] = rm[idx(r, c, dim)];
+                            }
+                        }
+
+                        let x: Array<T> = Array::solve_rhs_rect(b, m, n, |bc, x| {
+                            // Q' b = H[n] ... H[1] b
+                            for q in q_factor.iter() {
+                                Array::reflector_mat_dot_mat(q, bc, (m, 1), idxr);
+                            }
+                            // R y = (Q' b)[0..n], x = P y
+                            Array::u_tri_solve(&r_top, &bc[0..n], x, dimr, idxr)?;
+                            for &(i, j) in p.iter().rev() {x.swap(i, j);}
+                            // ||A x - b|| = ||(Q' b)[n..m]||
+                            residuals.push(if m > n {Array::norm_2(&bc[n..m])} else {z});
+                            Ok(())
+                        })?;
+
+                        return Ok(Lstsq {
+                            x,
+                            residuals: Array::new_array_1d(residuals.into_boxed_slice()),
+                            rank: n
+                        });
+                    }
+                }
+
+                // rank deficient or under determined
+                // x = V S^+ U' b
+                let (u, sv, v, _n_iter) = if m >= n {
+                    Array::golub_kahan_svd(arr, dim, by_row, None)?
+                } else {
+                    // A' = U1 S V1', A = V1 S U1'
+                    let (u1, sv, v1, n_iter) = Array::golub_kahan_svd(arr, (n, m), !by_row, None)?;
+                    (v1, sv, u1, n_iter)
+                };
+                let k: usize = sv.len();
+                let dimu: (usize, usize) = (m, m);
+                let dimv: (usize, usize) = (n, n);
+
+                let tol: T = if k > 0 {big * eps * sv[0]} else {z};
+                let rank: usize = sv.iter().filter(|&&s| s > tol).count();
+
+                let x: Array<T> = Array::solve_rhs_rect(b, m, n, |bc, x| {
+                    // w = S^+ U' b
+                    let mut w: Vec<T> = vec![z; rank];
+                    for i in 0..rank {
+                        let mut sum: T = z;
+                        for r in 0..m {sum += u[idxr(r, i, dimu)] * bc[r];}
+                        w[i] = sum / sv[i];
+                    }
+                    // x = V w
+                    for r in 0..n {
+                        let mut sum: T = z;
+                        for i in 0..rank {sum += v[idxr(r, i, dimv)] * w[i];}
+                        x[r] = sum;
+                    }
+                    // A x - b
+                    let mut ax: Vec<T> = vec![z; m];
+                    Array::mat_a_dot_vec_b(arr, x, &mut ax, dim, by_row);
+                    residuals.push(Array::dist_n2_vec_v1_v2(&ax, bc)?);
+                    Ok(())
+                })?;
+
+                Ok(Lstsq {
+                    x,
+                    residuals: Array::new_array_1d(residuals.into_boxed_slice()),
+                    rank
+                })
+            },
+
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+}
@@ -0,0 +1,252 @@
+// reusable lu factorization
+
+use super::matrix_operations::Sqrt;
+use super::{Array, ListError};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
+
+use crate::array::{idxr, idxc};
+
+/// ## LU factorization with partial pivoting, P A = L U
+/// L (unit lower triangular) and U are packed in one SQUARE matrix,
+/// P is recorded as row swaps (i, j) in the order they are applied
+/// ```
+/// use simple_blas::array::Array;
+///
+/// let a = Array::new_array_2d(
+///     Box::new([2.0, 1.0, 1.0, 3.0_f64]), (2, 2), true
+/// ).unwrap();
+///
+/// // factor once, solve many times
+/// let lu = a.mlu().unwrap();
+/// let x1 = lu.solve(&Array::new_array_1d(Box::new([3.0, 5.0]))).unwrap();
+/// let x2 = lu.solve(&Array::new_array_1d(Box::new([1.0, 0.0]))).unwrap();
+///
+/// assert!((x1[0] - 0.8).abs() < 1e-10);
+/// assert!((x2[0] - 0.6).abs() < 1e-10);
+/// assert!((lu.det() - 5.0).abs() < 1e-10);
+/// ```
+#[derive(Debug)]
+pub struct LuFactorization<T> {
+    pub(crate) lu: Box<[T]>,
+    pub(crate) p: Vec<(usize, usize)>,
+    pub(crate) n: usize,
+    pub(crate) by_row: bool,
+}
+
+impl<T> Array<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T> + Sub<Output=T>
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default + From<f32> + PartialOrd + Sqrt
+{
+    /// P A = L U for SQUARE A with partial pivoting,
+    /// a singular A is factored too, check it by is_singular
+    /// ```
+    /// use simple_blas::array::Array;
+    ///
+    /// let a = Array::new_array_2d(
+    ///     Box::new([1.0, 2.0, 3.0, 4.0_f64]), (2, 2), true
+    /// ).unwrap();
+    ///
+    /// // the larger pivot 3 is swapped to the top
+    /// let lu = a.mlu().unwrap();
+    /// assert_eq!(lu.pivots(), &[(0, 1)]);
+    /// assert_eq!(lu.l(), Array::new_array_2d(Box::new([1.0, 0.0, 1.0 / 3.0, 1.0]), (2, 2), true).unwrap());
+    /// assert_eq!(lu.u(), Array::new_array_2d(Box::new([3.0, 4.0, 0.0, 2.0 - 4.0 / 3.0]), (2, 2), true).unwrap());
+    /// assert!(!lu.is_singular());
+    /// ```
+    pub fn mlu(&self) -> Result<LuFactorization<T>, ListError> {
+        match self {
+            Array::Array2D { arr, nr, nc, put_val_by_row }
+            => {
+                if *nr != *nc {return Err(ListError::NotSquareMat);}
+
+                let dim: (usize, usize) = (*nr, *nc);
+                let idx: fn(usize, usize, (usize, usize)) -> usize = if *put_val_by_row {idxr} else {idxc};
+
+                // p A = lu
+                let mut lu: Box<[T]> = arr.clone();
+                let mut p: Vec<(usize, usize)> = vec![];
+                Array::p_lu(&mut p, &mut lu, dim, idx);
+
+                Ok(LuFactorization { lu, p, n: *nr, by_row: *put_val_by_row })
+            },
+
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+}
+
+impl<T> LuFactorization<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T> + Sub<Output=T>
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default + From<f32> + PartialOrd + Sqrt
+{
+    fn idx(&self) -> fn(usize, usize, (usize, usize)) -> usize {
+        if self.by_row {idxr} else {idxc}
+    }
+
+    fn dim(&self) -> (usize, usize) {
+        (self.n, self.n)
+    }
+
+    /// row swaps (i, j) of P in the order they are applied
+    pub fn pivots(&self) -> &[(usize, usize)] {
+        &self.p
+    }
+
+    /// U has a zero on its diagonal
+    pub fn is_singular(&self) -> bool {
+        let (idx, dim) = (self.idx(), self.dim());
+        (0..self.n).any(|i| self.lu[idx(i, i, dim)] == T::default())
+    }
+
+    /// solve A x = b,
+    /// b is Array1D for one right-hand side,
+    /// or Array2D for many right-hand sides in columns
+    pub fn solve(&self, b: &Array<T>) -> Result<Array<T>, ListError> {
+        if self.is_singular() {return Err(ListError::SingularMat);}
+
+        let (idx, dim) = (self.idx(), self.dim());
+        Array::solve_rhs(b, self.n, |bc, x| {
+            Array::p_lu_solve(&self.lu, &self.p, bc, x, dim, idx)
+        })
+    }
+
+    /// det(A) = det(P) * prod(diag(U))
+    pub fn det(&self) -> T {
+        let (idx, dim) = (self.idx(), self.dim());
+        let mut det: T = T::from(1.0_f32);
+        for i in 0..self.n {
+            det *= self.lu[idx(i, i, dim)];
+        }
+
+        if self.p.len() % 2 == 1 {T::default() - det} else {det}
+    }
+
+    /// A^-1, solve A X = I
+    pub fn inverse(&self) -> Result<Array<T>, ListError> {
+        Array::new_array_2d(self.eye().into_boxed_slice(), (self.n as isize, self.n as isize), self.by_row)
+        .and_then(|eye| self.solve(&eye))
+    }
+
+    /// reciprocal condition number in 1-norm,
+    /// anorm is ||A||_1 of the factored matrix
+    ///
+    /// ||A^-1||_1 is estimated as in LAPACK (hager, higham),
+    /// without forming A^-1, return 0 for singular A
+    pub fn rcond(&self, anorm: T) -> T {
+        let z: T = T::default();
+        if anorm == z || self.is_singular() {return z;}
+
+        match self.inv_norm_1_est() {
+            Ok(ainvnm) if ainvnm > z => T::from(1.0_f32) / anorm / ainvnm,
+            _ => z,
+        }
+    }
+
+    // estimate ||A^-1||_1
+    fn inv_norm_1_est(&self) -> Result<T, ListError> {
+        let n: usize = self.n;
+        let (idx, dim) = (self.idx(), self.dim());
+        let idx_t: fn(usize, usize, (usize, usize)) -> usize = if self.by_row {idxc} else {idxr};
+        let z: T = T::default();
+        let one: T = T::from(1.0_f32);
+
+        let norm_1 = |v: &[T]| -> T {
+            let mut sum: T = z;
+            for &val in v.iter() {sum += Array::abs(val, z);}
+            sum
+        };
+
+        // x = [1/n, ..., 1/n]
+        let mut x: Vec<T> = vec![one / T::from(n as f32); n];
+        let mut y: Vec<T> = vec![z; n];
+        let mut w: Vec<T> = vec![z; n];
+        let mut est: T = z;
+        let mut last_j: usize = n;
+
+        for _ in 0..5 {
+            // y = A^-1 x
+            Array::p_lu_solve(&self.lu, &self.p, &mut x, &mut y, dim, idx)?;
+            est = norm_1(&y);
+
+            // xi = sign(y), w = A^-T xi
+            let xi: Vec<T> = y.iter().map(|&v| if v < z {z - one} else {one}).collect();
+            Array::p_lu_solve_t(&self.lu, &self.p, &xi, &mut w, dim, idx, idx_t)?;
+
+            // j = argmax |w|
+            let mut j: usize = 0;
+            for i in 1..n {
+                if Array::abs(w[i], z) > Array::abs(w[j], z) {j = i;}
+            }
+
+            let mut wx: T = z;
+            for i in 0..n {wx += w[i] * x[i];}
+            if Array::abs(w[j], z) <= wx || j == last_j {break;}
+
+            // x = e_j
+            x = vec![z; n];
+            x[j] = one;
+            last_j = j;
+        }
+
+        // alternative x[i] = (-1)^i (1 + i / (n - 1))
+        if n > 1 {
+            for (i, xi) in x.iter_mut().enumerate() {
+                let val: T = one + T::from(i as f32) / T::from((n - 1) as f32);
+                *xi = if i % 2 == 0 {val} else {z - val};
+            }
+            Array::p_lu_solve(&self.lu, &self.p, &mut x, &mut y, dim, idx)?;
+            let alt: T = T::from(2.0_f32) * norm_1(&y) / T::from((3 * n) as f32);
+            if alt > est {est = alt;}
+        }
+
+        Ok(est)
+    }
+
+    /// unit lower triangular L
+    pub fn l(&self) -> Array<T> {
+        let (idx, dim) = (self.idx(), self.dim());
+        let mut l: Vec<T> = self.eye();
+        for r in 0..self.n {
+            for c in 0..r {
+                l[idx(r, c, dim)] = self.lu[idx(r, c, dim)];
+            }
+        }
+
+        Array::Array2D { arr: l.into_boxed_slice(), nr: self.n, nc: self.n, put_val_by_row: self.by_row }
+    }
+
+    /// upper triangular U
+    pub fn u(&self) -> Array<T> {
+        let (idx, dim) = (self.idx(), self.dim());
+        let mut u: Vec<T> = vec![T::default(); self.n * self.n];
+        for r in 0..self.n {
+            for c in r..self.n {
+                u[idx(r, c, dim)] = self.lu[idx(r, c, dim)];
+            }
+        }
+
+        Array::Array2D { arr: u.into_boxed_slice(), nr: self.n, nc: self.n, put_val_by_row: self.by_row }
+    }
+
+    /// permutation matrix P
+    pub fn p(&self) -> Array<T> {
+        let (idx, dim) = (self.idx(), self.dim());
+        let mut p: Box<[T]> = self.eye().into_boxed_slice();
+        for &(i, j) in self.p.iter() {
+            Array::swap_r_ij(&mut p, i, j, 0, self.n, idx, dim);
+        }
+
+        Array::Array2D { arr: p, nr: self.n, nc: self.n, put_val_by_row: self.by_row }
+    }
+
+    fn eye(&self) -> Vec<T> {
+        let (idx, dim) = (self.idx(), self.dim());
+        let mut eye: Vec<T> = vec![T::default(); self.n * self.n];
+        for i in 0..self.n {
+            eye[idx(i, i, dim)] = T::from(1.0_f32);
+        }
+        eye
+    }
+}
@@ -1,8 +1,19 @@
+use std::cmp::Ordering;
 use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
-use std::usize;
 use super::{idxc, idxr, ListError};
 
 use super::Array;
+use super::gemm_method::{Strided, Transpose};
+use super::{Diag, Uplo};
+
+// q_factor, r and column swaps of column pivoted QR
+pub(crate) type PivotedQr<T> = (Vec<Vec<T>>, Vec<T>, Vec<(usize, usize)>);
+
+// U, singular values, V and number of iterations of golub kahan svd
+pub(crate) type SvdFactors<T> = (Vec<T>, Vec<T>, Vec<T>, usize);
+
+// real part and, for a conjugate pair, imaginary part of an eigen vector
+pub(crate) type EigenVector<T> = (Vec<T>, Option<Vec<T>>);
 
 #[allow(dead_code)]
 impl<T> Array<T>
@@ -17,6 +28,89 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
         val
     }
 
+    // NaN and Inf times zero is NaN
+    pub(crate) fn is_finite(val: T, z: T) -> bool {
+        val * z == z
+    }
+
+    // (nr, nc) for broadcasting
+    // Scalar is 1 x 1, Array1D is a row 1 x n
+    pub(crate) fn broadcast_dim(&self) -> Option<(usize, usize)> {
+        match self {
+            Array::Scalar(_) => Some((1, 1)),
+            Array::Array1D { arr } => Some((1, arr.len())),
+            Array::Array2D { nr, nc, .. } => Some((*nr, *nc)),
+            _ => None,
+        }
+    }
+
+    // value at (r, c), axis with length 1 is repeated
+    pub(crate) fn broadcast_get(&self, r: usize, c: usize) -> T {
+        match self {
+            Array::Scalar(x) => *x,
+            Array::Array1D { arr } => arr[if arr.len() == 1 {0} else {c}],
+            Array::Array2D { arr, nr, nc, put_val_by_row } => {
+                let r: usize = if *nr == 1 {0} else {r};
+                let c: usize = if *nc == 1 {0} else {c};
+                let idx: fn(usize, usize, (usize, usize)) -> usize = if *put_val_by_row {idxr} else {idxc};
+                arr[idx(r, c, (*nr, *nc))]
+            },
+            _ => T::default(),
+        }
+    }
+
+    // element-wise op(self, other) with numpy broadcasting
+    // every axis must be equal or 1,
+    // the result is Array2D if any side is Array2D (layout of self first),
+    // else Array1D if any side is Array1D, else Scalar
+    pub(crate) fn broadcast_op(
+        &mut self, other: &Self,
+        op: impl Fn(&mut T, T) -> Result<(), ListError>,
+    ) -> Result<(), ListError>
+    {
+        let (d1, d2) = match (self.broadcast_dim(), other.broadcast_dim()) {
+            (Some(d1), Some(d2)) => (d1, d2),
+            _ => return Err(ListError::MismatchedTypes),
+        };
+
+        let axis = |n1: usize, n2: usize| -> Option<usize> {
+            if n1 == n2 || n2 == 1 {Some(n1)}
+            else if n1 == 1 {Some(n2)}
+            else {None}
+        };
+        let dim: (usize, usize) = match (axis(d1.0, d2.0), axis(d1.1, d2.1)) {
+            (Some(nr), Some(nc)) => (nr, nc),
+            _ => return Err(ListError::ShapeMismatch { lhs: self.shape(), rhs: other.shape() }),
+        };
+        let (nr, nc) = dim;
+
+        let by_row: bool = match (&*self, other) {
+            (Array::Array2D { put_val_by_row, .. }, _) => *put_val_by_row,
+            (_, Array::Array2D { put_val_by_row, .. }) => *put_val_by_row,
+            _ => true,
+        };
+        let idx: fn(usize, usize, (usize, usize)) -> usize = if by_row {idxr} else {idxc};
+
+        let mut res: Vec<T> = vec![T::default(); nr * nc];
+        for r in 0..nr {
+            for c in 0..nc {
+                let mut val: T = self.broadcast_get(r, c);
+                op(&mut val, other.broadcast_get(r, c))?;
+                res[idx(r, c, dim)] = val;
+            }
+        }
+
+        *self = match (&*self, other) {
+            (Array::Array2D { .. }, _) | (_, Array::Array2D { .. })
+            => Array::Array2D { arr: res.into_boxed_slice(), nr, nc, put_val_by_row: by_row },
+            (Array::Array1D { .. }, _) | (_, Array::Array1D { .. })
+            => Array::Array1D { arr: res.into_boxed_slice() },
+            _ => Array::Scalar(res[0]),
+        };
+
+        Ok(())
+    }
+
     // matrix row permutation
 
     pub(crate) fn permute_r(
@@ -111,6 +205,7 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
     // row operation
     // (row j) minus (factor * row i)
     // from start element to end
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn row_i_add_frow_j(
         arr: &mut [T], factor: T, i: usize, j: usize,
         dim: (usize, usize), 
@@ -122,6 +217,7 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn row_i_minus_frow_j(
         arr: &mut [T], factor: T, i: usize, j: usize,
         dim: (usize, usize), 
@@ -151,6 +247,7 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
 
     // matrix add 
 
+    #[allow(clippy::needless_range_loop)]
     pub(crate) fn self_add_scalar_s (
         arr: &mut [T], s: T
     ) {
@@ -159,6 +256,7 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
         }
     }
 
+    #[allow(clippy::needless_range_loop)]
     pub(crate) fn self_mult_scalar_s (
         arr: &mut [T], s: T
     ) {
@@ -167,6 +265,7 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
         }
     }
 
+    #[allow(clippy::needless_range_loop)]
     pub(crate) fn self_div_scalar_s (
         arr: &mut [T], s: T) -> Result<(), ListError>
     {
@@ -323,24 +422,12 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
         ni: usize, 
         by_row_m1: bool, by_row_m2: bool) 
     {
-        let index1: fn(usize, usize, (usize, usize)) -> usize = if by_row_m1 { idxr } else { idxc };
-        let index2: fn(usize, usize, (usize, usize)) -> usize = if by_row_m2 { idxr } else { idxc };
-
-        for r in 0..new_dim.0 {
-            for c in 0..new_dim.1 {
-                let mut sum = T::default();
-                for i in 0..ni {
-                    sum += 
-                    
-                    m1[index1(r, i, (new_dim.0, ni))] 
-                    * 
-                    m2[index2(i, c, (ni, new_dim.1))];
-                    
-                }
+        // res (by row) = m1 m2 by the packed gemm kernel
+        let s1: Strided = Strided::new(new_dim.0, ni, by_row_m1);
+        let s2: Strided = Strided::new(ni, new_dim.1, by_row_m2);
+        let sr: Strided = Strided::new(new_dim.0, new_dim.1, true);
 
-                res[idxr(r, c, new_dim)] = sum;
-            }
-        }
+        Array::gemm_kernel(None, m1, s1, m2, s2, T::default(), res, sr);
     }
     
     pub(crate) fn vec_a_dot_vec_b (
@@ -424,6 +511,44 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
 + PartialEq + AddAssign + Copy + MulAssign + SubAssign
 + Default + PartialOrd + From<f32> + Sqrt
 {
+    // machine epsilon
+    // the smallest power of 2 that 1 + eps != 1
+    pub(crate) fn epsilon() -> T
+    {
+        let one: T = T::from(1.0_f32);
+        let two: T = T::from(2.0_f32);
+        let mut eps: T = one;
+        while one + eps / two != one {
+            eps = eps / two;
+        }
+        eps
+    }
+
+    // symmetric SQUARE matrix up to rounding,
+    //  |a[r, c] - a[c, r]| <= n eps max |a|
+    pub(crate) fn is_symmetric_tol(&self) -> bool {
+        match self {
+            Array::Array2D { arr, nr, nc, .. } => {
+                if *nr != *nc {return false;}
+                let z: T = T::default();
+                let mut amax: T = z;
+                for &v in arr.iter() {
+                    let v: T = Array::abs(v, z);
+                    if v > amax {amax = v;}
+                }
+                let tol: T = T::from(*nr as f32) * Array::epsilon() * amax;
+
+                for r in 0..*nr {
+                    for c in 0..r {
+                        if Array::abs(self[(r, c)] - self[(c, r)], z) > tol {return false;}
+                    }
+                }
+                true
+            },
+            _ => false,
+        }
+    }
+
     // distance norm 2
     
     pub(crate) fn dist_n2_vec_v1_v2(
@@ -482,6 +607,40 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
         Ok(())
     }
 
+    // solve A' x = b with p A = lu
+    // A' = U' L' p
+    // idx_t reads lu by the other index, which gives lu'
+    pub(crate) fn p_lu_solve_t(
+        lu: &[T], p: &[(usize, usize)], b: &[T], x: &mut [T],
+        dim: (usize, usize),
+        idx: fn(usize, usize, (usize, usize)) -> usize,
+        idx_t: fn(usize, usize, (usize, usize)) -> usize,
+    ) -> Result<(), ListError>
+    {
+        let (nr, _nc) = dim;
+        let mut y: Vec<T> = vec![T::default(); nr];
+
+        // U' y = b
+        let is_lu: bool = false;
+        Array::l_tri_solve(lu, b, &mut y, dim, idx_t, is_lu)?;
+
+        // L' w = y, unit diagonal
+        for r in (0..nr).rev() {
+            let mut sum = T::default();
+            for fi in (r+1)..nr {
+                sum += lu[idx(fi, r, dim)] * x[fi];
+            }
+            x[r] = y[r] - sum;
+        }
+
+        // x = p' w
+        for &(i, j) in p.iter().rev() {
+            x.swap(i, j);
+        }
+
+        Ok(())
+    }
+
     // solve Lx = b
     pub(crate) fn l_tri_solve(
         l: &[T], b: &[T],
@@ -519,7 +678,7 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
             if is_lu {
                 x[r] = b[r] - sum;
             } else {
-                x[r] = b[r] - sum / l[idx(r, r, dim)];
+                x[r] = (b[r] - sum) / l[idx(r, r, dim)];
             }
         }
 
@@ -598,7 +757,62 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
         }
     }
 
+    // ||v||_2 with scaling, no overflow for large elements
+    //  ||v||_2 = scale * sqrt(ssq)
+    pub(crate) fn norm_2_scaled(v: &[T]) -> T {
+        let z: T = T::default();
+        let one: T = T::from(1.0_f32);
+        let mut scale: T = z;
+        let mut ssq: T = one;
+        for &val in v.iter() {
+            let a: T = Array::abs(val, z);
+            if a == z {continue;}
+            if scale < a {
+                let t: T = scale / a;
+                ssq = one + ssq * t * t;
+                scale = a;
+            } else {
+                let t: T = a / scale;
+                ssq += t * t;
+            }
+        }
+        scale * ssq.sqrt()
+    }
+
+    // max column sum ||A||_1
+    pub(crate) fn mat_norm_1(
+        ma: &[T], dim: (usize, usize),
+        idx: fn(usize, usize, (usize, usize)) -> usize,
+    ) -> T {
+        let (nr, nc) = dim;
+        let z: T = T::default();
+        let mut res: T = z;
+        for c in 0..nc {
+            let mut sum: T = z;
+            for r in 0..nr {sum += Array::abs(ma[idx(r, c, dim)], z);}
+            if sum > res {res = sum;}
+        }
+        res
+    }
+
+    // max row sum ||A||_inf
+    pub(crate) fn mat_norm_inf(
+        ma: &[T], dim: (usize, usize),
+        idx: fn(usize, usize, (usize, usize)) -> usize,
+    ) -> T {
+        let (nr, nc) = dim;
+        let z: T = T::default();
+        let mut res: T = z;
+        for r in 0..nr {
+            let mut sum: T = z;
+            for c in 0..nc {sum += Array::abs(ma[idx(r, c, dim)], z);}
+            if sum > res {res = sum;}
+        }
+        res
+    }
+
     // compute vector norm ||v||_2
+    #[allow(clippy::needless_range_loop, clippy::needless_return)]
     pub(crate) fn norm_2(v1: &[T]) -> T{
         let mut d = v1[0] * v1[0];
         for i in 1..(v1.len()) {
@@ -607,6 +821,23 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
         return d.sqrt();
     }
 
+    // compute sqrt(a^2 + b^2) without overflow
+    pub(crate) fn pythag(a: T, b: T) -> T {
+        let z: T = T::default();
+        let one: T = T::from(1.0_f32);
+        let a: T = Array::abs(a, z);
+        let b: T = Array::abs(b, z);
+        if a > b {
+            let t: T = b / a;
+            a * (one + t * t).sqrt()
+        } else if b == z {
+            z
+        } else {
+            let t: T = a / b;
+            b * (one + t * t).sqrt()
+        }
+    }
+
     // find householder reflector
     pub(crate) fn reflector(y: &[T], reflector: &mut [T]) -> Result<(), ListError> 
     {
@@ -714,6 +945,7 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
         }
     }
 
+    #[allow(clippy::ptr_arg)]
     pub(crate) fn q_factor_dot_ma (
         q_factor: & Vec<Vec<T>>,
         ma: &mut [T],
@@ -725,6 +957,7 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
         } 
     }
 
+    #[allow(clippy::ptr_arg)]
     pub(crate) fn ma_dot_q_factor(
         ma: &mut [T],
         dim: (usize, usize),
@@ -744,6 +977,23 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
         by_row: bool
     ) -> Result<(Vec<Vec<T>>, Vec<T>), ListError> {
         let idx: fn(usize, usize, (usize, usize)) -> usize = if by_row {idxr} else {idxc};
+
+        // call sepcial case in qr householder
+        Array::qr_householder_with(ma, dim, by_row, |reflector, a_mat, _| {
+            Array::reflector_mat_dot_mat_hqr(reflector, a_mat, dim, idx);
+            Ok(())
+        })
+    }
+
+    // householder QR loop,
+    // apply(reflector, a_mat, c) applies H[c] to the columns c..
+    pub(crate) fn qr_householder_with(
+        ma: &[T],
+        dim: (usize, usize),
+        by_row: bool,
+        mut apply: impl FnMut(&[T], &mut [T], usize) -> Result<(), ListError>,
+    ) -> Result<(Vec<Vec<T>>, Vec<T>), ListError> {
+        let idx: fn(usize, usize, (usize, usize)) -> usize = if by_row {idxr} else {idxc};
         let (nr, nc) = dim;
         
         if nr < nc {panic!("nr must >= nc")}
@@ -765,8 +1015,7 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
                 continue;
             }
 
-            // call sepcial case in qr householder
-            Array::reflector_mat_dot_mat_hqr(&reflector, &mut a_mat, dim, idx);
+            apply(&reflector, &mut a_mat, c)?;
             q_factor.push(reflector);
         }
 
@@ -774,6 +1023,68 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
         Ok((q_factor, a_mat))
     }
 
+    // column pivoted QR (businger golub)
+    //  A P = H[1] H[2] ... H[k] r
+    // at step c the column with largest norm in r[c.., c..] is swapped to c,
+    // swaps (c, j) are recorded in pc in the order they are applied
+    // 
+    // return q_factor, r (nr x nc, same layout as ma) and pc
+    pub(crate) fn qr_householder_pivoted(
+        ma: &[T],
+        dim: (usize, usize),
+        by_row: bool
+    ) -> Result<PivotedQr<T>, ListError> {
+        let idx: fn(usize, usize, (usize, usize)) -> usize = if by_row {idxr} else {idxc};
+        let (nr, nc) = dim;
+        let k: usize = if nr < nc {nr} else {nc};
+
+        let mut a_mat: Box<[T]> = ma.to_vec().into_boxed_slice();
+        let mut q_factor: Vec<Vec<T>> = vec![];
+        let mut pc: Vec<(usize, usize)> = vec![];
+        let z = T::default();
+
+        for c in 0..k {
+            // squared norm of columns in r[c.., c..]
+            let mut maxc: usize = c;
+            let mut maxv: T = z;
+            for j in c..nc {
+                let mut val: T = z;
+                for i in c..nr {
+                    val += a_mat[idx(i, j, dim)] * a_mat[idx(i, j, dim)];
+                }
+                if maxv < val {
+                    maxc = j;
+                    maxv = val;
+                }
+            }
+
+            // the rest is zero
+            if maxv == z {break;}
+
+            if maxc != c {
+                Array::swap_c_ij(&mut a_mat, c, maxc, 0, nr, idx, dim);
+                pc.push((c, maxc));
+            }
+
+            let mut reflector: Vec<T> = vec![z; nr-c];
+            let mut v1: Vec<T> = vec![z; nr-c];
+            for i in c..nr {
+                v1[i-c] = a_mat[idx(i, c, dim)];
+            }
+
+            Array::reflector(&v1, &mut reflector)?;
+            Array::reflector_mat_dot_mat_hqr(&reflector, &mut a_mat, dim, idx);
+            q_factor.push(reflector);
+
+            // clean round off under diagonal
+            for i in (c+1)..nr {
+                a_mat[idx(i, c, dim)] = z;
+            }
+        }
+
+        Ok((q_factor, a_mat.into_vec(), pc))
+    }
+
     // transfer q_factor to a Q matrix
     pub(crate) fn get_qm(q_factor: &Vec<Vec<T>>, n: usize) -> Vec<T> {
         let mut ma: Vec<T> = vec![T::default(); n * n];
@@ -790,11 +1101,14 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
     }
 
     // Hessenberg_form
+    // A = Q H Q'
+    //  Q = H[1] H[2] ... H[n-2]
+    // ma becomes H and q_factor is returned
     pub(crate) fn hessenberg(
         ma: &mut [T],
         dim: (usize, usize),
         by_row: bool
-    ) -> Result<(), ListError>
+    ) -> Result<Vec<Vec<T>>, ListError>
     {
         let idx: fn(usize, usize, (usize, usize)) -> usize = if by_row {idxr} else {idxc};
         let (nr, nc) = dim;
@@ -802,8 +1116,9 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
         let n: usize = nr;
         let z: T = T::default();
         let two: T = T::from(2.0_f32);
+        let mut q_factor: Vec<Vec<T>> = vec![];
 
-        for c in 0..(n-2) {
+        for c in 0..n.saturating_sub(2) {
             let mut v1: Vec<T> = vec![z; nr-c-1];
             let mut reflector: Vec<T> = vec![z; nr-c-1];    
             for i in (c+1)..n {
@@ -842,17 +1157,37 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
                     ma[idx(i, j, dim)] -= two * sum * reflector[j-k];
                 }
             }
+
+            q_factor.push(reflector);
         }
 
-        Ok(())
+        Ok(q_factor)
     }
 
+    // real part of eigen values in descending order
     pub(crate) fn eigen_values(
         ma: &[T], dim: (usize, usize), 
         by_row: bool, 
         max_iter: Option<usize>,
         max_tol: Option<f32>
     ) -> Result<Vec<T>, ListError>
+    {
+        let (e_re, _e_im, _n_iter) = Array::eigen_values_qr(ma, dim, by_row, max_iter, max_tol)?;
+        Ok(e_re)
+    }
+
+    // francis double shift QR iteration on hessenberg form
+    // return real part, imaginary part of eigen values
+    // and number of iterations used
+    // 
+    // conjugate pairs are next to each other, (re, +im) then (re, -im)
+    #[allow(clippy::manual_unwrap_or)]
+    pub(crate) fn eigen_values_qr(
+        ma: &[T], dim: (usize, usize), 
+        by_row: bool, 
+        max_iter: Option<usize>,
+        max_tol: Option<f32>
+    ) -> Result<(Vec<T>, Vec<T>, usize), ListError>
     {
         let (nr,nc) = dim;
         if nr != nc {return Err(ListError::EigenMismatchedDim);}
@@ -863,65 +1198,221 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
 
         let n: usize = nr;
         let idx: fn(usize, usize, (usize, usize)) -> usize = if by_row {idxr} else {idxc};
-        let two:  T = T::from(2.0_f32);
-        let four: T = T::from(4.0_f32);
-        let z:    T = T::default();
+        let z: T = T::default();
 
         let n_iter: usize = 
         if let Some(ni) = max_iter {ni} else {10_000_usize};
 
-        let mtol: T =
-        if let Some(mt) = max_tol {T::from(mt)} else {T::from(1e-20_f32)};
+        // |val| is negligible compared with |s|
+        // without tolerance, val is negligible when s + val == s
+        let mtol: Option<T> = max_tol.map(T::from);
+        let negligible = |val: T, s: T| -> bool {
+            match mtol {
+                Some(tol) => val <= tol * s,
+                None => s + val == s,
+            }
+        };
+
+        // |a| with sign of b
+        let sign = |a: T, b: T| -> T {
+            let a = Array::abs(a, z);
+            if b < z {z - a} else {a}
+        };
+
+        let mut e_re: Vec<T> = vec![z; n];
+        let mut e_im: Vec<T> = vec![z; n];
+
+        // norm for the zero diagonal case
+        let mut anorm: T = z;
+        for r in 0..n {
+            for c in r.saturating_sub(1)..n {
+                anorm += Array::abs(mat_a[idx(r, c, dim)], z);
+            }
+        }
+
+        // active block is 0..nn
+        let mut nn: usize = n;
+        // accumulated exceptional shift
+        let mut t: T = z;
+        let mut iter: usize = 0;
+
+        while nn > 0 {
+            let mut its: usize = 0;
+            loop {
+                let hi = nn - 1;
+
+                // look for single small sub-diagonal element
+                let mut l = hi;
+                while l > 0 {
+                    let mut s = Array::abs(mat_a[idx(l-1, l-1, dim)], z) + Array::abs(mat_a[idx(l, l, dim)], z);
+                    if s == z {s = anorm;}
+                    if negligible(Array::abs(mat_a[idx(l, l-1, dim)], z), s) {
+                        mat_a[idx(l, l-1, dim)] = z;
+                        break;
+                    }
+                    l -= 1;
+                }
 
-        for _iter in 0..n_iter {
-            // check sub-diagnol whether or not close to zero
-            let mut isbreak = true;
-            for r in 0..nr-1 {
-                if Array::abs(mat_a[idx(r+1, r, dim)], z) > mtol {
-                    isbreak = false;
+                let mut x: T = mat_a[idx(hi, hi, dim)];
+
+                // one root found
+                if l == hi {
+                    e_re[hi] = x + t;
+                    nn -= 1;
+                    break;
+                }
+
+                let mut y: T = mat_a[idx(hi-1, hi-1, dim)];
+                let mut w: T = mat_a[idx(hi, hi-1, dim)] * mat_a[idx(hi-1, hi, dim)];
+
+                // two roots found
+                if l == hi - 1 {
+                    let p: T = (y - x) / T::from(2.0_f32);
+                    let q: T = p * p + w;
+                    let zz: T = Array::abs(q, z).sqrt();
+                    x += t;
+                    if q >= z {
+                        // real pair
+                        let zz = p + sign(zz, p);
+                        e_re[hi-1] = x + zz;
+                        e_re[hi] = x + zz;
+                        if zz != z {e_re[hi] = x - w / zz;}
+                    } else {
+                        // complex pair
+                        e_re[hi-1] = x + p;
+                        e_re[hi] = x + p;
+                        e_im[hi-1] = zz;
+                        e_im[hi] = z - zz;
+                    }
+                    nn -= 2;
                     break;
                 }
-            }
-            if isbreak {break;}
-
-            // wilkinson shift
-            let b11: T = mat_a[idx(n-2, n-2, dim)];
-            let b12: T = mat_a[idx(n-2, n-1, dim)];
-            let b21: T = mat_a[idx(n-1, n-2, dim)];
-            let b22: T = mat_a[idx(n-1, n-1, dim)];
-            let p2: T = (b11 - b22).powi(2) + four * b12 * b21;
-            let lambda1: T = (b11 + b22 + p2.sqrt()) / two;
-            let lambda2: T = (b11 + b22 - p2.sqrt()) / two;
-            let d1: T = Array::abs(lambda1 - b22, z);
-            let d2: T = Array::abs(lambda2 - b22, z);
-            let s: T = if d1 < d2 {lambda1} else {lambda2};
-
-            // make shift
-            for i in 0..n {
-                mat_a[idx(i, i, dim)] -= s
-            }
-            
-            let (qf, mut r) = Array::qr_householder(&mat_a, dim, by_row)?;
 
-            Array::ma_dot_q_factor(&mut r, dim, idx, &qf);
-            mat_a = r;
+                if iter == n_iter {return Err(ListError::EigenNotConverged);}
+
+                // exceptional shift
+                if its == 10 || its == 20 {
+                    t += x;
+                    for i in 0..nn {
+                        mat_a[idx(i, i, dim)] -= x;
+                    }
+                    let s = Array::abs(mat_a[idx(hi, hi-1, dim)], z) + Array::abs(mat_a[idx(hi-1, hi-2, dim)], z);
+                    x = T::from(0.75_f32) * s;
+                    y = x;
+                    w = T::from(-0.4375_f32) * s * s;
+                }
+                its += 1;
+                iter += 1;
+
+                // form shift and look for two consecutive
+                // small sub-diagonal elements
+                let mut m: usize = hi - 2;
+                let (mut p, mut q, mut r): (T, T, T);
+                loop {
+                    let zz = mat_a[idx(m, m, dim)];
+                    let rr = x - zz;
+                    let ss = y - zz;
+                    p = (rr * ss - w) / mat_a[idx(m+1, m, dim)] + mat_a[idx(m, m+1, dim)];
+                    q = mat_a[idx(m+1, m+1, dim)] - zz - rr - ss;
+                    r = mat_a[idx(m+2, m+1, dim)];
+                    let s = Array::abs(p, z) + Array::abs(q, z) + Array::abs(r, z);
+                    p = p / s;
+                    q = q / s;
+                    r = r / s;
+                    if m == l {break;}
+                    let u = Array::abs(mat_a[idx(m, m-1, dim)], z) * (Array::abs(q, z) + Array::abs(r, z));
+                    let v = Array::abs(p, z) * (
+                        Array::abs(mat_a[idx(m-1, m-1, dim)], z) + Array::abs(zz, z) + Array::abs(mat_a[idx(m+1, m+1, dim)], z)
+                    );
+                    if negligible(u, v) {break;}
+                    m -= 1;
+                }
 
-            // recover shift
-            for i in 0..n {
-                mat_a[idx(i, i, dim)] += s
+                for i in (m+2)..=hi {
+                    mat_a[idx(i, i-2, dim)] = z;
+                    if i != m+2 {mat_a[idx(i, i-3, dim)] = z;}
+                }
+
+                // double shift QR step on rows l..=hi and columns m..=hi
+                for k in m..hi {
+                    if k != m {
+                        p = mat_a[idx(k, k-1, dim)];
+                        q = mat_a[idx(k+1, k-1, dim)];
+                        r = if k+1 != hi {mat_a[idx(k+2, k-1, dim)]} else {z};
+                        x = Array::abs(p, z) + Array::abs(q, z) + Array::abs(r, z);
+                        if x != z {
+                            p = p / x;
+                            q = q / x;
+                            r = r / x;
+                        }
+                    }
+
+                    let s = sign((p * p + q * q + r * r).sqrt(), p);
+                    if s == z {continue;}
+
+                    if k == m {
+                        if l != m {
+                            mat_a[idx(k, k-1, dim)] = z - mat_a[idx(k, k-1, dim)];
+                        }
+                    } else {
+                        mat_a[idx(k, k-1, dim)] = z - s * x;
+                    }
+
+                    p += s;
+                    x = p / s;
+                    y = q / s;
+                    let zz = r / s;
+                    q = q / p;
+                    r = r / p;
+
+                    // row modification
+                    for j in k..=hi {
+                        let mut pp = mat_a[idx(k, j, dim)] + q * mat_a[idx(k+1, j, dim)];
+                        if k+1 != hi {
+                            pp += r * mat_a[idx(k+2, j, dim)];
+                            mat_a[idx(k+2, j, dim)] -= pp * zz;
+                        }
+                        mat_a[idx(k+1, j, dim)] -= pp * y;
+                        mat_a[idx(k, j, dim)] -= pp * x;
+                    }
+
+                    // column modification
+                    let mmin = if hi < k+3 {hi} else {k+3};
+                    for i in l..=mmin {
+                        let mut pp = x * mat_a[idx(i, k, dim)] + y * mat_a[idx(i, k+1, dim)];
+                        if k+1 != hi {
+                            pp += zz * mat_a[idx(i, k+2, dim)];
+                            mat_a[idx(i, k+2, dim)] -= pp * r;
+                        }
+                        mat_a[idx(i, k+1, dim)] -= pp * q;
+                        mat_a[idx(i, k, dim)] -= pp;
+                    }
+                }
             }
         }
 
-        let mut eigen_values: Vec<T> = vec![z; n];
-        for i in 0..n {
-            eigen_values[i] = mat_a[idx(i, i, dim)];
+        // NaN input never converges to a real spectrum
+        if e_re.iter().chain(e_im.iter()).any(|v| v.partial_cmp(v).is_none()) {
+            return Err(ListError::EigenNotConverged);
         }
 
-        eigen_values.sort_by(|a, b| b.partial_cmp(a).unwrap());
-        Ok(eigen_values)
+        // descending real part, then descending |im|,
+        // so a conjugate pair stays adjacent as (re, +im) then (re, -im)
+        let cmp = |a: T, b: T| a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| {
+            cmp(e_re[j], e_re[i])
+            .then(cmp(Array::abs(e_im[j], z), Array::abs(e_im[i], z)))
+            .then(cmp(e_im[j], e_im[i]))
+        });
 
+        let e_re: Vec<T> = order.iter().map(|&i| e_re[i]).collect();
+        let e_im: Vec<T> = order.iter().map(|&i| e_im[i]).collect();
+
+        Ok((e_re, e_im, iter))
     }
 
+    #[allow(clippy::needless_range_loop)]
     pub(crate) fn eigen_vector(
         ma: &[T],
         dim: (usize, usize),
@@ -944,11 +1435,26 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
         let mut mat_a: Box<[T]> = mat_a.into_boxed_slice();
 
         Array::p_lu(&mut p, &mut mat_a, dim, idx);        
-        let lu: Box<[T]> = mat_a;
+        let mut lu: Box<[T]> = mat_a;
+
+        // e_val is exact eigen value, U is singular
+        // perturb the zero pivot to keep inverse iteration going
+        let mut anorm: T = z;
+        for &v in ma.iter() {anorm += Array::abs(v, z);}
+        if anorm == z {anorm = T::from(1.0_f32);}
+        let eps: T = Array::epsilon();
+        let tiny: T = eps * anorm;
+        for i in 0..n {
+            if Array::abs(lu[idx(i, i, dim)], z) < tiny {lu[idx(i, i, dim)] = tiny;}
+        }
 
-        // make random vetor b
+        // start vector b[i] = (-1)^i (1 + ((i + ith) mod n) / n),
+        // a column of lu can miss the eigen space of a sparse matrix
         let mut b: Vec<T> = vec![z; nr];
-        for i in 0..nr {b[i] = lu[idx(i, ith, dim)];}
+        for i in 0..nr {
+            let val: T = T::from(1.0_f32) + T::from(((i + ith) % n) as f32) / T::from(n as f32);
+            b[i] = if i % 2 == 0 {val} else {z - val};
+        }
 
         let mtol: T = T::from(1e-15_f32);
         let max_iter: i32 = 10;
@@ -969,31 +1475,492 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
         Ok(b)
     }
 
+    // eigen vector x + iy of complex eigen value re + i im
+    //
+    // (A - re I) x + im y = 0
+    // -im x + (A - re I) y = 0
+    //
+    // solve the real 2n x 2n system
+    // | A - re I    im I    | | x |
+    // | -im I      A - re I | | y | = 0
+    pub(crate) fn eigen_vector_complex(
+        ma: &[T],
+        dim: (usize, usize),
+        idx: fn(usize, usize, (usize, usize)) -> usize,
+        e_re: T, e_im: T,
+        ith: usize,
+    ) -> Result<(Vec<T>, Vec<T>), ListError>
+    {
+        let (nr, nc) = dim;
+        if nr != nc {panic!("eigen vector needs SQUARE!")}
+        let n = nr;
+        let z: T = T::default();
+
+        let dim2: (usize, usize) = (2 * n, 2 * n);
+        let mut mat_m: Vec<T> = vec![z; 4 * n * n];
+        for r in 0..n {
+            for c in 0..n {
+                let val = ma[idx(r, c, dim)];
+                mat_m[idxr(r, c, dim2)] = val;
+                mat_m[idxr(n + r, n + c, dim2)] = val;
+            }
+            mat_m[idxr(r, r, dim2)] -= e_re;
+            mat_m[idxr(n + r, n + r, dim2)] -= e_re;
+            mat_m[idxr(r, n + r, dim2)] = e_im;
+            mat_m[idxr(n + r, r, dim2)] = z - e_im;
+        }
+
+        // [x; y] is unit vector
+        let xy: Vec<T> = Array::eigen_vector(&mat_m, dim2, idxr, z, ith)?;
+        let (x, y) = xy.split_at(n);
+
+        Ok((x.to_vec(), y.to_vec()))
+    }
+
+    // first column of each real eigen value or conjugate pair,
+    // a pair takes column c and c+1 and must be (re, +im) then (re, -im)
+    pub(crate) fn eigen_pair_starts(e_im: &[T]) -> Result<Vec<usize>, ListError> {
+        let z: T = T::default();
+        let n: usize = e_im.len();
+
+        let mut starts: Vec<usize> = vec![];
+        let mut c: usize = 0;
+        while c < n {
+            starts.push(c);
+            if e_im[c] == z {
+                c += 1;
+                continue;
+            }
+
+            // a complex eigen value without its conjugate next to it
+            if c + 1 >= n || e_im[c + 1] != z - e_im[c] {
+                return Err(ListError::EigenNotConverged);
+            }
+            c += 2;
+        }
+
+        Ok(starts)
+    }
+
+    // eigen vector of the real eigen value at column c,
+    // or real and imaginary part of the conjugate pair starting at column c
+    pub(crate) fn eigen_vector_at(
+        ma: &[T],
+        dim: (usize, usize),
+        idx: fn(usize, usize, (usize, usize)) -> usize,
+        e_re: &[T],
+        e_im: &[T],
+        c: usize,
+    ) -> Result<EigenVector<T>, ListError>
+    {
+        if e_im[c] == T::default() {
+            Ok((Array::eigen_vector(ma, dim, idx, e_re[c], c)?, None))
+        } else {
+            let (x, y) = Array::eigen_vector_complex(ma, dim, idx, e_re[c], e_im[c], c)?;
+            Ok((x, Some(y)))
+        }
+    }
+
+    // put x in column c and y (if any) in column c+1
+    pub(crate) fn put_eigen_vector(
+        evecs: &mut [T],
+        dim: (usize, usize),
+        idx: fn(usize, usize, (usize, usize)) -> usize,
+        c: usize,
+        vec: EigenVector<T>,
+    ) {
+        let (x, y) = vec;
+        for (i, &v) in x.iter().enumerate() {evecs[idx(i, c, dim)] = v;}
+        if let Some(y) = y {
+            for (i, &v) in y.iter().enumerate() {evecs[idx(i, c + 1, dim)] = v;}
+        }
+    }
+
+    // eigen vectors are put in column
+    // for conjugate pair at column c and c+1
+    // column c is real part and column c+1 is imaginary part
+    // of the eigen vector of (re, +im)
     pub(crate) fn eigen_vectors(
         ma: &[T],
         dim: (usize, usize),
         by_row: bool,
-        e_vals: &[T],
+        e_re: &[T],
+        e_im: &[T],
+    ) -> Result<Vec<T>, ListError>
+    {
+        Array::eigen_vectors_with(ma, dim, by_row, e_im, |h, idx, starts| {
+            starts.iter().map(|&c| Array::eigen_vector_at(h, dim, idx, e_re, e_im, c)).collect()
+        })
+    }
+
+    // hessenberg H = Q' A Q, then
+    // solve(H, idx, starts) gives the eigen vector of H
+    // for every real eigen value or conjugate pair starting at starts
+    pub(crate) fn eigen_vectors_with(
+        ma: &[T],
+        dim: (usize, usize),
+        by_row: bool,
+        e_im: &[T],
+        solve: impl FnOnce(&[T], fn(usize, usize, (usize, usize)) -> usize, &[usize]) -> Vec<Result<EigenVector<T>, ListError>>,
     ) -> Result<Vec<T>, ListError>
     {
         let idx: fn(usize, usize, (usize, usize)) -> usize = if by_row {idxr} else {idxc};
         let mut ma: Vec<T> = ma.to_vec();
-        Array::hessenberg(&mut ma, dim, by_row)?;
+        let q_factor: Vec<Vec<T>> = Array::hessenberg(&mut ma, dim, by_row)?;
 
         let (nr, nc) = dim;
         if nr != nc {panic!("eigen vector needs SQUARE!")}
         let n: usize = nr;
+        let z: T = T::default();
 
-        let mut evecs: Vec<T> = vec![T::default(); n * n];
+        let starts: Vec<usize> = Array::eigen_pair_starts(e_im)?;
+        let vecs: Vec<Result<EigenVector<T>, ListError>> = solve(&ma, idx, &starts);
 
-        for c in 0..n {
-            let evec = Array::eigen_vector(&ma, dim, idx, e_vals[c], c)?;
-            for i in 0..n {evecs[idx(i, c, dim)] = evec[i]}
+        let mut evecs: Vec<T> = vec![z; n * n];
+        for (&c, vec) in starts.iter().zip(vecs) {
+            Array::put_eigen_vector(&mut evecs, dim, idx, c, vec?);
         }
 
+        // H v = lambda v
+        // A Q v = Q H Q' Q v = lambda Q v
+        // eigen vectors of A are Q v
+        Array::q_factor_dot_ma(&q_factor, &mut evecs, dim, idx);
+
         Ok(evecs)
     }
 
+    // symmetric eigen problem
+    // A = Q T Q', T is tridiagonal (hessenberg form of symmetric matrix)
+    // implicit QR with wilkinson shift on T,
+    // the givens rotations are accumulated into Q
+    // 
+    // return ascending eigen values, 
+    // eigen vectors (row major, ith column for ith eigen value)
+    // and number of iterations used
+    pub(crate) fn sym_eigen(
+        ma: &[T], dim: (usize, usize),
+        by_row: bool,
+        max_iter: Option<usize>,
+    ) -> Result<(Vec<T>, Vec<T>, usize), ListError>
+    {
+        let (nr, nc) = dim;
+        if nr != nc {return Err(ListError::EigenMismatchedDim);}
+        let n: usize = nr;
+        let idx: fn(usize, usize, (usize, usize)) -> usize = if by_row {idxr} else {idxc};
+        let z: T = T::default();
+        let one: T = T::from(1.0_f32);
+        let two: T = T::from(2.0_f32);
+
+        let n_iter: usize = max_iter.unwrap_or(10_000_usize);
+
+        // tridiagonal form
+        let mut mat_t: Vec<T> = ma.to_vec();
+        let q_factor: Vec<Vec<T>> = Array::hessenberg(&mut mat_t, dim, by_row)?;
+        let mut qm: Vec<T> = Array::get_qm(&q_factor, n);
+
+        // d: diagonal, e: sub-diagonal
+        let mut d: Vec<T> = vec![z; n];
+        let mut e: Vec<T> = vec![z; n];
+        for i in 0..n {
+            d[i] = mat_t[idx(i, i, dim)];
+            if i + 1 < n {e[i] = mat_t[idx(i + 1, i, dim)];}
+        }
+
+        // |a| with sign of b
+        let sign = |a: T, b: T| -> T {
+            let a = Array::abs(a, z);
+            if b < z {z - a} else {a}
+        };
+
+        let mut iter: usize = 0;
+        for l in 0..n {
+            loop {
+                // look for small sub-diagonal element
+                let mut m: usize = l;
+                while m + 1 < n {
+                    let dd: T = Array::abs(d[m], z) + Array::abs(d[m + 1], z);
+                    if dd + Array::abs(e[m], z) == dd {break;}
+                    m += 1;
+                }
+                if m == l {break;}
+
+                if iter == n_iter {return Err(ListError::EigenNotConverged);}
+                iter += 1;
+
+                // wilkinson shift
+                let mut g: T = (d[l + 1] - d[l]) / (two * e[l]);
+                let mut r: T = Array::pythag(g, one);
+                g = d[m] - d[l] + e[l] / (g + sign(r, g));
+
+                let (mut s, mut c, mut p): (T, T, T) = (one, one, z);
+                let mut underflow: bool = false;
+
+                // chase the bulge by givens rotations
+                for i in (l..m).rev() {
+                    let f: T = s * e[i];
+                    let b: T = c * e[i];
+                    r = Array::pythag(f, g);
+                    e[i + 1] = r;
+                    if r == z {
+                        d[i + 1] -= p;
+                        e[m] = z;
+                        underflow = true;
+                        break;
+                    }
+                    s = f / r;
+                    c = g / r;
+                    g = d[i + 1] - p;
+                    r = (d[i] - g) * s + two * c * b;
+                    p = s * r;
+                    d[i + 1] = g + p;
+                    g = c * r - b;
+
+                    // Q = Q G
+                    for k in 0..n {
+                        let f: T = qm[idxr(k, i + 1, (n, n))];
+                        let qki: T = qm[idxr(k, i, (n, n))];
+                        qm[idxr(k, i + 1, (n, n))] = s * qki + c * f;
+                        qm[idxr(k, i, (n, n))] = c * qki - s * f;
+                    }
+                }
+                if underflow {continue;}
+
+                d[l] -= p;
+                e[l] = g;
+                e[m] = z;
+            }
+        }
+
+        // NaN or Inf input has no finite spectrum
+        if d.iter().any(|&v| !Array::is_finite(v, z)) {
+            return Err(ListError::EigenNotConverged);
+        }
+
+        // ascending eigen values
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| d[i].partial_cmp(&d[j]).unwrap_or(Ordering::Equal));
+
+        let e_vals: Vec<T> = order.iter().map(|&i| d[i]).collect();
+        let mut e_vecs: Vec<T> = vec![z; n * n];
+        for (c, &oc) in order.iter().enumerate() {
+            for r in 0..n {
+                e_vecs[idxr(r, c, (n, n))] = qm[idxr(r, oc, (n, n))];
+            }
+        }
+
+        Ok((e_vals, e_vecs, iter))
+    }
+
+    // singular value decomposition, nr >= nc
+    // A = U S V'
+    // 
+    // golub kahan bidiagonalization by householder reflectors
+    //  B = U1' A V1
+    // then implicit shifted QR on the bidiagonal B by givens rotations
+    // 
+    // return U (nr x nr), singular values (nc), V (nc x nc)
+    // and number of iterations used
+    // U and V are row major, singular values in descending order
+    pub(crate) fn golub_kahan_svd(
+        ma: &[T], dim: (usize, usize),
+        by_row: bool,
+        max_iter: Option<usize>,
+    ) -> Result<SvdFactors<T>, ListError>
+    {
+        let (m, n) = dim;
+        if m < n {panic!("nr must >= nc")}
+        let idx: fn(usize, usize, (usize, usize)) -> usize = if by_row {idxr} else {idxc};
+        let z: T = T::default();
+        let one: T = T::from(1.0_f32);
+        let two: T = T::from(2.0_f32);
+
+        let n_iter: usize = max_iter.unwrap_or(10_000_usize);
+
+        // bidiagonal form
+        // work on row major copy
+        let mut a: Vec<T> = vec![z; m * n];
+        for r in 0..m {
+            for c in 0..n {
+                a[idxr(r, c, dim)] = ma[idx(r, c, dim)];
+            }
+        }
+
+        let mut u_factor: Vec<Vec<T>> = vec![];
+        let mut v_factor: Vec<Vec<T>> = vec![];
+
+        for k in 0..n {
+            // zero kth column under (k, k)
+            let y: Vec<T> = (k..m).map(|r| a[idxr(r, k, dim)]).collect();
+            let mut reflector: Vec<T> = vec![z; m - k];
+            match Array::reflector(&y, &mut reflector) {
+                Ok(()) => {
+                    Array::reflector_mat_dot_mat(&reflector, &mut a, dim, idxr);
+                    u_factor.push(reflector);
+                },
+                Err(ListError::ReflectorZeroLength) => {},
+                Err(err) => return Err(err),
+            }
+
+            // zero kth row right to (k, k+1)
+            if k + 2 < n {
+                let y: Vec<T> = ((k+1)..n).map(|c| a[idxr(k, c, dim)]).collect();
+                let mut reflector: Vec<T> = vec![z; n - k - 1];
+                match Array::reflector(&y, &mut reflector) {
+                    Ok(()) => {
+                        Array::mat_dot_reflector_mat(&mut a, dim, idxr, &reflector);
+                        v_factor.push(reflector);
+                    },
+                    Err(ListError::ReflectorZeroLength) => {},
+                    Err(err) => return Err(err),
+                }
+            }
+        }
+
+        // w: diagonal
+        // rv1[i]: super-diagonal at (i-1, i)
+        let mut w: Vec<T> = vec![z; n];
+        let mut rv1: Vec<T> = vec![z; n];
+        for i in 0..n {
+            w[i] = a[idxr(i, i, dim)];
+            if i > 0 {rv1[i] = a[idxr(i-1, i, dim)];}
+        }
+
+        let dimu: (usize, usize) = (m, m);
+        let dimv: (usize, usize) = (n, n);
+        let mut um: Vec<T> = Array::get_qm(&u_factor, m);
+        let mut vm: Vec<T> = Array::get_qm(&v_factor, n);
+
+        // |a| with sign of b
+        let sign = |a: T, b: T| -> T {
+            let a = Array::abs(a, z);
+            if b < z {z - a} else {a}
+        };
+
+        let mut anorm: T = z;
+        for i in 0..n {
+            let val = Array::abs(w[i], z) + Array::abs(rv1[i], z);
+            if val > anorm {anorm = val;}
+        }
+        let eps: T = Array::epsilon();
+        let tol: T = eps * anorm;
+
+        // rotate column i, j of mat by (c, s)
+        let rotate = |mat: &mut Vec<T>, nrow: usize, dimm: (usize, usize), i: usize, j: usize, c: T, s: T| {
+            for r in 0..nrow {
+                let y: T = mat[idxr(r, i, dimm)];
+                let x: T = mat[idxr(r, j, dimm)];
+                mat[idxr(r, i, dimm)] = y * c + x * s;
+                mat[idxr(r, j, dimm)] = x * c - y * s;
+            }
+        };
+
+        let mut iter: usize = 0;
+        for k in (0..n).rev() {
+            loop {
+                // test for splitting
+                let mut l: usize = k;
+                let mut cancel: bool = true;
+                loop {
+                    if l == 0 || Array::abs(rv1[l], z) <= tol {cancel = false; break;}
+                    if Array::abs(w[l-1], z) <= tol {break;}
+                    l -= 1;
+                }
+
+                // w[l-1] is zero, cancel rv1[l]
+                if cancel {
+                    let nm: usize = l - 1;
+                    let (mut c, mut s): (T, T) = (z, one);
+                    for i in l..=k {
+                        let f: T = s * rv1[i];
+                        rv1[i] = c * rv1[i];
+                        if Array::abs(f, z) <= tol {break;}
+                        let g: T = w[i];
+                        let h: T = Array::pythag(f, g);
+                        w[i] = h;
+                        c = g / h;
+                        s = z - f / h;
+                        rotate(&mut um, m, dimu, nm, i, c, s);
+                    }
+                }
+
+                // converge
+                let zk: T = w[k];
+                if l == k {
+                    // make singular value non-negative
+                    if zk < z {
+                        w[k] = z - zk;
+                        for r in 0..n {vm[idxr(r, k, dimv)] = z - vm[idxr(r, k, dimv)];}
+                    }
+                    break;
+                }
+
+                if iter == n_iter {return Err(ListError::SvdNotConverged);}
+                iter += 1;
+
+                // shift from bottom 2 x 2 minor
+                let mut x: T = w[l];
+                let nm: usize = k - 1;
+                let mut y: T = w[nm];
+                let mut g: T = rv1[nm];
+                let mut h: T = rv1[k];
+                let mut f: T = ((y - zk) * (y + zk) + (g - h) * (g + h)) / (two * h * y);
+                g = Array::pythag(f, one);
+                f = ((x - zk) * (x + zk) + h * ((y / (f + sign(g, f))) - h)) / x;
+
+                // next QR transformation
+                let (mut c, mut s): (T, T) = (one, one);
+                for j in l..=nm {
+                    let i: usize = j + 1;
+                    g = rv1[i];
+                    y = w[i];
+                    h = s * g;
+                    g = c * g;
+                    let mut zz: T = Array::pythag(f, h);
+                    rv1[j] = zz;
+                    c = f / zz;
+                    s = h / zz;
+                    f = x * c + g * s;
+                    g = g * c - x * s;
+                    h = y * s;
+                    y *= c;
+                    rotate(&mut vm, n, dimv, j, i, c, s);
+
+                    zz = Array::pythag(f, h);
+                    w[j] = zz;
+                    if zz != z {
+                        c = f / zz;
+                        s = h / zz;
+                    }
+                    f = c * g + s * y;
+                    x = c * y - s * g;
+                    rotate(&mut um, m, dimu, j, i, c, s);
+                }
+                rv1[l] = z;
+                rv1[k] = f;
+                w[k] = x;
+            }
+        }
+
+        // NaN or Inf input has no finite singular values
+        if w.iter().any(|&v| !Array::is_finite(v, z)) {
+            return Err(ListError::SvdNotConverged);
+        }
+
+        // descending singular values
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| w[j].partial_cmp(&w[i]).unwrap_or(Ordering::Equal));
+
+        let sv: Vec<T> = order.iter().map(|&i| w[i]).collect();
+        let mut u: Vec<T> = um.clone();
+        let mut v: Vec<T> = vec![z; n * n];
+        for (c, &oc) in order.iter().enumerate() {
+            for r in 0..m {u[idxr(r, c, dimu)] = um[idxr(r, oc, dimu)];}
+            for r in 0..n {v[idxr(r, c, dimv)] = vm[idxr(r, oc, dimv)];}
+        }
+
+        Ok((u, sv, v, iter))
+    }
+
     pub(crate) fn qr(
         arr: &[T],
         dim: (usize, usize),
@@ -1068,23 +2035,19 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
         }
 
         if nr < nc {
-            for c in n..nc {
-                let mut b: Vec<T> = vec![T::default(); nr];
-                let mut res: Vec<T> = vec![T::default(); nr];
-                for i in 0..nr {
-                    b[i] = arr[idx(i, c, dim)];
-                }
-                // q (nr, nr)
-                // r (nr, nc)
-                // b (nc, 1)
-                // q x = b
-                
-                Array::q_solve(&qm, &b, &mut res, dimq, by_row);
-
-                for i in 0..nr {
-                    rm[idx(i, c, dim)] = res[i];
-                }
-            }
+            // q (nr, nr), r (nr, nc)
+            // r[.., n..] = q' a[.., n..] for the columns left
+            let sq: Strided = Strided::new(nr, n, by_row).op(Transpose::Trans);
+            let sa: Strided = Strided::new(nr, nc, by_row);
+            let sr: Strided = Strided::new(n, nc, by_row);
+            let sa_tail: Strided = Strided { nc: nc - n, ..sa };
+            let sr_tail: Strided = Strided { nc: nc - n, ..sr };
+
+            Array::gemm_kernel(
+                None, &qm, sq,
+                &arr[n * sa.cs..], sa_tail,
+                T::default(), &mut rm[n * sr.cs..], sr_tail,
+            );
         }
 
         (qm, rm)
@@ -1109,6 +2072,7 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
     // solve QR x = b
     // Q'Q Rx = Q'b
     // Rx = Q'b
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn qr_solve (
         res: &mut [T],
         qm: &[T], rm: &[T], 
@@ -1127,8 +2091,11 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
         Array::q_solve(qm, b, &mut y, dimq, by_row_q);
 
         //  solve Rx = y
-        let idx: fn(usize, usize, (usize, usize)) -> usize = if by_row_r {idxr} else {idxc};
-        Array::u_tri_solve(rm, &y, res, dimr, idx)?;
+        let (nr_r, nc_r) = dimr;
+        if nr_r != nc_r {return Err(ListError::NotSquareMat);}
+        res.copy_from_slice(&y);
+        let sr: Strided = Strided::new(nr_r, nc_r, by_row_r);
+        Array::trsm_kernel(Uplo::Upper, Diag::NonUnit, T::from(1.0_f32), rm, sr, res, Strided::new(nr_r, 1, true));
 
         Ok(())
     }
@@ -1137,6 +2104,7 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
 pub trait Sqrt {
     fn sqrt(self) -> Self;
     fn powi(self, n: i32) -> Self;
+    fn powf(self, n: Self) -> Self;
 }
 
 impl Sqrt for f32 {
@@ -1147,6 +2115,10 @@ impl Sqrt for f32 {
     fn powi(self, n: i32) -> Self {
         self.powi(n)
     }
+
+    fn powf(self, n: Self) -> Self {
+        self.powf(n)
+    }
 }
 
 impl Sqrt for f64 {
@@ -1157,4 +2129,8 @@ impl Sqrt for f64 {
     fn powi(self, n: i32) -> Self {
         self.powi(n)
     }
+
+    fn powf(self, n: Self) -> Self {
+        self.powf(n)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,61 @@
+// condition number
+
+use super::matrix_operations::Sqrt;
+use super::{Array, ListError, Norm};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
+
+impl<T> Array<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T> + Sub<Output=T>
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default + From<f32> + PartialOrd + Sqrt
+{
+    /// condition number ||A|| ||A^-1|| of SQUARE A,
+    /// L2 and Spectral are s_max / s_min from singular values,
+    /// infinity for singular A, EmptyArray for 0 x 0 A
+    /// ```
+    /// use simple_blas::array::{Array, Norm};
+    ///
+    /// let a = Array::new_array_2d(
+    ///     Box::new([1.0, 0.0, 0.0, 100.0_f64]), (2, 2), true
+    /// ).unwrap();
+    ///
+    /// assert!((a.mcond(Norm::L2).unwrap() - 100.0).abs() < 1e-10);
+    /// assert!((a.mrcond().unwrap() - 0.01).abs() < 1e-10);
+    /// ```
+    pub fn mcond(&self, norm: Norm) -> Result<T, ListError> {
+        match self {
+            Array::Array2D { arr, nr, nc, put_val_by_row }
+            => {
+                if *nr != *nc {return Err(ListError::NotSquareMat);}
+                if *nr == 0 {return Err(ListError::EmptyArray);}
+                let dim: (usize, usize) = (*nr, *nc);
+                let inf: T = T::from(f32::INFINITY);
+
+                if norm == Norm::L2 || norm == Norm::Spectral {
+                    let (_u, sv, _v, _n_iter) = Array::golub_kahan_svd(arr, dim, *put_val_by_row, None)?;
+                    let s_min: T = sv[sv.len() - 1];
+                    return Ok(if s_min == T::default() {inf} else {sv[0] / s_min});
+                }
+
+                let lu = self.mlu()?;
+                if lu.is_singular() {return Ok(inf);}
+
+                Ok(self.norm(norm)? * lu.inverse()?.norm(norm)?)
+            },
+
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+
+    /// estimate of 1 / cond(A) in 1-norm from the LU factors,
+    /// cheaper than mcond, close to 0 means ill conditioned
+    pub fn mrcond(&self) -> Result<T, ListError> {
+        match self {
+            Array::Array2D { .. }
+            => Ok(self.mlu()?.rcond(self.norm(Norm::L1)?)),
+
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+
+}
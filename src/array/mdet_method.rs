@@ -10,6 +10,7 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T> + Sub<Output=T>
 + PartialEq + AddAssign + Copy + MulAssign + SubAssign
 + Default + From<f32> + PartialOrd
 {
+    #[allow(clippy::needless_return)]
     pub fn mdet(&self) -> Result<T, ListError> {
         match self {
             Array::Array2D { arr, nr, nc, put_val_by_row} => {
@@ -41,7 +42,7 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T> + Sub<Output=T>
         // compute sign and UPPER TRIANGULAR matrix's determinant
         let mut det = T::from( 1.0_f32 );
         let num_swp = (pr.len() % 2) as i32 + (pc.len() % 2) as i32;
-        let sign = T::from( (1.0_f32).powi(num_swp) );
+        let sign = T::from( (-1.0_f32).powi(num_swp) );
 
         for i in 0..n {
             det *= arr[i * n + i];
@@ -136,6 +137,7 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T> + Sub<Output=T>
 + PartialEq + AddAssign + Copy + MulAssign + SubAssign
 + Default + PartialOrd
 {
+    #[allow(clippy::needless_return)]
     pub fn permute(&mut self) -> Result< Vec<(usize, usize)>, ListError > {
         match self {
             Array::Array2D { arr, nr, nc, put_val_by_row }
@@ -162,6 +164,7 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T> + Sub<Output=T>
         }
     }
 
+    #[allow(clippy::needless_return)]
     pub fn compute_dist(arr1: &Self, arr2: &Self) -> Result<T, ListError> {
         match (arr1, arr2) {
             (Array::Array2D { arr: arr1, nr, nc, put_val_by_row },
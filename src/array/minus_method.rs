@@ -33,16 +33,19 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
         Ok(())
     }
 
+    /// element-wise minus with numpy broadcasting,
+    /// Scalar is 1 x 1 and Array1D is a 1 x n row,
+    /// every axis must be equal or 1
     pub fn mminus(&mut self, other: &Self) -> Result<(), ListError> {
         match (self, other) {
             (Array::Array1D {arr: arr1, ..}, 
-             Array::Array1D {arr: other, ..}) => {
+             Array::Array1D {arr: other, ..}) if arr1.len() == other.len() => {
                 Array::self_minus_vec_v2(arr1, other)?;
             }, 
 
             (Array::Array2D { arr: arr1, nr: nr1, nc:nc1, put_val_by_row: by_row1 },
              Array::Array2D { arr: arr2, nr: nr2, nc:nc2, put_val_by_row: by_row2, ..})
-            => {
+            if (*nr1, *nc1) == (*nr2, *nc2) => {
                 let dim1: (usize, usize) = (*nr1, *nc1);
                 let dim2: (usize, usize) = (*nr2, *nc2);
                 
@@ -52,7 +55,8 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
                 Array::self_minus_mat_m(arr1, arr2, dim1, dim2, idx1, idx2)?;
             },
             
-            _ => return Err(ListError::MismatchedTypes),
+            // broadcast other shapes
+            (arr1, other) => arr1.broadcast_op(other, |x, y| {*x -= y; Ok(())})?,
         }
 
         Ok(())
@@ -1,9 +1,8 @@
 // matrix inverse
 
 use super::matrix_operations::Sqrt;
-use super::Array;
-use super::ListError;
-use std::mem;
+use super::{Array, Diag, ListError, Uplo};
+use super::gemm_method::Strided;
 use std::ops::Sub;
 use std::ops::{Add, AddAssign, Div, Mul, MulAssign, SubAssign};
 
@@ -20,8 +19,7 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T> + Sub<Output=T>
             => {
                 if nr != nc {return Err(ListError::MatrixInvDimError);}
 
-                // replace
-                let mut lu: Box<[T]> = mem::replace(arr, arr.clone());
+                let mut lu: Box<[T]> = arr.clone();
 
                 // p-lu
                 // p A = lu
@@ -40,46 +38,26 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T> + Sub<Output=T>
 
                 // now we have p, l, u
                 // and A is invertible
-                // to solve Y, we solve every column of y
-
-                let y: Vec<T> = vec![T::default(); (*nr) * (*nc)];
-                let mut y: Box<[T]> = y.into_boxed_slice();
-
-                for c in 0..(*nc) {
-                    // ei: ith column on Identity matrix
-                    let mut ei = vec![T::default(); *nr];
-                    ei[c] = T::from(1.0_f32);
-                    
-                    // b = p * ei
-                    for &(i, j) in p.iter() {
-                        (ei[i], ei[j]) = (ei[j], ei[i]);
-                    }
-
-                    let is_lu: bool = true;
-                    // Y's column
-                    // assumne put val by col
-                    // 0st column idx is 0..(nr)
-                    // 1nd colunm idx is nr..(2nr)
-                    let start: usize = c * (*nr);
-                    let end: usize = start + *nr;
-                    let yc = &mut y[start..end];
-                    Array::l_tri_solve(&lu, &ei, yc, dim, idx, is_lu)?;
+                // A^-1 = U^-1 L^-1 p, solve all columns of p I at once
+
+                let z: T = T::default();
+                let mut x: Box<[T]> = vec![z; (*nr) * (*nc)].into_boxed_slice();
+                for i in 0..(*nr) {
+                    x[idx(i, i, dim)] = T::from(1.0_f32);
                 }
-                
-                for c in 0..(*nc) {
-                    let start: usize = c * (*nr);
-                    let end: usize = start + *nr;
-                    let yc = &y[start..end];
-                    let mut xc = vec![T::default(); *nr];
-
-                    // solve Uxc = yc
-                    Array::u_tri_solve(&lu, yc, xc.as_mut_slice(), dim, idx)?;
-                    
-                    // write result
-                    for (i, xc_i) in xc.into_iter().enumerate() {
-                        arr[idx(i, c, dim)] = xc_i;
-                    }
+
+                // p I
+                for &(i, j) in p.iter() {
+                    Array::swap_r_ij(&mut x, i, j, 0, *nc, idx, dim);
                 }
+
+                // L Y = p I, then U X = Y
+                let s: Strided = Strided::new(*nr, *nc, *put_val_by_row);
+                let one: T = T::from(1.0_f32);
+                Array::trsm_kernel(Uplo::Lower, Diag::Unit, one, &lu, s, &mut x, s);
+                Array::trsm_kernel(Uplo::Upper, Diag::NonUnit, one, &lu, s, &mut x, s);
+
+                *arr = x;
             },
 
             _ => return Err(ListError::MismatchedTypes),
@@ -87,6 +65,38 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T> + Sub<Output=T>
         Ok(())
     }
 
+    /// same as minv, but fails with IllConditioned
+    /// when the estimated 1 / cond(A) in 1-norm is below min_rcond
+    /// ```
+    /// use simple_blas::array::{Array, ListError};
+    ///
+    /// let mut a = Array::new_array_2d(
+    ///     Box::new([1.0, 1.0, 1.0, 1.0 + 1e-12_f64]), (2, 2), true
+    /// ).unwrap();
+    ///
+    /// assert_eq!(a.minv_rcond(1e-8), Err(ListError::IllConditioned));
+    /// ```
+    pub fn minv_rcond(&mut self, min_rcond: T) -> Result<(), ListError> {
+        let (lu, anorm) = match &*self {
+            Array::Array2D { arr, nr, nc, put_val_by_row }
+            => {
+                if nr != nc {return Err(ListError::MatrixInvDimError);}
+
+                let idx: fn(usize, usize, (usize, usize)) -> usize = if *put_val_by_row {idxr} else {idxc};
+                let anorm: T = Array::mat_norm_1(arr, (*nr, *nc), idx);
+                (self.mlu()?, anorm)
+            },
+
+            _ => return Err(ListError::MismatchedTypes),
+        };
+
+        if lu.is_singular() {return Err(ListError::SingularMat);}
+        if lu.rcond(anorm) < min_rcond {return Err(ListError::IllConditioned);}
+
+        *self = lu.inverse()?;
+        Ok(())
+    }
+
 }
 
 
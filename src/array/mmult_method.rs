@@ -3,16 +3,23 @@
 use std::mem;
 use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
 
-use super::{idxc, idxr, Array};
+use super::gemm_method::Strided;
+use super::{Array, ExecContext};
 use super::ListError;
-use rayon::{prelude::*, ThreadPoolBuilder};
 
 impl<T> Array<T>
 where T: Add<Output=T> + Mul<Output=T> + Div<Output=T> 
 + PartialEq + AddAssign + Copy + MulAssign + SubAssign
 + Default + Sub<Output=T> + PartialOrd
 {
+    #[allow(clippy::needless_borrow)]
     pub fn mmult(&mut self, other: &Self) -> Result<(), ListError> {
+        // batched over the leading axes
+        if let (Self::ArrayND { .. }, Self::ArrayND { .. }) = (&*self, other) {
+            *self = self.mmult_batched(other)?;
+            return Ok(());
+        }
+
         match (self, other) {
             (Self::Array2D { arr: arr1, nr: nr1, nc: nc1, put_val_by_row: by_row1}, 
              Self::Array2D { arr: arr2, nr: nr2, nc: nc2, put_val_by_row: by_row2}) => {
@@ -58,9 +65,16 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
 impl<T> Array<T>
 where T: Add<Output=T> + Mul<Output=T> + Div<Output=T> 
 + PartialEq + AddAssign + Copy + MulAssign + SubAssign
-+ Default + Sync + Send
++ Default + Sub<Output=T> + PartialOrd + Sync + Send
 {
+    /// parallel mmult, blocks of rows of the result
+    /// run the packed gemm kernel in parallel
     pub fn mmult_speed(&mut self, other: &Self) -> Result<(), ListError> {
+        self.mmult_speed_with(&ExecContext::global(), other)
+    }
+
+    /// mmult_speed in ctx
+    pub fn mmult_speed_with(&mut self, ctx: &ExecContext, other: &Self) -> Result<(), ListError> {
         match (self, other) {
             (Self::Array2D { arr: arr1, nr: nr1, nc: nc1, put_val_by_row: by_row1}, 
              Self::Array2D { arr: arr2, nr: nr2, nc: nc2, put_val_by_row: by_row2}) => {
@@ -83,7 +97,11 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
                 // replace by_row
                 let old_by_row = mem::replace(by_row1, true);
 
-                Array::matrix_mult_speed(arr1, arr2, old_arr, new_dim, ni, old_by_row, *by_row2);
+                // res (by row) = old_arr arr2
+                let s1: Strided = Strided::new(new_dim.0, ni, old_by_row);
+                let s2: Strided = Strided::new(ni, new_dim.1, *by_row2);
+                let sr: Strided = Strided::new(new_dim.0, new_dim.1, true);
+                Array::gemm_kernel_speed(ctx, None, &old_arr, s1, arr2, s2, T::default(), arr1, sr)?;
 
             },
             
@@ -92,36 +110,4 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
 
         Ok(())
     }
-
-    fn matrix_mult_speed(
-        arr1: &mut Box<[T]>, arr2: &Box<[T]>, old_arr: Box<[T]>, 
-        new_dim: (usize, usize), 
-        ni: usize, 
-        old_by_row: bool, by_row2: bool) 
-    {
-        let pool = ThreadPoolBuilder::new()
-            .num_threads(6)
-            .build()
-            .unwrap();
-
-        let index_old: fn(usize, usize, (usize, usize)) -> usize =  if old_by_row { idxr } else { idxc };
-        let index_other: fn(usize, usize, (usize, usize)) -> usize = if by_row2 { idxr } else { idxc };
-        
-        pool.install(|| {
-            arr1.par_chunks_mut(new_dim.1).enumerate().for_each(|(r, row)| {
-                for c in 0..new_dim.0 {
-                    let mut sum = T::default();
-                    for i in 0..ni {
-                        sum += 
-                        
-                        old_arr[index_old(r, i, (new_dim.0, ni))] 
-                        * 
-                        arr2[index_other(i, c, (ni, new_dim.1))];
-                    }
-
-                    row[c] = sum;
-                }
-            })
-        });
-    }
 }
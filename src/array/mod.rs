@@ -6,19 +6,54 @@ mod minus_method;
 mod mult_method;
 mod div_method;
 mod convert_method;
+mod value_method;
+mod blas1_method;
+mod view_method;
+mod nd_method;
+mod stats_method;
 
 mod matrix_operations;
 
 // for matrix
 mod mmult_method;
+mod gemm_method;
+mod blas2_method;
+mod blas3_method;
 mod mdet_method;
 mod minv_method;
+mod mpinv_method;
 mod qr_method;
+mod msolve_method;
+mod lu_method;
+mod lstsq_method;
+mod cholesky_method;
 mod eigen_method;
+mod svd_method;
+mod mcond_method;
+mod norm_method;
+
+mod exec_context;
+mod speed_method;
 
 mod index_trait;
+mod ops_trait;
 mod display_trait;
 
+pub use eigen_method::Eigen;
+pub use svd_method::Svd;
+pub use lu_method::LuFactorization;
+pub use cholesky_method::Ldl;
+pub use lstsq_method::Lstsq;
+pub use qr_method::QrPivoted;
+pub use norm_method::Norm;
+pub use gemm_method::Transpose;
+pub use blas1_method::{VecView, VecViewMut};
+pub use view_method::{ArrayView, ArrayViewMut};
+pub use blas2_method::{Uplo, Diag};
+pub use blas3_method::Side;
+pub use stats_method::Summation;
+pub use exec_context::{ExecContext, Parallelism};
+
 /// ## Possibe Error types
 #[derive(Debug, PartialEq)]
 pub enum ListError {
@@ -33,7 +68,17 @@ pub enum ListError {
     NotSquareMat,
     MatrixQRHouseHolderDimError,
     EigenMismatchedDim,
+    EigenNotConverged,
+    NotSymmetricMat,
+    SvdNotConverged,
+    NotPositiveDefinite,
+    IllConditioned,
+    InvalidNorm,
+    ShapeMismatch { lhs: Vec<usize>, rhs: Vec<usize> },
+    InvalidStride,
+    OutOfBounds,
     ReflectorZeroLength,
+    EmptyArray,
 }
 
 /// index for put value by row
@@ -46,12 +91,16 @@ pub(crate) fn idxc(r: usize, c: usize, dim: (usize, usize)) -> usize {
     c * dim.0 + r
 }
 
-/// ## Three possible Type (Now, Matrix will add later)
+/// ## Possible Types
 /// -1 Null: store nothing
 /// 
 /// -2 Scalar: store a value
 /// 
 /// -3 Array1D: store 1 dimension vector
+/// 
+/// -4 Array2D: store a matrix, put by row or by column
+/// 
+/// -5 ArrayND: store N dimensions with shape and strides, C or F order
 /// ```
 /// use simple_blas::array::Array;
 /// 
@@ -69,7 +118,7 @@ pub(crate) fn idxc(r: usize, c: usize, dim: (usize, usize)) -> usize {
 /// println!("{:?}", arr_1d);
 /// ```
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Array<T>
 where T:
 Add<Output=T> + Mul<Output=T> + Div<Output=T> 
@@ -88,6 +137,16 @@ Add<Output=T> + Mul<Output=T> + Div<Output=T>
         arr: Box<[T]>, 
         nr: usize, nc: usize, 
         put_val_by_row: bool 
+    },
+
+    // Tensor
+    // shape: length of each axis
+    // strides: step in arr of each axis,
+    // C order has the last axis contiguous, F order the first
+    ArrayND {
+        arr: Box<[T]>,
+        shape: Vec<usize>,
+        strides: Vec<usize>,
     }
 }
 
@@ -138,6 +197,35 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
         )
     }
 
+    /// ArrayND of the given shape put in C or F order,
+    /// C order has the last axis contiguous
+    /// ```
+    /// use simple_blas::array::Array;
+    ///
+    /// let a = Array::new_array_nd(Box::new([1, 2, 3, 4, 5, 6]), &[3, 2], false).unwrap();
+    /// assert_eq!(a[[1, 1]], 5);
+    /// ```
+    pub fn new_array_nd(arr: Box<[T]>, shape: &[usize], c_order: bool) -> Result<Self, ListError> {
+        if shape.iter().product::<usize>() != arr.len() {
+            return Err(ListError::MismatchedDim);
+        }
+
+        Ok(
+            Array::ArrayND { arr, shape: shape.to_vec(), strides: nd_method::nd_strides(shape, c_order) }
+        )
+    }
+
+    /// [] for Null and Scalar, [n] for Array1D, [nr, nc] for Array2D,
+    /// shape for ArrayND
+    pub fn shape(&self) -> Vec<usize> {
+        match self {
+            Array::Array1D { arr } => vec![arr.len()],
+            Array::Array2D { nr, nc, .. } => vec![*nr, *nc],
+            Array::ArrayND { shape, .. } => shape.clone(),
+            _ => vec![],
+        }
+    }
+
     pub fn transpose(&mut self) -> Result<(), ListError>{
         match self {
             Array::Array2D { arr: _arr, nr, nc, put_val_by_row }
@@ -187,6 +275,10 @@ where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
 
                 true
             }, 
+
+            (Self::ArrayND { shape: shape1, .. },
+             Self::ArrayND { shape: shape2, .. })
+                => shape1 == shape2 && self.nd_values() == other.nd_values(),
             
             _ =>  panic!("cannot compare!!"),
         }
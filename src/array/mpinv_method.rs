@@ -0,0 +1,80 @@
+// moore penrose pseudo inverse
+
+use super::matrix_operations::Sqrt;
+use super::{Array, ListError};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
+
+use crate::array::{idxr, idxc};
+
+impl<T> Array<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T> + Sub<Output=T>
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default + From<f32> + PartialOrd + Sqrt
+{
+    /// pseudo inverse A+ = V S+ U' for any nr x nc matrix,
+    /// singular values s <= rcond * s_max are treated as zero,
+    /// default rcond is max(nr, nc) * epsilon
+    ///
+    /// return a new nc x nr Array2D in the same layout
+    /// ```
+    /// use simple_blas::array::Array;
+    ///
+    /// let a = Array::new_array_2d(
+    ///     Box::new([1.0, 2.0, 2.0, 4.0_f64]), (2, 2), true
+    /// ).unwrap();
+    ///
+    /// // singular, A+ = A' / 25
+    /// let a_pinv = a.mpinv(None).unwrap();
+    /// assert!((a_pinv[(0, 1)] - 0.08).abs() < 1e-10);
+    /// assert!((a_pinv[(0, 0)] - 0.04).abs() < 1e-10);
+    /// ```
+    pub fn mpinv(&self, rcond: Option<T>) -> Result<Self, ListError> {
+        match self {
+            Array::Array2D { arr, nr, nc, put_val_by_row }
+            => {
+                let (m, n) = (*nr, *nc);
+                let by_row: bool = *put_val_by_row;
+                let idx: fn(usize, usize, (usize, usize)) -> usize = if by_row {idxr} else {idxc};
+                let z: T = T::default();
+
+                // A = U S V', U and V are row major
+                let (u, sv, v, _n_iter) = if m >= n {
+                    Array::golub_kahan_svd(arr, (m, n), by_row, None)?
+                } else {
+                    // A' = U1 S V1', A = V1 S U1'
+                    let (u1, sv, v1, n_iter) = Array::golub_kahan_svd(arr, (n, m), !by_row, None)?;
+                    (v1, sv, u1, n_iter)
+                };
+                let dimu: (usize, usize) = (m, m);
+                let dimv: (usize, usize) = (n, n);
+
+                let rcond: T = match rcond {
+                    Some(rcond) => rcond,
+                    None => {
+                        let eps: T = Array::epsilon();
+                        T::from(if m < n {n as f32} else {m as f32}) * eps
+                    },
+                };
+                let tol: T = if sv.is_empty() {z} else {rcond * sv[0]};
+                let rank: usize = sv.iter().filter(|&&s| s > tol).count();
+
+                // A+[r, c] = sum v[r, i] u[c, i] / s[i]
+                let dim: (usize, usize) = (n, m);
+                let mut res: Vec<T> = vec![z; n * m];
+                for r in 0..n {
+                    for c in 0..m {
+                        let mut sum: T = z;
+                        for i in 0..rank {
+                            sum += v[idxr(r, i, dimv)] * u[idxr(c, i, dimu)] / sv[i];
+                        }
+                        res[idx(r, c, dim)] = sum;
+                    }
+                }
+
+                Array::new_array_2d(res.into_boxed_slice(), (n as isize, m as isize), by_row)
+            },
+
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+}
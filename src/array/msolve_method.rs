@@ -0,0 +1,89 @@
+// solve linear system
+
+use super::matrix_operations::Sqrt;
+use super::{Array, ListError};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
+
+use crate::array::{idxr, idxc};
+
+impl<T> Array<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T> + Sub<Output=T>
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default + From<f32> + PartialOrd + Sqrt
+{
+    /// solve A x = b for SQUARE A by LU with partial pivoting,
+    /// b is Array1D for one right-hand side,
+    /// or Array2D for many right-hand sides in columns
+    /// ```
+    /// use simple_blas::array::Array;
+    ///
+    /// let a = Array::new_array_2d(
+    ///     Box::new([2.0, 1.0, 1.0, 3.0_f64]), (2, 2), true
+    /// ).unwrap();
+    /// let b = Array::new_array_1d(Box::new([3.0, 5.0_f64]));
+    ///
+    /// let x = a.msolve(&b).unwrap();
+    /// assert!((x[0] - 0.8).abs() < 1e-10);
+    /// assert!((x[1] - 1.4).abs() < 1e-10);
+    /// ```
+    pub fn msolve(&self, b: &Self) -> Result<Self, ListError> {
+        self.mlu()?.solve(b)
+    }
+
+    // call solve(b, x) for every right-hand side b
+    // b: Array1D or column of Array2D, with n rows
+    // x is put in the same type and layout as b
+    pub(crate) fn solve_rhs(
+        b: &Self, n: usize,
+        solve: impl FnMut(&mut [T], &mut [T]) -> Result<(), ListError>,
+    ) -> Result<Self, ListError>
+    {
+        Array::solve_rhs_rect(b, n, n, solve)
+    }
+
+    // same as solve_rhs for A: m x n,
+    // b has m rows and x has n rows
+    pub(crate) fn solve_rhs_rect(
+        b: &Self, m: usize, n: usize,
+        mut solve: impl FnMut(&mut [T], &mut [T]) -> Result<(), ListError>,
+    ) -> Result<Self, ListError>
+    {
+        match b {
+            Array::Array1D { arr: b }
+            => {
+                if b.len() != m {return Err(ListError::ShapeMismatch { lhs: vec![m, n], rhs: vec![b.len()] });}
+                let mut bc: Vec<T> = b.to_vec();
+                let mut x: Vec<T> = vec![T::default(); n];
+                solve(&mut bc, &mut x)?;
+
+                Ok(Array::new_array_1d(x.into_boxed_slice()))
+            },
+
+            Array::Array2D { arr: b, nr: nr_b, nc: nc_b, put_val_by_row: by_row_b }
+            => {
+                if *nr_b != m {return Err(ListError::ShapeMismatch { lhs: vec![m, n], rhs: vec![*nr_b, *nc_b] });}
+                let dim_b: (usize, usize) = (*nr_b, *nc_b);
+                let dim_x: (usize, usize) = (n, *nc_b);
+                let idx_b: fn(usize, usize, (usize, usize)) -> usize = if *by_row_b {idxr} else {idxc};
+
+                // solve every column of b
+                let mut res: Vec<T> = vec![T::default(); n * (*nc_b)];
+                let mut bc: Vec<T> = vec![T::default(); m];
+                let mut x: Vec<T> = vec![T::default(); n];
+                for c in 0..(*nc_b) {
+                    for r in 0..m {bc[r] = b[idx_b(r, c, dim_b)];}
+                    solve(&mut bc, &mut x)?;
+                    for r in 0..n {res[idx_b(r, c, dim_x)] = x[r];}
+                }
+
+                Array::new_array_2d(
+                    res.into_boxed_slice(),
+                    (n as isize, *nc_b as isize),
+                    *by_row_b
+                )
+            },
+
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+}
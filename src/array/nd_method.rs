@@ -0,0 +1,301 @@
+// N dimensional arrays
+//
+// ArrayND keeps a shape and a stride per axis:
+//  a[i0, i1, ..] = arr[i0 * s0 + i1 * s1 + ..]
+// so permute_axes only moves strides, reshape and
+// reductions read the elements in C order
+
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
+
+use super::gemm_method::Strided;
+use super::{Array, ListError};
+
+// strides of a contiguous array, C order has the last axis contiguous
+pub(crate) fn nd_strides(shape: &[usize], c_order: bool) -> Vec<usize> {
+    let mut strides: Vec<usize> = vec![0; shape.len()];
+    let mut step: usize = 1;
+    if c_order {
+        for k in (0..shape.len()).rev() {
+            strides[k] = step;
+            step *= shape[k];
+        }
+    } else {
+        for k in 0..shape.len() {
+            strides[k] = step;
+            step *= shape[k];
+        }
+    }
+    strides
+}
+
+// buffer positions of all elements in C order
+pub(crate) fn c_order_pos(shape: &[usize], strides: &[usize]) -> Vec<usize> {
+    let n: usize = shape.iter().product();
+    let mut pos: Vec<usize> = Vec::with_capacity(n);
+    if n == 0 {return pos;}
+
+    let mut idx: Vec<usize> = vec![0; shape.len()];
+    let mut p: usize = 0;
+    for _ in 0..n {
+        pos.push(p);
+        // next index, last axis first
+        for k in (0..shape.len()).rev() {
+            idx[k] += 1;
+            p += strides[k];
+            if idx[k] < shape[k] {break;}
+            p -= idx[k] * strides[k];
+            idx[k] = 0;
+        }
+    }
+    pos
+}
+
+impl<T> Array<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default
+{
+    // buffer position of a[idx], None when out of bound
+    pub(crate) fn nd_pos(&self, idx: &[usize]) -> Option<usize> {
+        match self {
+            Array::ArrayND { shape, strides, .. } => {
+                if idx.len() != shape.len() {return None;}
+                let mut p: usize = 0;
+                for k in 0..idx.len() {
+                    if idx[k] >= shape[k] {return None;}
+                    p += idx[k] * strides[k];
+                }
+                Some(p)
+            },
+            _ => None,
+        }
+    }
+
+    // elements of an ArrayND in C order
+    pub(crate) fn nd_values(&self) -> Vec<T> {
+        match self {
+            Array::ArrayND { arr, shape, strides } => c_order_pos(shape, strides).into_iter().map(|p| arr[p]).collect(),
+            _ => vec![],
+        }
+    }
+}
+
+impl<T> Array<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
++ Sub<Output=T> + PartialOrd
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default
+{
+    /// a[idx] of an ArrayND
+    /// ```
+    /// use simple_blas::array::Array;
+    ///
+    /// // shape (2, 3, 2) put in F order
+    /// let a = Array::new_array_nd((0..12).collect::<Vec<i32>>().into_boxed_slice(), &[2, 3, 2], false).unwrap();
+    ///
+    /// assert_eq!(a.get_nd(&[1, 2, 0]).unwrap(), 5);
+    /// assert_eq!(a[[1, 0, 1]], 7);
+    /// ```
+    pub fn get_nd(&self, idx: &[usize]) -> Result<T, ListError> {
+        match self {
+            Array::ArrayND { arr, shape, .. } => {
+                if idx.len() != shape.len() {return Err(ListError::MismatchedDim);}
+                match self.nd_pos(idx) {
+                    Some(p) => Ok(arr[p]),
+                    None => Err(ListError::OutOfBounds),
+                }
+            },
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+
+    /// Array1D and Array2D as an ArrayND with the same strides
+    pub fn to_array_nd(&self) -> Result<Self, ListError> {
+        match self {
+            Array::Array1D { arr } => Ok(Array::ArrayND { arr: arr.clone(), shape: vec![arr.len()], strides: vec![1] }),
+            Array::Array2D { arr, nr, nc, put_val_by_row } => {
+                let s: Strided = Strided::new(*nr, *nc, *put_val_by_row);
+                Ok(Array::ArrayND { arr: arr.clone(), shape: vec![*nr, *nc], strides: vec![s.rs, s.cs] })
+            },
+            Array::ArrayND { .. } => Ok(self.clone()),
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+
+    /// ArrayND of 2 axes as an Array2D
+    pub fn to_array_2d(&self) -> Result<Self, ListError> {
+        match self {
+            Array::ArrayND { arr, shape, strides } => {
+                if shape.len() != 2 {return Err(ListError::MismatchedDim);}
+                let (nr, nc) = (shape[0], shape[1]);
+                if arr.len() == nr * nc && *strides == nd_strides(shape, false) {
+                    return Ok(Array::Array2D { arr: arr.clone(), nr, nc, put_val_by_row: false });
+                }
+                Ok(Array::Array2D { arr: self.nd_values().into_boxed_slice(), nr, nc, put_val_by_row: true })
+            },
+            Array::Array2D { .. } => Ok(self.clone()),
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+
+    /// new shape with the same elements read in C order,
+    /// the buffer is copied to C order when it is not
+    /// ```
+    /// use simple_blas::array::Array;
+    ///
+    /// let mut a = Array::new_array_nd(Box::new([1, 2, 3, 4, 5, 6]), &[2, 3], true).unwrap();
+    /// a.reshape(&[3, 1, 2]).unwrap();
+    ///
+    /// assert_eq!(a.shape(), vec![3, 1, 2]);
+    /// assert_eq!(a[[2, 0, 0]], 5);
+    /// ```
+    pub fn reshape(&mut self, new_shape: &[usize]) -> Result<(), ListError> {
+        let contiguous: bool = match &*self {
+            Array::ArrayND { arr, shape, strides } => {
+                if new_shape.iter().product::<usize>() != shape.iter().product::<usize>() {
+                    return Err(ListError::MismatchedDim);
+                }
+                arr.len() == shape.iter().product() && *strides == nd_strides(shape, true)
+            },
+            _ => return Err(ListError::MismatchedTypes),
+        };
+        let values: Option<Vec<T>> = if contiguous {None} else {Some(self.nd_values())};
+
+        if let Array::ArrayND { arr, shape, strides } = self {
+            if let Some(v) = values {*arr = v.into_boxed_slice();}
+            *shape = new_shape.to_vec();
+            *strides = nd_strides(new_shape, true);
+        }
+        Ok(())
+    }
+
+    /// axis k of the result is axis axes[k] of self,
+    /// only the strides move
+    /// ```
+    /// use simple_blas::array::Array;
+    ///
+    /// let mut a = Array::new_array_nd((0..24).collect::<Vec<i32>>().into_boxed_slice(), &[2, 3, 4], true).unwrap();
+    /// a.permute_axes(&[2, 0, 1]).unwrap();
+    ///
+    /// assert_eq!(a.shape(), vec![4, 2, 3]);
+    /// assert_eq!(a[[3, 1, 2]], 23);
+    /// ```
+    pub fn permute_axes(&mut self, axes: &[usize]) -> Result<(), ListError> {
+        match self {
+            Array::ArrayND { shape, strides, .. } => {
+                let n: usize = shape.len();
+                let mut seen: Vec<bool> = vec![false; n];
+                if axes.len() != n {return Err(ListError::MismatchedDim);}
+                for &k in axes {
+                    if k >= n || seen[k] {return Err(ListError::MismatchedDim);}
+                    seen[k] = true;
+                }
+
+                *shape = axes.iter().map(|&k| shape[k]).collect();
+                *strides = axes.iter().map(|&k| strides[k]).collect();
+                Ok(())
+            },
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+
+    /// fold along axis with f, the axis is removed from the shape,
+    /// the result is put in C order, Scalar when no axis is left,
+    /// sum, min, max and the other statistics take the axis as Some(axis)
+    /// ```
+    /// use simple_blas::array::Array;
+    ///
+    /// let a = Array::new_array_nd(Box::new([1, 2, 3, 4, 5, 6]), &[2, 3], true).unwrap();
+    /// let m = a.reduce_axis(0, |x, y| if x > y {x} else {y}).unwrap();
+    ///
+    /// assert_eq!(m, Array::new_array_nd(Box::new([4, 5, 6]), &[3], true).unwrap());
+    /// ```
+    pub fn reduce_axis(&self, axis: usize, f: impl Fn(T, T) -> T) -> Result<Self, ListError> {
+        match self {
+            Array::ArrayND { arr, shape, strides } => {
+                if axis >= shape.len() {return Err(ListError::OutOfBounds);}
+                let len: usize = shape[axis];
+                if len == 0 {return Err(ListError::EmptyArray);}
+
+                let mut rest_shape: Vec<usize> = shape.clone();
+                let mut rest_strides: Vec<usize> = strides.clone();
+                rest_shape.remove(axis);
+                rest_strides.remove(axis);
+
+                let step: usize = strides[axis];
+                let v: Vec<T> = c_order_pos(&rest_shape, &rest_strides).into_iter().map(|p| {
+                    let mut acc: T = arr[p];
+                    for i in 1..len {acc = f(acc, arr[p + i * step]);}
+                    acc
+                }).collect();
+
+                if rest_shape.is_empty() {return Ok(Array::Scalar(v[0]));}
+                let rest_strides: Vec<usize> = nd_strides(&rest_shape, true);
+                Ok(Array::ArrayND { arr: v.into_boxed_slice(), shape: rest_shape, strides: rest_strides })
+            },
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+
+    /// matrix product over the last two axes, batched over the
+    /// leading axes, a side with only two axes is used for every batch,
+    /// the result is put in C order
+    /// ```
+    /// use simple_blas::array::Array;
+    ///
+    /// // two 2 x 2 matrices times the same 2 x 1
+    /// let a = Array::new_array_nd(Box::new([1, 2, 3, 4, 0, 1, 1, 0]), &[2, 2, 2], true).unwrap();
+    /// let b = Array::new_array_nd(Box::new([1, 10]), &[2, 1], true).unwrap();
+    /// let c = a.mmult_batched(&b).unwrap();
+    ///
+    /// assert_eq!(c, Array::new_array_nd(Box::new([21, 43, 10, 1]), &[2, 2, 1], true).unwrap());
+    /// ```
+    pub fn mmult_batched(&self, other: &Self) -> Result<Self, ListError> {
+        match (self, other) {
+            (Array::ArrayND { arr: arr1, shape: shape1, strides: strides1 },
+             Array::ArrayND { arr: arr2, shape: shape2, strides: strides2 })
+            => {
+                let (n1, n2) = (shape1.len(), shape2.len());
+                if n1 < 2 || n2 < 2 || shape1[n1 - 1] != shape2[n2 - 2] {
+                    return Err(ListError::ShapeMismatch { lhs: shape1.clone(), rhs: shape2.clone() });
+                }
+
+                let (batch1, batch2) = (&shape1[..n1 - 2], &shape2[..n2 - 2]);
+                let batch: &[usize] = match (batch1.is_empty(), batch2.is_empty()) {
+                    (true, _) => batch2,
+                    (_, true) => batch1,
+                    _ if batch1 == batch2 => batch1,
+                    _ => return Err(ListError::ShapeMismatch { lhs: shape1.clone(), rhs: shape2.clone() }),
+                };
+
+                let sa: Strided = Strided { nr: shape1[n1 - 2], nc: shape1[n1 - 1], rs: strides1[n1 - 2], cs: strides1[n1 - 1] };
+                let sb: Strided = Strided { nr: shape2[n2 - 2], nc: shape2[n2 - 1], rs: strides2[n2 - 2], cs: strides2[n2 - 1] };
+                let sc: Strided = Strided::new(sa.nr, sb.nc, true);
+                let size: usize = sc.nr * sc.nc;
+
+                // start of each batch, a side without batch axes stays at 0
+                let starts = |b: &[usize], strides: &[usize]| {
+                    if b.is_empty() {vec![0; batch.iter().product()]}
+                    else {c_order_pos(batch, &strides[..b.len()])}
+                };
+                let (pos1, pos2) = (starts(batch1, strides1), starts(batch2, strides2));
+
+                // an empty inner axis leaves C zero, the starts
+                // may then be past the end of the empty buffers
+                let mut c: Vec<T> = vec![T::default(); pos1.len() * size];
+                if sa.nc > 0 {
+                    for (k, chunk) in c.chunks_mut(size.max(1)).enumerate().take(pos1.len()) {
+                        Array::gemm_kernel(None, &arr1[pos1[k]..], sa, &arr2[pos2[k]..], sb, T::default(), chunk, sc);
+                    }
+                }
+
+                let mut shape: Vec<usize> = batch.to_vec();
+                shape.extend([sc.nr, sc.nc]);
+                let strides: Vec<usize> = nd_strides(&shape, true);
+                Ok(Array::ArrayND { arr: c.into_boxed_slice(), shape, strides })
+            },
+
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+}
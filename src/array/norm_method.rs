@@ -0,0 +1,139 @@
+// vector and matrix norms
+
+use super::matrix_operations::Sqrt;
+use super::{Array, ListError};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
+
+use crate::array::{idxr, idxc};
+
+/// ## Kind of norm
+/// -1 L1: sum |x| for vector, max column sum for matrix
+///
+/// -2 L2: euclidean for vector, largest singular value for matrix
+///
+/// -3 Inf: max |x| for vector, max row sum for matrix
+///
+/// -4 Frobenius: sqrt of sum of squares
+///
+/// -5 Nuclear: sum of singular values
+///
+/// -6 Spectral: largest singular value
+///
+/// -7 P(p): (sum |x|^p)^(1/p) over all elements, p >= 1
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Norm {
+    L1,
+    L2,
+    Inf,
+    Frobenius,
+    Nuclear,
+    Spectral,
+    P(f32),
+}
+
+impl<T> Array<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T> + Sub<Output=T>
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default + From<f32> + PartialOrd + Sqrt
+{
+    /// norm of Array1D or Array2D, Scalar gives |x|,
+    /// Array1D is treated as a column for Nuclear and Spectral
+    /// ```
+    /// use simple_blas::array::{Array, Norm};
+    ///
+    /// let v = Array::new_array_1d(Box::new([3.0, -4.0_f64]));
+    /// assert_eq!(v.norm(Norm::L2).unwrap(), 5.0);
+    /// assert_eq!(v.norm(Norm::Inf).unwrap(), 4.0);
+    ///
+    /// let a = Array::new_array_2d(
+    ///     Box::new([1.0, -2.0, 3.0, 4.0_f64]), (2, 2), true
+    /// ).unwrap();
+    /// assert_eq!(a.norm(Norm::L1).unwrap(), 6.0);
+    /// assert_eq!(a.norm(Norm::Inf).unwrap(), 7.0);
+    /// ```
+    pub fn norm(&self, kind: Norm) -> Result<T, ListError> {
+        let z: T = T::default();
+        if let Norm::P(p) = kind {
+            if p.is_nan() || p < 1.0 {return Err(ListError::InvalidNorm);}
+        }
+
+        match self {
+            Array::Scalar(val) => Ok(Array::abs(*val, z)),
+
+            Array::Array1D { arr }
+            => {
+                let res: T = match kind {
+                    Norm::L1 => Array::norm_p(arr, 1.0),
+                    Norm::Inf => Array::norm_max(arr),
+                    Norm::P(p) => Array::norm_p(arr, p),
+                    _ => Array::norm_2_scaled(arr),
+                };
+                Ok(res)
+            },
+
+            Array::Array2D { arr, nr, nc, put_val_by_row }
+            => {
+                let dim: (usize, usize) = (*nr, *nc);
+                let by_row: bool = *put_val_by_row;
+                let idx: fn(usize, usize, (usize, usize)) -> usize = if by_row {idxr} else {idxc};
+
+                let res: T = match kind {
+                    Norm::L1 => Array::mat_norm_1(arr, dim, idx),
+                    Norm::Inf => Array::mat_norm_inf(arr, dim, idx),
+                    Norm::Frobenius => Array::norm_2_scaled(arr),
+                    Norm::P(p) => Array::norm_p(arr, p),
+                    Norm::L2 | Norm::Spectral => {
+                        let sv: Vec<T> = Array::singular_values(arr, dim, by_row)?;
+                        if sv.is_empty() {z} else {sv[0]}
+                    },
+                    Norm::Nuclear => {
+                        let sv: Vec<T> = Array::singular_values(arr, dim, by_row)?;
+                        let mut sum: T = z;
+                        for s in sv {sum += s;}
+                        sum
+                    },
+                };
+                Ok(res)
+            },
+
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+
+    // max |x|
+    fn norm_max(v: &[T]) -> T {
+        let z: T = T::default();
+        let mut res: T = z;
+        for &val in v.iter() {
+            let a: T = Array::abs(val, z);
+            if a > res {res = a;}
+        }
+        res
+    }
+
+    // (sum |x|^p)^(1/p), scaled by max |x|
+    fn norm_p(v: &[T], p: f32) -> T {
+        let z: T = T::default();
+        let scale: T = Array::norm_max(v);
+        if scale == z {return z;}
+
+        let pt: T = T::from(p);
+        let mut sum: T = z;
+        for &val in v.iter() {
+            sum += (Array::abs(val, z) / scale).powf(pt);
+        }
+        scale * sum.powf(T::from(1.0_f32) / pt)
+    }
+
+    // singular values in descending order
+    fn singular_values(ma: &[T], dim: (usize, usize), by_row: bool) -> Result<Vec<T>, ListError> {
+        let (m, n) = dim;
+        let (_u, sv, _v, _n_iter) = if m >= n {
+            Array::golub_kahan_svd(ma, dim, by_row, None)?
+        } else {
+            // A' has the same singular values
+            Array::golub_kahan_svd(ma, (n, m), !by_row, None)?
+        };
+        Ok(sv)
+    }
+}
@@ -0,0 +1,320 @@
+// std::ops for Array
+// + - * / are element-wise with broadcasting,
+// matrix product is mdot
+
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use super::{Array, ListError};
+
+impl<T> Array<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
++ Sub<Output=T> + PartialOrd
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default
+{
+    /// self + other, the + that returns the error, same as added
+    pub fn checked_add(&self, other: &Self) -> Result<Self, ListError> {
+        self.added(other)
+    }
+
+    /// self - other, the - that returns the error, same as subtracted
+    pub fn checked_sub(&self, other: &Self) -> Result<Self, ListError> {
+        self.subtracted(other)
+    }
+
+    /// element-wise self * other, the * that returns the error, same as ele_multiplied
+    pub fn checked_mul(&self, other: &Self) -> Result<Self, ListError> {
+        self.ele_multiplied(other)
+    }
+
+    /// element-wise self / other, the / that returns the error, same as ele_divided
+    pub fn checked_div(&self, other: &Self) -> Result<Self, ListError> {
+        self.ele_divided(other)
+    }
+
+    /// matrix product self other, the mdot that returns the error, same as matmul
+    pub fn checked_matmul(&self, other: &Self) -> Result<Self, ListError> {
+        self.matmul(other)
+    }
+
+    /// matrix product self other,
+    /// checked_matmul returns the error instead
+    ///
+    /// # Panics
+    /// when matmul fails: self and other are not both Array2D
+    /// or both ArrayND, or their inner dimensions differ
+    /// ```
+    /// use simple_blas::array::Array;
+    ///
+    /// let a = Array::new_array_2d(Box::new([1.0, 2.0, 3.0, 4.0]), (2, 2), true).unwrap();
+    /// let b = Array::new_array_2d(Box::new([1.0, 0.0, 0.0, 1.0]), (2, 2), true).unwrap();
+    /// let c = Array::new_array_1d(Box::new([1.0, 1.0]));
+    ///
+    /// // A B + c, c added to every row
+    /// let d = a.mdot(&b) + &c;
+    /// assert_eq!(d, Array::new_array_2d(Box::new([2.0, 3.0, 4.0, 5.0]), (2, 2), true).unwrap());
+    /// ```
+    pub fn mdot(&self, other: &Self) -> Self {
+        self.matmul(other)
+        .unwrap_or_else(|error| panic!("Array mdot Array failed: {:?}", error))
+    }
+}
+
+macro_rules! impl_bin_op {
+    ($op:ident, $op_fn:ident, $op_assign:ident, $op_assign_fn:ident,
+     $checked:ident, $new:ident, $inplace:ident, $scalar:ident, $sym:literal,
+     $arr_panics:literal, $scalar_panics:literal) => {
+        impl<T> $op<&Array<T>> for &Array<T>
+        where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
+        + Sub<Output=T> + PartialOrd
+        + PartialEq + AddAssign + Copy + MulAssign + SubAssign
+        + Default
+        {
+            type Output = Array<T>;
+            #[doc = concat!("`", stringify!($checked), "` returns the error instead")]
+            ///
+            /// # Panics
+            #[doc = $arr_panics]
+            fn $op_fn(self, rhs: &Array<T>) -> Array<T> {
+                self.$new(rhs)
+                .unwrap_or_else(|error| panic!(concat!("Array ", $sym, " Array failed: {:?}"), error))
+            }
+        }
+
+        impl<T> $op<Array<T>> for &Array<T>
+        where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
+        + Sub<Output=T> + PartialOrd
+        + PartialEq + AddAssign + Copy + MulAssign + SubAssign
+        + Default
+        {
+            type Output = Array<T>;
+            #[doc = concat!("`", stringify!($checked), "` returns the error instead")]
+            ///
+            /// # Panics
+            #[doc = $arr_panics]
+            fn $op_fn(self, rhs: Array<T>) -> Array<T> {
+                $op::$op_fn(self, &rhs)
+            }
+        }
+
+        impl<T> $op<&Array<T>> for Array<T>
+        where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
+        + Sub<Output=T> + PartialOrd
+        + PartialEq + AddAssign + Copy + MulAssign + SubAssign
+        + Default
+        {
+            type Output = Array<T>;
+            #[doc = concat!("`", stringify!($checked), "` returns the error instead")]
+            ///
+            /// # Panics
+            #[doc = $arr_panics]
+            fn $op_fn(mut self, rhs: &Array<T>) -> Array<T> {
+                self.$op_assign_fn(rhs);
+                self
+            }
+        }
+
+        impl<T> $op<Array<T>> for Array<T>
+        where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
+        + Sub<Output=T> + PartialOrd
+        + PartialEq + AddAssign + Copy + MulAssign + SubAssign
+        + Default
+        {
+            type Output = Array<T>;
+            #[doc = concat!("`", stringify!($checked), "` returns the error instead")]
+            ///
+            /// # Panics
+            #[doc = $arr_panics]
+            fn $op_fn(mut self, rhs: Array<T>) -> Array<T> {
+                self.$op_assign_fn(&rhs);
+                self
+            }
+        }
+
+        impl<T> $op<T> for &Array<T>
+        where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
+        + Sub<Output=T> + PartialOrd
+        + PartialEq + AddAssign + Copy + MulAssign + SubAssign
+        + Default
+        {
+            type Output = Array<T>;
+            #[doc = concat!("`", stringify!($scalar), "` returns the error instead")]
+            ///
+            /// # Panics
+            #[doc = $scalar_panics]
+            fn $op_fn(self, rhs: T) -> Array<T> {
+                $op::$op_fn(self.clone(), rhs)
+            }
+        }
+
+        impl<T> $op<T> for Array<T>
+        where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
+        + Sub<Output=T> + PartialOrd
+        + PartialEq + AddAssign + Copy + MulAssign + SubAssign
+        + Default
+        {
+            type Output = Array<T>;
+            #[doc = concat!("`", stringify!($scalar), "` returns the error instead")]
+            ///
+            /// # Panics
+            #[doc = $scalar_panics]
+            fn $op_fn(mut self, rhs: T) -> Array<T> {
+                self.$op_assign_fn(rhs);
+                self
+            }
+        }
+
+        impl<T> $op_assign<&Array<T>> for Array<T>
+        where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
+        + Sub<Output=T> + PartialOrd
+        + PartialEq + AddAssign + Copy + MulAssign + SubAssign
+        + Default
+        {
+            #[doc = concat!("`", stringify!($inplace), "` returns the error instead")]
+            ///
+            /// # Panics
+            #[doc = $arr_panics]
+            fn $op_assign_fn(&mut self, rhs: &Array<T>) {
+                if let Err(error) = self.$inplace(rhs) {
+                    panic!(concat!("Array ", $sym, " Array failed: {:?}"), error);
+                }
+            }
+        }
+
+        impl<T> $op_assign<Array<T>> for Array<T>
+        where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
+        + Sub<Output=T> + PartialOrd
+        + PartialEq + AddAssign + Copy + MulAssign + SubAssign
+        + Default
+        {
+            #[doc = concat!("`", stringify!($inplace), "` returns the error instead")]
+            ///
+            /// # Panics
+            #[doc = $arr_panics]
+            fn $op_assign_fn(&mut self, rhs: Array<T>) {
+                self.$op_assign_fn(&rhs);
+            }
+        }
+
+        impl<T> $op_assign<T> for Array<T>
+        where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
+        + Sub<Output=T> + PartialOrd
+        + PartialEq + AddAssign + Copy + MulAssign + SubAssign
+        + Default
+        {
+            #[doc = concat!("`", stringify!($scalar), "` returns the error instead")]
+            ///
+            /// # Panics
+            #[doc = $scalar_panics]
+            fn $op_assign_fn(&mut self, rhs: T) {
+                if let Err(error) = self.$scalar(rhs) {
+                    panic!(concat!("Array ", $sym, " scalar failed: {:?}"), error);
+                }
+            }
+        }
+    };
+}
+
+impl_bin_op!(Add, add, AddAssign, add_assign, checked_add, added, madd, add, "+",
+    "when the shapes do not broadcast or either side is Null or an ArrayND",
+    "when self is Null or an ArrayND");
+impl_bin_op!(Sub, sub, SubAssign, sub_assign, checked_sub, subtracted, mminus, minus, "-",
+    "when the shapes do not broadcast or either side is Null or an ArrayND",
+    "when self is Null or an ArrayND");
+impl_bin_op!(Mul, mul, MulAssign, mul_assign, checked_mul, ele_multiplied, ele_mult, mult, "*",
+    "when the shapes do not broadcast or either side is Null or an ArrayND",
+    "when self is Null or an ArrayND");
+impl_bin_op!(Div, div, DivAssign, div_assign, checked_div, ele_divided, ele_div, div, "/",
+    "when the shapes do not broadcast or either side is Null or an ArrayND,\nor an element of rhs is zero",
+    "when rhs is zero or self is Null or an ArrayND");
+
+// scalar on the left, s op A
+macro_rules! impl_scalar_lhs {
+    ($t:ty) => {
+        impl Add<&Array<$t>> for $t {
+            type Output = Array<$t>;
+            /// # Panics
+            /// when rhs is Null or an ArrayND
+            fn add(self, rhs: &Array<$t>) -> Array<$t> {rhs + self}
+        }
+
+        impl Add<Array<$t>> for $t {
+            type Output = Array<$t>;
+            /// # Panics
+            /// when rhs is Null or an ArrayND
+            fn add(self, rhs: Array<$t>) -> Array<$t> {rhs + self}
+        }
+
+        impl Mul<&Array<$t>> for $t {
+            type Output = Array<$t>;
+            /// # Panics
+            /// when rhs is Null or an ArrayND
+            fn mul(self, rhs: &Array<$t>) -> Array<$t> {rhs * self}
+        }
+
+        impl Mul<Array<$t>> for $t {
+            type Output = Array<$t>;
+            /// # Panics
+            /// when rhs is Null or an ArrayND
+            fn mul(self, rhs: Array<$t>) -> Array<$t> {rhs * self}
+        }
+
+        impl Sub<&Array<$t>> for $t {
+            type Output = Array<$t>;
+            /// # Panics
+            /// when rhs is Null or an ArrayND
+            fn sub(self, rhs: &Array<$t>) -> Array<$t> {Array::Scalar(self) - rhs}
+        }
+
+        impl Sub<Array<$t>> for $t {
+            type Output = Array<$t>;
+            /// # Panics
+            /// when rhs is Null or an ArrayND
+            fn sub(self, rhs: Array<$t>) -> Array<$t> {Array::Scalar(self) - &rhs}
+        }
+
+        impl Div<&Array<$t>> for $t {
+            type Output = Array<$t>;
+            /// # Panics
+            /// when rhs is Null or an ArrayND or an element of rhs is zero
+            fn div(self, rhs: &Array<$t>) -> Array<$t> {Array::Scalar(self) / rhs}
+        }
+
+        impl Div<Array<$t>> for $t {
+            type Output = Array<$t>;
+            /// # Panics
+            /// when rhs is Null or an ArrayND or an element of rhs is zero
+            fn div(self, rhs: Array<$t>) -> Array<$t> {Array::Scalar(self) / &rhs}
+        }
+    };
+}
+
+impl_scalar_lhs!(f32);
+impl_scalar_lhs!(f64);
+
+impl<T> Neg for &Array<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
++ Sub<Output=T> + PartialOrd
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default
+{
+    type Output = Array<T>;
+    /// # Panics
+    /// when self is Null or an ArrayND
+    fn neg(self) -> Array<T> {
+        Array::Scalar(T::default()).subtracted(self)
+        .unwrap_or_else(|error| panic!("- Array failed: {:?}", error))
+    }
+}
+
+impl<T> Neg for Array<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
++ Sub<Output=T> + PartialOrd
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default
+{
+    type Output = Array<T>;
+    fn neg(self) -> Array<T> {
+        -&self
+    }
+}
@@ -2,6 +2,39 @@ use super::{Array, ListError, idxr, idxc};
 use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
 use crate::array::matrix_operations::Sqrt;
 
+/// ## Column pivoted QR, A P = Q R
+/// -1 q: nr x k, orthonormal columns, k = min(nr, nc)
+///
+/// -2 r: k x nc, upper triangular, |r[i, i]| non increasing
+///
+/// -3 p: column swaps (i, j) of P in the order they are applied,
+/// the same format as permute_rc
+///
+/// -4 rank: number of |r[i, i]| > tol * |r[0, 0]|
+/// ```
+/// use simple_blas::array::Array;
+///
+/// // second column = 2 * first column
+/// let a = Array::new_array_2d(
+///     Box::new([1.0, 2.0, 2.0, 4.0, 3.0, 6.0_f64]), (3, 2), true
+/// ).unwrap();
+///
+/// let qrp = a.mqr_pivoted(None).unwrap();
+/// assert_eq!(qrp.rank, 1);
+/// assert_eq!(qrp.p, vec![(0, 1)]);
+/// ```
+#[derive(Debug)]
+pub struct QrPivoted<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default
+{
+    pub q: Array<T>,
+    pub r: Array<T>,
+    pub p: Vec<(usize, usize)>,
+    pub rank: usize,
+}
+
 impl<T> Array<T> 
 where T: Sqrt + 
 Add<Output=T> + Mul<Output=T> + 
@@ -10,6 +43,7 @@ Div<Output=T> + Sub<Output=T>
 + Default + PartialOrd + From<f32>
 {
     
+    #[allow(clippy::needless_borrow)]
     pub fn mqr(&self) -> Result<(Array<T>, Array<T>), ListError> {      
         let (q, r) = 
         match self {
@@ -49,13 +83,23 @@ Div<Output=T> + Sub<Output=T>
     }
 
     pub fn mqr_householder(&self) -> Result<(Vec<Vec<T>>, Array<T>), ListError>{
+        self.mqr_householder_with(Array::qr_householder)
+    }
+
+    // mqr_householder with q_factor and r from qr(ma, dim, by_row)
+    #[allow(clippy::needless_return)]
+    pub(crate) fn mqr_householder_with(
+        &self,
+        qr: impl FnOnce(&[T], (usize, usize), bool) -> Result<(Vec<Vec<T>>, Vec<T>), ListError>,
+    ) -> Result<(Vec<Vec<T>>, Array<T>), ListError>
+    {
         match self {
             Array::Array2D { arr, nr, nc, put_val_by_row } 
             => {
                 let dim: (usize, usize) = (*nr, *nc);
                 let by_row: bool = *put_val_by_row;
                 if *nr < *nc {return Err(ListError::MatrixQRHouseHolderDimError);}
-                let (q_factor, r) = Array::qr_householder(&arr, dim, by_row)?;
+                let (q_factor, r) = qr(arr, dim, by_row)?;
                
                 let r = Array::new_array_2d(
                     r.into_boxed_slice(), 
@@ -70,6 +114,64 @@ Div<Output=T> + Sub<Output=T>
         }
     }
 
+    /// rank revealing QR with column pivoting,
+    /// tol is relative to |r[0, 0]|, default max(nr, nc) * epsilon
+    pub fn mqr_pivoted(&self, tol: Option<T>) -> Result<QrPivoted<T>, ListError> {
+        match self {
+            Array::Array2D { arr, nr, nc, put_val_by_row }
+            => {
+                let dim: (usize, usize) = (*nr, *nc);
+                let (nr, nc) = dim;
+                let by_row: bool = *put_val_by_row;
+                let idx: fn(usize, usize, (usize, usize)) -> usize = if by_row {idxr} else {idxc};
+                let k: usize = if nr < nc {nr} else {nc};
+                let z: T = T::default();
+
+                let (q_factor, ra, p) = Array::qr_householder_pivoted(arr, dim, by_row)?;
+
+                // first k columns of Q
+                let qm: Vec<T> = Array::get_qm(&q_factor, nr);
+                let dimq: (usize, usize) = (nr, k);
+                let mut q: Vec<T> = vec![z; nr * k];
+                for r in 0..nr {
+                    for c in 0..k {
+                        q[idx(r, c, dimq)] = qm[idxr(r, c, (nr, nr))];
+                    }
+                }
+
+                // first k rows of R
+                let dimr: (usize, usize) = (k, nc);
+                let mut rm: Vec<T> = vec![z; k * nc];
+                for r in 0..k {
+                    for c in r..nc {
+                        rm[idx(r, c, dimr)] = ra[idx(r, c, dim)];
+                    }
+                }
+
+                let tol: T = match tol {
+                    Some(tol) => tol,
+                    None => {
+                        let eps: T = Array::epsilon();
+                        T::from(if nr < nc {nc as f32} else {nr as f32}) * eps
+                    },
+                };
+                let r00: T = if k > 0 {Array::abs(rm[idx(0, 0, dimr)], z)} else {z};
+                let rank: usize = (0..k)
+                    .take_while(|&i| Array::abs(rm[idx(i, i, dimr)], z) > tol * r00)
+                    .count();
+
+                Ok(QrPivoted {
+                    q: Array::new_array_2d(q.into_boxed_slice(), (nr as isize, k as isize), by_row)?,
+                    r: Array::new_array_2d(rm.into_boxed_slice(), (k as isize, nc as isize), by_row)?,
+                    p,
+                    rank
+                })
+            },
+
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+
     pub fn mq_factor_mult_mat_a(q_factor: &Vec<Vec<T>>, ma: &mut Self) -> Result<(), ListError>{
         match ma {
             Array::Array2D { arr, nr, nc, put_val_by_row } 
@@ -0,0 +1,285 @@
+// parallel variants, run in a given ExecContext
+// or in ExecContext::global()
+//
+// each *_speed method gives the same result as its serial
+// counterpart, the work is split by rows or columns
+// and small problems stay on the current thread
+
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
+
+use super::matrix_operations::Sqrt;
+use super::{idxc, idxr, Array, Eigen, ExecContext, ListError, LuFactorization};
+
+// chunk length of Array1D element-wise ops
+const CHUNK_1D: usize = 4096;
+
+impl<T> Array<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
++ Sub<Output=T> + PartialOrd
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default + Sync + Send
+{
+    /// parallel madd, other shapes are broadcast serially
+    pub fn madd_speed(&mut self, other: &Self) -> Result<(), ListError> {
+        self.madd_speed_with(&ExecContext::global(), other)
+    }
+
+    /// madd_speed in ctx
+    pub fn madd_speed_with(&mut self, ctx: &ExecContext, other: &Self) -> Result<(), ListError> {
+        self.ele_op_speed(ctx, other, |x, y| {*x += y; Ok(())})
+    }
+
+    /// parallel mminus, other shapes are broadcast serially
+    pub fn mminus_speed(&mut self, other: &Self) -> Result<(), ListError> {
+        self.mminus_speed_with(&ExecContext::global(), other)
+    }
+
+    /// mminus_speed in ctx
+    pub fn mminus_speed_with(&mut self, ctx: &ExecContext, other: &Self) -> Result<(), ListError> {
+        self.ele_op_speed(ctx, other, |x, y| {*x -= y; Ok(())})
+    }
+
+    /// parallel ele_mult, other shapes are broadcast serially
+    pub fn ele_mult_speed(&mut self, other: &Self) -> Result<(), ListError> {
+        self.ele_mult_speed_with(&ExecContext::global(), other)
+    }
+
+    /// ele_mult_speed in ctx
+    pub fn ele_mult_speed_with(&mut self, ctx: &ExecContext, other: &Self) -> Result<(), ListError> {
+        self.ele_op_speed(ctx, other, |x, y| {*x *= y; Ok(())})
+    }
+
+    /// parallel ele_div, other shapes are broadcast serially
+    pub fn ele_div_speed(&mut self, other: &Self) -> Result<(), ListError> {
+        self.ele_div_speed_with(&ExecContext::global(), other)
+    }
+
+    /// ele_div_speed in ctx
+    pub fn ele_div_speed_with(&mut self, ctx: &ExecContext, other: &Self) -> Result<(), ListError> {
+        self.ele_op_speed(ctx, other, |x, y| {
+            if y == T::default() {return Err(ListError::DivisionByZero);}
+            *x = *x / y;
+            Ok(())
+        })
+    }
+
+    // same shape: chunks of self (rows or columns) in parallel
+    fn ele_op_speed(
+        &mut self, ctx: &ExecContext, other: &Self,
+        op: impl Fn(&mut T, T) -> Result<(), ListError> + Send + Sync,
+    ) -> Result<(), ListError>
+    {
+        match (&mut *self, other) {
+            (Array::Array1D { arr: arr1 },
+             Array::Array1D { arr: arr2 }) if arr1.len() == arr2.len() => {
+                let work: usize = arr1.len();
+                ctx.for_each_chunk(arr1, CHUNK_1D, work, |i, chunk| {
+                    let start: usize = i * CHUNK_1D;
+                    for j in 0..chunk.len() {
+                        op(&mut chunk[j], arr2[start + j])?;
+                    }
+                    Ok(())
+                })
+            },
+
+            (Array::Array2D { arr: arr1, nr: nr1, nc: nc1, put_val_by_row: by_row1 },
+             Array::Array2D { arr: arr2, nr: nr2, nc: nc2, put_val_by_row: by_row2 })
+            if (*nr1, *nc1) == (*nr2, *nc2) => {
+                let dim: (usize, usize) = (*nr1, *nc1);
+                let by_row: bool = *by_row1;
+                let idx2: fn(usize, usize, (usize, usize)) -> usize = if *by_row2 {idxr} else {idxc};
+                let len: usize = if by_row {dim.1} else {dim.0};
+
+                ctx.for_each_chunk(arr1, len.max(1), dim.0 * dim.1, |i, chunk| {
+                    for (j, x) in chunk.iter_mut().enumerate() {
+                        let (r, c) = if by_row {(i, j)} else {(j, i)};
+                        op(x, arr2[idx2(r, c, dim)])?;
+                    }
+                    Ok(())
+                })
+            },
+
+            // broadcast other shapes
+            (arr1, other) => arr1.broadcast_op(other, op),
+        }
+    }
+}
+
+impl<T> Array<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T> + Sub<Output=T>
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default + From<f32> + PartialOrd + Sqrt + Sync + Send
+{
+    /// parallel mlu, rows (or columns) under the pivot
+    /// are eliminated in parallel
+    pub fn mlu_speed(&self) -> Result<LuFactorization<T>, ListError> {
+        self.mlu_speed_with(&ExecContext::global())
+    }
+
+    /// mlu_speed in ctx
+    pub fn mlu_speed_with(&self, ctx: &ExecContext) -> Result<LuFactorization<T>, ListError> {
+        match self {
+            Array::Array2D { arr, nr, nc, put_val_by_row }
+            => {
+                if *nr != *nc {return Err(ListError::NotSquareMat);}
+
+                // p A = lu
+                let mut lu: Box<[T]> = arr.clone();
+                let mut p: Vec<(usize, usize)> = vec![];
+                Array::p_lu_speed(ctx, &mut p, &mut lu, *nr, *put_val_by_row)?;
+
+                Ok(LuFactorization { lu, p, n: *nr, by_row: *put_val_by_row })
+            },
+
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+
+    /// parallel mqr_householder, each reflector is applied
+    /// to the remaining columns in parallel
+    pub fn mqr_householder_speed(&self) -> Result<(Vec<Vec<T>>, Array<T>), ListError> {
+        self.mqr_householder_speed_with(&ExecContext::global())
+    }
+
+    /// mqr_householder_speed in ctx
+    pub fn mqr_householder_speed_with(&self, ctx: &ExecContext) -> Result<(Vec<Vec<T>>, Array<T>), ListError> {
+        self.mqr_householder_with(|ma, dim, by_row| Array::qr_householder_speed(ctx, ma, dim, by_row))
+    }
+
+    /// parallel meig, eigen values by serial QR iteration,
+    /// the inverse iterations for eigen vectors run in parallel
+    pub fn meig_speed(&self) -> Result<Eigen<T>, ListError> {
+        self.meig_speed_with(&ExecContext::global())
+    }
+
+    /// meig_speed in ctx
+    pub fn meig_speed_with(&self, ctx: &ExecContext) -> Result<Eigen<T>, ListError> {
+        self.meig_with(|ma, dim, by_row, e_re, e_im| {
+            Array::eigen_vectors_speed(ctx, ma, dim, by_row, e_re, e_im)
+        })
+    }
+
+    // p_lu of n x n matrix,
+    // after the pivot row r is chosen, the rows r+1.. (by row)
+    // or the columns r+1.. (by column) are independent
+    fn p_lu_speed(
+        ctx: &ExecContext,
+        p: &mut Vec<(usize, usize)>,
+        arr: &mut Box<[T]>,
+        n: usize,
+        by_row: bool,
+    ) -> Result<(), ListError>
+    {
+        let dim: (usize, usize) = (n, n);
+        let idx: fn(usize, usize, (usize, usize)) -> usize = if by_row {idxr} else {idxc};
+        let z: T = T::default();
+
+        for r in 0..n {
+            Array::permute_r(arr, r, dim, z, p, idx);
+
+            let maxv: T = arr[idx(r, r, dim)];
+            if maxv == z {continue;}
+
+            let work: usize = (n - r) * (n - r);
+            let (head, tail) = arr.split_at_mut((r + 1) * n);
+
+            if by_row {
+                // row r2 -= factor * row r, L is kept at (r2, r)
+                let pivot: &[T] = &head[r * n..];
+                ctx.for_each_chunk(tail, n, work, |_, row| {
+                    let factor: T = row[r] / maxv;
+                    for k in (r + 1)..n {
+                        row[k] -= factor * pivot[k];
+                    }
+                    row[r] = factor;
+                    Ok(())
+                })?;
+            } else {
+                // column r becomes L first,
+                // then column c -= L[.., r] * a[r, c]
+                let col_r: &mut [T] = &mut head[r * n..];
+                for x in col_r[(r + 1)..n].iter_mut() {*x = *x / maxv;}
+                let col_r: &[T] = col_r;
+                ctx.for_each_chunk(tail, n, work, |_, col| {
+                    let val: T = col[r];
+                    for i in (r + 1)..n {
+                        col[i] -= col_r[i] * val;
+                    }
+                    Ok(())
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // qr_householder with the reflector applied
+    // to columns c.. in parallel
+    pub(crate) fn qr_householder_speed(
+        ctx: &ExecContext,
+        ma: &[T],
+        dim: (usize, usize),
+        by_row: bool,
+    ) -> Result<(Vec<Vec<T>>, Vec<T>), ListError>
+    {
+        let (nr, nc) = dim;
+        let z: T = T::default();
+        let two: T = T::from(2.0_f32);
+
+        Array::qr_householder_with(ma, dim, by_row, |v, a_mat, c| {
+            let work: usize = (nr - c) * (nc - c);
+
+            if by_row {
+                // w = v' A[c.., c..], then A[c.., c..] -= 2 v w
+                let w: Vec<T> = ctx.map(nc - c, work, |j| {
+                    let mut sum: T = z;
+                    for r in c..nr {
+                        sum += v[r - c] * a_mat[idxr(r, c + j, dim)];
+                    }
+                    sum
+                });
+
+                let (_, tail) = a_mat.split_at_mut(c * nc);
+                ctx.for_each_chunk(tail, nc, work, |i, row| {
+                    for j in c..nc {
+                        row[j] -= two * w[j - c] * v[i];
+                    }
+                    Ok(())
+                })
+            } else {
+                // each column j: a_j -= 2 (v' a_j) v
+                let (_, tail) = a_mat.split_at_mut(c * nr);
+                ctx.for_each_chunk(tail, nr, work, |_, col| {
+                    let mut sum: T = z;
+                    for r in c..nr {
+                        sum += v[r - c] * col[r];
+                    }
+                    for r in c..nr {
+                        col[r] -= two * sum * v[r - c];
+                    }
+                    Ok(())
+                })
+            }
+        })
+    }
+
+    // eigen_vectors with one inverse iteration per
+    // real eigen value (or conjugate pair) in parallel
+    fn eigen_vectors_speed(
+        ctx: &ExecContext,
+        ma: &[T],
+        dim: (usize, usize),
+        by_row: bool,
+        e_re: &[T],
+        e_im: &[T],
+    ) -> Result<Vec<T>, ListError>
+    {
+        let n: usize = dim.0;
+        Array::eigen_vectors_with(ma, dim, by_row, e_im, |h, idx, starts| {
+            let work: usize = n * n * n * starts.len();
+            ctx.map(starts.len(), work, |k| {
+                Array::eigen_vector_at(h, dim, idx, e_re, e_im, starts[k])
+            })
+        })
+    }
+}
@@ -0,0 +1,380 @@
+// reductions and statistics over all elements or along an axis
+//
+// every variant is read as shape and strides, a reduction
+// runs over lanes walked by stride from a start position:
+//  axis None: one lane of all elements in C order (row-major for Array2D)
+//  axis k: one lane along axis k for each index of the other axes
+
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
+
+use super::gemm_method::Strided;
+use super::matrix_operations::Sqrt;
+use super::nd_method::{c_order_pos, nd_strides};
+use super::{Array, ListError};
+
+// buffer, shape and strides
+type StridedNd<'a, T> = (&'a [T], Vec<usize>, Vec<usize>);
+
+// lanes shorter than this are summed in a loop
+const PAIRWISE_BLOCK: usize = 8;
+
+/// ## Summation used by sum
+/// -1 Pairwise: split in halves, error grows with log n
+///
+/// -2 Kahan: compensated running sum, error does not grow with n
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Summation {
+    Pairwise,
+    Kahan,
+}
+
+// lane i starts at starts[i] and walks axes in C order,
+// shape is what is left of the axes
+struct Lanes {
+    starts: Vec<usize>,
+    axes: Vec<usize>,
+    steps: Vec<usize>,
+    len: usize,
+    shape: Vec<usize>,
+}
+
+impl Lanes {
+    fn count(&self) -> usize {
+        self.starts.len()
+    }
+
+    fn lane(&self, i: usize) -> Lane<'_> {
+        Lane { start: self.starts[i], axes: &self.axes, steps: &self.steps, len: self.len }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Lane<'a> {
+    start: usize,
+    axes: &'a [usize],
+    steps: &'a [usize],
+    len: usize,
+}
+
+impl<'a> Lane<'a> {
+    // buffer position of the i-th element, last axis first
+    fn pos(&self, i: usize) -> usize {
+        let mut p: usize = self.start;
+        let mut rest: usize = i;
+        for (&n, &s) in self.axes.iter().zip(self.steps.iter()).rev() {
+            p += rest % n * s;
+            rest /= n;
+        }
+        p
+    }
+
+    fn iter(self) -> impl Iterator<Item = usize> + 'a {
+        (0..self.len).map(move |i| self.pos(i))
+    }
+}
+
+// pairwise sum of val(lo..hi)
+fn pairwise<T>(lo: usize, hi: usize, val: &impl Fn(usize) -> T) -> T
+where T: Add<Output=T> + Copy + Default
+{
+    if hi - lo <= PAIRWISE_BLOCK {
+        let mut sum: T = T::default();
+        for i in lo..hi {sum = sum + val(i);}
+        return sum;
+    }
+    let mid: usize = lo + (hi - lo) / 2;
+    pairwise(lo, mid, val) + pairwise(mid, hi, val)
+}
+
+fn kahan<T>(vals: impl Iterator<Item = T>) -> T
+where T: Add<Output=T> + Sub<Output=T> + Copy + Default
+{
+    let mut sum: T = T::default();
+    let mut comp: T = T::default();
+    for val in vals {
+        let y: T = val - comp;
+        let t: T = sum + y;
+        comp = (t - sum) - y;
+        sum = t;
+    }
+    sum
+}
+
+impl<T> Array<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
++ Sub<Output=T> + PartialOrd
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default
+{
+    // buffer, shape and strides of any variant but Null
+    fn as_strided_nd(&self) -> Result<StridedNd<'_, T>, ListError> {
+        match self {
+            Array::Scalar(val) => Ok((std::slice::from_ref(val), vec![], vec![])),
+            Array::Array1D { arr } => Ok((arr, vec![arr.len()], vec![1])),
+            Array::Array2D { arr, nr, nc, put_val_by_row } => {
+                let s: Strided = Strided::new(*nr, *nc, *put_val_by_row);
+                Ok((arr, vec![*nr, *nc], vec![s.rs, s.cs]))
+            },
+            Array::ArrayND { arr, shape, strides } => Ok((arr, shape.clone(), strides.clone())),
+            Array::Null => Err(ListError::MismatchedTypes),
+        }
+    }
+
+    fn lanes(&self, axis: Option<usize>) -> Result<(&[T], Lanes), ListError> {
+        let (arr, shape, strides) = self.as_strided_nd()?;
+        match axis {
+            None => {
+                let len: usize = shape.iter().product();
+                // a C order buffer is walked as one axis
+                let (axes, steps) = if strides == nd_strides(&shape, true) {(vec![len], vec![1])} else {(shape, strides)};
+                Ok((arr, Lanes { starts: vec![0], axes, steps, len, shape: vec![] }))
+            },
+            Some(k) => {
+                if k >= shape.len() {return Err(ListError::OutOfBounds);}
+                let (len, step) = (shape[k], strides[k]);
+                let mut rest_shape: Vec<usize> = shape;
+                let mut rest_strides: Vec<usize> = strides;
+                rest_shape.remove(k);
+                rest_strides.remove(k);
+
+                let starts: Vec<usize> = c_order_pos(&rest_shape, &rest_strides);
+                Ok((arr, Lanes { starts, axes: vec![len], steps: vec![step], len, shape: rest_shape }))
+            },
+        }
+    }
+
+    // one value per lane, Scalar when no axis is left,
+    // Array1D from Array1D and Array2D, ArrayND in C order otherwise
+    fn collect_lanes<U>(&self, shape: Vec<usize>, v: Vec<U>) -> Array<U>
+    where U: Add<Output=U> + Mul<Output=U> + Div<Output=U>
+    + PartialEq + AddAssign + Copy + MulAssign + SubAssign
+    + Default
+    {
+        match (self, shape.len()) {
+            (_, 0) => Array::Scalar(v[0]),
+            (Array::Array1D { .. } | Array::Array2D { .. }, _) => Array::Array1D { arr: v.into_boxed_slice() },
+            _ => {
+                let strides: Vec<usize> = nd_strides(&shape, true);
+                Array::ArrayND { arr: v.into_boxed_slice(), shape, strides }
+            },
+        }
+    }
+
+    // f on every lane, an empty lane is an error
+    fn reduce_lanes<U>(&self, axis: Option<usize>, f: impl Fn(&[T], Lane) -> U) -> Result<Array<U>, ListError>
+    where U: Add<Output=U> + Mul<Output=U> + Div<Output=U>
+    + PartialEq + AddAssign + Copy + MulAssign + SubAssign
+    + Default
+    {
+        let (arr, lanes) = self.lanes(axis)?;
+        if lanes.len == 0 {return Err(ListError::EmptyArray);}
+        let v: Vec<U> = (0..lanes.count()).map(|i| f(arr, lanes.lane(i))).collect();
+        Ok(self.collect_lanes(lanes.shape, v))
+    }
+
+    // index in the lane of the first value that wins over all others
+    fn arg_lanes(&self, axis: Option<usize>, wins: impl Fn(T, T) -> bool) -> Result<Array<usize>, ListError> {
+        self.reduce_lanes(axis, |arr, lane| {
+            let (mut best, mut best_val) = (0, arr[lane.start]);
+            for (i, p) in lane.iter().enumerate().skip(1) {
+                if wins(arr[p], best_val) {(best, best_val) = (i, arr[p]);}
+            }
+            best
+        })
+    }
+
+    // running f along every lane, the result keeps the shape and layout,
+    // axis None gives an Array1D in C order
+    fn scan_lanes(&self, axis: Option<usize>, f: impl Fn(&[T], Lane, &mut dyn FnMut(usize, T))) -> Result<Self, ListError> {
+        let (arr, lanes) = self.lanes(axis)?;
+        let mut out: Vec<T> = match axis {
+            None => vec![T::default(); lanes.len],
+            Some(_) => arr.to_vec(),
+        };
+        for i in 0..lanes.count() {
+            let lane: Lane = lanes.lane(i);
+            match axis {
+                None => f(arr, lane, &mut |j, val| out[j] = val),
+                Some(_) => f(arr, lane, &mut |j, val| out[lane.pos(j)] = val),
+            }
+        }
+
+        let mut res: Self = match axis {
+            None => return Ok(Array::Array1D { arr: out.into_boxed_slice() }),
+            Some(_) => self.clone(),
+        };
+        match &mut res {
+            Array::Scalar(val) => *val = out[0],
+            Array::Array1D { arr } | Array::Array2D { arr, .. } | Array::ArrayND { arr, .. } => *arr = out.into_boxed_slice(),
+            Array::Null => {},
+        }
+        Ok(res)
+    }
+
+    /// sum with pairwise summation, over all elements when axis is None,
+    /// along axis 0 (down the columns) or 1 (along the rows) of an Array2D
+    /// ```
+    /// use simple_blas::array::Array;
+    ///
+    /// let a = Array::new_array_2d(Box::new([1, 2, 3, 4, 5, 6]), (2, 3), false).unwrap();
+    ///
+    /// assert_eq!(a.sum(None).unwrap(), Array::new_scalar(21));
+    /// assert_eq!(a.sum(Some(0)).unwrap(), Array::new_array_1d(Box::new([3, 7, 11])));
+    /// assert_eq!(a.sum(Some(1)).unwrap(), Array::new_array_1d(Box::new([9, 12])));
+    /// ```
+    pub fn sum(&self, axis: Option<usize>) -> Result<Self, ListError> {
+        self.sum_with(axis, Summation::Pairwise)
+    }
+
+    /// sum with the given summation, an empty lane sums to zero
+    /// ```
+    /// use simple_blas::array::{Array, Summation};
+    ///
+    /// let a = Array::new_array_1d(Box::new([0.1_f64; 10]));
+    /// assert_eq!(a.sum_with(None, Summation::Kahan).unwrap(), Array::new_scalar(1.0));
+    /// ```
+    pub fn sum_with(&self, axis: Option<usize>, summation: Summation) -> Result<Self, ListError> {
+        let (arr, lanes) = self.lanes(axis)?;
+        let v: Vec<T> = (0..lanes.count()).map(|i| {
+            let lane: Lane = lanes.lane(i);
+            match summation {
+                Summation::Pairwise => pairwise(0, lane.len, &|j| arr[lane.pos(j)]),
+                Summation::Kahan => kahan(lane.iter().map(|p| arr[p])),
+            }
+        }).collect();
+        Ok(self.collect_lanes(lanes.shape, v))
+    }
+
+    /// product, over all elements or along axis
+    pub fn prod(&self, axis: Option<usize>) -> Result<Self, ListError> {
+        self.reduce_lanes(axis, |arr, lane| {
+            let mut acc: T = arr[lane.start];
+            for p in lane.iter().skip(1) {acc *= arr[p];}
+            acc
+        })
+    }
+
+    /// min, over all elements or along axis
+    pub fn min(&self, axis: Option<usize>) -> Result<Self, ListError> {
+        self.reduce_lanes(axis, |arr, lane| {
+            let mut acc: T = arr[lane.start];
+            for p in lane.iter().skip(1) {if arr[p] < acc {acc = arr[p];}}
+            acc
+        })
+    }
+
+    /// max, over all elements or along axis
+    pub fn max(&self, axis: Option<usize>) -> Result<Self, ListError> {
+        self.reduce_lanes(axis, |arr, lane| {
+            let mut acc: T = arr[lane.start];
+            for p in lane.iter().skip(1) {if arr[p] > acc {acc = arr[p];}}
+            acc
+        })
+    }
+
+    /// index of the first min, axis None gives the index in
+    /// C order (r * nc + c for an Array2D in either layout)
+    /// ```
+    /// use simple_blas::array::Array;
+    ///
+    /// let a = Array::new_array_2d(Box::new([3, 1, 4, 1, 5, 9]), (2, 3), true).unwrap();
+    ///
+    /// assert_eq!(a.argmin(None).unwrap(), Array::new_scalar(1));
+    /// assert_eq!(a.argmax(Some(1)).unwrap(), Array::new_array_1d(Box::new([2, 2])));
+    /// ```
+    pub fn argmin(&self, axis: Option<usize>) -> Result<Array<usize>, ListError> {
+        self.arg_lanes(axis, |x, best| x < best)
+    }
+
+    /// index of the first max, see argmin
+    pub fn argmax(&self, axis: Option<usize>) -> Result<Array<usize>, ListError> {
+        self.arg_lanes(axis, |x, best| x > best)
+    }
+
+    /// running sum with Kahan compensation, along axis the
+    /// shape and layout are kept, axis None gives an Array1D in C order
+    /// ```
+    /// use simple_blas::array::Array;
+    ///
+    /// let a = Array::new_array_2d(Box::new([1, 2, 3, 4]), (2, 2), true).unwrap();
+    ///
+    /// assert_eq!(a.cumsum(None).unwrap(), Array::new_array_1d(Box::new([1, 3, 6, 10])));
+    /// assert_eq!(a.cumsum(Some(0)).unwrap(), Array::new_array_2d(Box::new([1, 2, 4, 6]), (2, 2), true).unwrap());
+    /// ```
+    pub fn cumsum(&self, axis: Option<usize>) -> Result<Self, ListError> {
+        self.scan_lanes(axis, |arr, lane, put| {
+            let mut sum: T = T::default();
+            let mut comp: T = T::default();
+            for (j, p) in lane.iter().enumerate() {
+                let y: T = arr[p] - comp;
+                let t: T = sum + y;
+                comp = (t - sum) - y;
+                sum = t;
+                put(j, sum);
+            }
+        })
+    }
+
+    /// running product, see cumsum
+    pub fn cumprod(&self, axis: Option<usize>) -> Result<Self, ListError> {
+        self.scan_lanes(axis, |arr, lane, put| {
+            let mut acc: Option<T> = None;
+            for (j, p) in lane.iter().enumerate() {
+                let val: T = match acc {Some(a) => a * arr[p], None => arr[p]};
+                acc = Some(val);
+                put(j, val);
+            }
+        })
+    }
+}
+
+impl<T> Array<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T> + Sub<Output=T>
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default + From<f32> + PartialOrd + Sqrt
+{
+    /// mean with pairwise summation, over all elements or along axis
+    /// ```
+    /// use simple_blas::array::Array;
+    ///
+    /// let a = Array::new_array_2d(Box::new([1.0, 2.0, 3.0, 4.0]), (2, 2), false).unwrap();
+    ///
+    /// assert_eq!(a.mean(None).unwrap(), Array::new_scalar(2.5));
+    /// assert_eq!(a.mean(Some(1)).unwrap(), Array::new_array_1d(Box::new([2.0, 3.0])));
+    /// ```
+    pub fn mean(&self, axis: Option<usize>) -> Result<Self, ListError> {
+        self.reduce_lanes(axis, |arr, lane| pairwise(0, lane.len, &|j| arr[lane.pos(j)]) / T::from(lane.len as f32))
+    }
+
+    /// population variance, sum of squared deviations from
+    /// the mean divided by n, the mean is taken first
+    /// ```
+    /// use simple_blas::array::Array;
+    ///
+    /// let a = Array::new_array_1d(Box::new([2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]));
+    ///
+    /// assert_eq!(a.var(None).unwrap(), Array::new_scalar(4.0));
+    /// assert_eq!(a.std(None).unwrap(), Array::new_scalar(2.0));
+    /// ```
+    pub fn var(&self, axis: Option<usize>) -> Result<Self, ListError> {
+        self.reduce_lanes(axis, |arr, lane| {
+            let n: T = T::from(lane.len as f32);
+            let mean: T = pairwise(0, lane.len, &|j| arr[lane.pos(j)]) / n;
+            pairwise(0, lane.len, &|j| {
+                let dev: T = arr[lane.pos(j)] - mean;
+                dev * dev
+            }) / n
+        })
+    }
+
+    /// population standard deviation, sqrt of var
+    pub fn std(&self, axis: Option<usize>) -> Result<Self, ListError> {
+        let mut v: Self = self.var(axis)?;
+        match &mut v {
+            Array::Scalar(val) => *val = val.sqrt(),
+            Array::Array1D { arr } | Array::ArrayND { arr, .. } => arr.iter_mut().for_each(|x| *x = x.sqrt()),
+            _ => {},
+        }
+        Ok(v)
+    }
+}
@@ -0,0 +1,109 @@
+use super::{Array, ListError, idxr};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
+use crate::array::matrix_operations::Sqrt;
+
+/// ## Singular value decomposition A = U S V'
+/// for a nr x nc matrix, k = min(nr, nc)
+///
+/// -1 u: Array2D, left singular vectors in column,
+/// nr x k from msvd, nr x nr from msvd_full
+///
+/// -2 s: Array1D, k singular values in descending order
+///
+/// -3 vt: Array2D, right singular vectors in row,
+/// k x nc from msvd, nc x nc from msvd_full
+///
+/// -4 n_iter: number of QR iterations used to converge
+/// ```
+/// use simple_blas::array::Array;
+///
+/// let arr = Array::new_array_2d(
+///     Box::new([3.0, 0.0, 0.0, -2.0_f64]), (2, 2), true
+/// ).unwrap();
+///
+/// let svd = arr.msvd().unwrap();
+/// assert!((svd.s[0] - 3.0).abs() < 1e-10);
+/// assert!((svd.s[1] - 2.0).abs() < 1e-10);
+/// ```
+#[derive(Debug)]
+pub struct Svd<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default
+{
+    pub u: Array<T>,
+    pub s: Array<T>,
+    pub vt: Array<T>,
+    pub n_iter: usize,
+}
+
+impl<T> Array<T>
+where T: Sqrt +
+Add<Output=T> + Mul<Output=T> +
+Div<Output=T> + Sub<Output=T>
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default + PartialOrd + From<f32>
+{
+    /// thin singular value decomposition
+    pub fn msvd(&self) -> Result<Svd<T>, ListError> {
+        self.svd(false)
+    }
+
+    /// full singular value decomposition, U and V' are SQUARE
+    pub fn msvd_full(&self) -> Result<Svd<T>, ListError> {
+        self.svd(true)
+    }
+
+    fn svd(&self, full: bool) -> Result<Svd<T>, ListError> {
+        match self {
+            Array::Array2D { arr, nr, nc, put_val_by_row }
+            => {
+                let (nr, nc) = (*nr, *nc);
+                let k: usize = if nr < nc {nr} else {nc};
+
+                // keep first k columns of a row major SQUARE matrix
+                let first_columns = |mat: &[T], n: usize| -> Vec<T> {
+                    let mut res: Vec<T> = vec![T::default(); n * k];
+                    for r in 0..n {
+                        for c in 0..k {
+                            res[idxr(r, c, (n, k))] = mat[idxr(r, c, (n, n))];
+                        }
+                    }
+                    res
+                };
+
+                // row major buffer read by column is its transpose
+                let (u, dimu, vt, dimvt, s, n_iter) =
+                if nr >= nc {
+                    let (u, s, v, n_iter) = Array::golub_kahan_svd(arr, (nr, nc), *put_val_by_row, None)?;
+                    let (u, dimu) = if full {(u, (nr, nr))} else {(first_columns(&u, nr), (nr, k))};
+                    (u, dimu, v, (nc, nc), s, n_iter)
+                } else {
+                    // A' = U1 S V1'
+                    // A = V1 S U1'
+                    let (u1, s, v1, n_iter) = Array::golub_kahan_svd(arr, (nc, nr), !*put_val_by_row, None)?;
+                    let (vt, dimvt) = if full {(u1, (nc, nc))} else {(first_columns(&u1, nc), (k, nc))};
+                    (v1, (nr, nr), vt, dimvt, s, n_iter)
+                };
+
+                let u: Array<T> = Array::new_array_2d(
+                    u.into_boxed_slice(),
+                    (dimu.0 as isize, dimu.1 as isize),
+                    true
+                )?;
+
+                let vt: Array<T> = Array::new_array_2d(
+                    vt.into_boxed_slice(),
+                    (dimvt.0 as isize, dimvt.1 as isize),
+                    false
+                )?;
+
+                let s: Array<T> = Array::new_array_1d(s.into_boxed_slice());
+
+                Ok(Svd { u, s, vt, n_iter })
+            },
+
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+}
@@ -0,0 +1,85 @@
+// arithmetic returning new arrays, inputs are not changed
+
+use super::matrix_operations::Sqrt;
+use super::{Array, ListError};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
+
+impl<T> Array<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
++ Sub<Output=T> + PartialOrd
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default
+{
+    /// self + other, same rules as madd
+    /// ```
+    /// use simple_blas::array::Array;
+    ///
+    /// let a = Array::new_array_2d(Box::new([1, 2, 3, 4]), (2, 2), true).unwrap();
+    /// let b = Array::new_array_1d(Box::new([10, 20]));
+    ///
+    /// let c = a.added(&b).unwrap();
+    /// assert_eq!(c, Array::new_array_2d(Box::new([11, 22, 13, 24]), (2, 2), true).unwrap());
+    /// // a is unchanged
+    /// assert_eq!(a, Array::new_array_2d(Box::new([1, 2, 3, 4]), (2, 2), true).unwrap());
+    /// ```
+    pub fn added(&self, other: &Self) -> Result<Self, ListError> {
+        let mut res: Array<T> = self.clone();
+        res.madd(other)?;
+        Ok(res)
+    }
+
+    /// self - other, same rules as mminus
+    pub fn subtracted(&self, other: &Self) -> Result<Self, ListError> {
+        let mut res: Array<T> = self.clone();
+        res.mminus(other)?;
+        Ok(res)
+    }
+
+    /// element-wise self * other, same rules as ele_mult
+    pub fn ele_multiplied(&self, other: &Self) -> Result<Self, ListError> {
+        let mut res: Array<T> = self.clone();
+        res.ele_mult(other)?;
+        Ok(res)
+    }
+
+    /// element-wise self / other, same rules as ele_div
+    pub fn ele_divided(&self, other: &Self) -> Result<Self, ListError> {
+        let mut res: Array<T> = self.clone();
+        res.ele_div(other)?;
+        Ok(res)
+    }
+
+    /// every element times val
+    pub fn scaled(&self, val: T) -> Result<Self, ListError> {
+        let mut res: Array<T> = self.clone();
+        res.mult(val)?;
+        Ok(res)
+    }
+
+    /// matrix product self other, same rules as mmult
+    pub fn matmul(&self, other: &Self) -> Result<Self, ListError> {
+        let mut res: Array<T> = self.clone();
+        res.mmult(other)?;
+        Ok(res)
+    }
+
+    /// transpose of Array2D
+    pub fn transposed(&self) -> Result<Self, ListError> {
+        let mut res: Array<T> = self.clone();
+        res.transpose()?;
+        Ok(res)
+    }
+}
+
+impl<T> Array<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T> + Sub<Output=T>
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default + From<f32> + PartialOrd + Sqrt
+{
+    /// A^-1, same rules as minv
+    pub fn inverse(&self) -> Result<Self, ListError> {
+        let mut res: Array<T> = self.clone();
+        res.minv()?;
+        Ok(res)
+    }
+}
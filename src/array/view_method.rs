@@ -0,0 +1,478 @@
+// borrowed views of Array2D (and Array1D as a 1 x n row)
+//
+// a view is offset, shape and strides into the buffer:
+//  v[r, c] = arr[offset + r * rs + c * cs]
+// so row, column, sub-block, diagonal and transpose
+// are new views of the same buffer, nothing is copied
+
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Range, Sub, SubAssign};
+
+use super::blas1_method::VecView;
+use super::gemm_method::{Strided, Transpose};
+use super::matrix_operations::Sqrt;
+use super::{Array, Eigen, ListError, LuFactorization, Norm, Svd};
+
+/// ## Borrowed view of an Array
+/// offset, shape and row / column strides into the buffer,
+/// row, col, slice, diag and t give views of the same buffer
+/// ```
+/// use simple_blas::array::Array;
+///
+/// let a = Array::new_array_2d(Box::new([1, 2, 3, 4, 5, 6, 7, 8, 9]), (3, 3), true).unwrap();
+/// let v = a.view().unwrap();
+///
+/// assert_eq!(v.row(1).unwrap().to_array(), Array::new_array_2d(Box::new([4, 5, 6]), (1, 3), true).unwrap());
+/// assert_eq!(v.col(2).unwrap()[(0, 0)], 3);
+/// assert_eq!(v.slice((1..3, 0..2)).unwrap()[(1, 1)], 8);
+/// assert_eq!(v.diag()[(2, 0)], 9);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ArrayView<'a, T> {
+    arr: &'a [T],
+    offset: usize,
+    nr: usize,
+    nc: usize,
+    rs: usize,
+    cs: usize,
+}
+
+/// ## Mutable borrowed view of an Array
+/// same as ArrayView, the sub views borrow this view
+/// ```
+/// use simple_blas::array::Array;
+///
+/// let mut a = Array::new_array_2d(Box::new([1, 2, 3, 4]), (2, 2), false).unwrap();
+///
+/// // second column times 10
+/// a.view_mut().unwrap().col_mut(1).unwrap().mult(10);
+/// assert_eq!(a, Array::new_array_2d(Box::new([1, 2, 30, 40]), (2, 2), false).unwrap());
+/// ```
+#[derive(Debug)]
+pub struct ArrayViewMut<'a, T> {
+    arr: &'a mut [T],
+    offset: usize,
+    nr: usize,
+    nc: usize,
+    rs: usize,
+    cs: usize,
+}
+
+// offset, shape and strides of a view
+#[derive(Clone, Copy)]
+struct Layout {
+    offset: usize,
+    nr: usize,
+    nc: usize,
+    rs: usize,
+    cs: usize,
+}
+
+impl Layout {
+    fn pos(&self, r: usize, c: usize) -> usize {
+        if r >= self.nr || c >= self.nc {panic!("index out of bound");}
+        self.offset + r * self.rs + c * self.cs
+    }
+
+    // (r0..r1, c0..c1) of the view
+    fn slice(&self, rows: Range<usize>, cols: Range<usize>) -> Result<Layout, ListError> {
+        if rows.start > rows.end || rows.end > self.nr || cols.start > cols.end || cols.end > self.nc {
+            return Err(ListError::OutOfBounds);
+        }
+        let (nr, nc) = (rows.end - rows.start, cols.end - cols.start);
+        // an empty view reads nothing
+        let offset: usize = if nr == 0 || nc == 0 {0} else {self.offset + rows.start * self.rs + cols.start * self.cs};
+        Ok(Layout { offset, nr, nc, rs: self.rs, cs: self.cs })
+    }
+
+    // main diagonal as a column
+    fn diag(&self) -> Layout {
+        let n: usize = if self.nr < self.nc {self.nr} else {self.nc};
+        Layout { offset: if n == 0 {0} else {self.offset}, nr: n, nc: 1, rs: self.rs + self.cs, cs: 0 }
+    }
+
+    fn t(&self) -> Layout {
+        Layout { offset: self.offset, nr: self.nc, nc: self.nr, rs: self.cs, cs: self.rs }
+    }
+
+    fn strided(&self) -> Strided {
+        Strided { nr: self.nr, nc: self.nc, rs: self.rs, cs: self.cs }
+    }
+
+    // (n, inc) of a single row or column
+    fn as_vec(&self) -> Result<(usize, usize), ListError> {
+        if self.nr == 1 {Ok((self.nc, self.cs))}
+        else if self.nc == 1 {Ok((self.nr, self.rs))}
+        else {Err(ListError::MismatchedTypes)}
+    }
+}
+
+impl<'a, T: Copy> ArrayView<'a, T> {
+    fn layout(&self) -> Layout {
+        Layout { offset: self.offset, nr: self.nr, nc: self.nc, rs: self.rs, cs: self.cs }
+    }
+
+    fn with_layout(&self, l: Layout) -> ArrayView<'a, T> {
+        ArrayView { arr: self.arr, offset: l.offset, nr: l.nr, nc: l.nc, rs: l.rs, cs: l.cs }
+    }
+
+    // buffer from offset and strides for the strided kernels
+    pub(crate) fn strided(&self) -> (&'a [T], Strided) {
+        (&self.arr[self.offset..], self.layout().strided())
+    }
+
+    /// [nr, nc]
+    pub fn shape(&self) -> Vec<usize> {
+        vec![self.nr, self.nc]
+    }
+
+    /// v[r, c]
+    pub fn get(&self, r: usize, c: usize) -> T {
+        self.arr[self.layout().pos(r, c)]
+    }
+
+    pub(crate) fn at(&self, r: usize, c: usize) -> &'a T {
+        &self.arr[self.layout().pos(r, c)]
+    }
+
+    /// row i as a 1 x nc view
+    pub fn row(&self, i: usize) -> Result<ArrayView<'a, T>, ListError> {
+        Ok(self.with_layout(self.layout().slice(i..i + 1, 0..self.nc)?))
+    }
+
+    /// column j as a nr x 1 view
+    pub fn col(&self, j: usize) -> Result<ArrayView<'a, T>, ListError> {
+        Ok(self.with_layout(self.layout().slice(0..self.nr, j..j + 1)?))
+    }
+
+    /// sub-block rows r0..r1, columns c0..c1
+    pub fn slice(&self, (rows, cols): (Range<usize>, Range<usize>)) -> Result<ArrayView<'a, T>, ListError> {
+        Ok(self.with_layout(self.layout().slice(rows, cols)?))
+    }
+
+    /// main diagonal as a min(nr, nc) x 1 view
+    pub fn diag(&self) -> ArrayView<'a, T> {
+        self.with_layout(self.layout().diag())
+    }
+
+    /// transposed view
+    pub fn t(&self) -> ArrayView<'a, T> {
+        self.with_layout(self.layout().t())
+    }
+
+    /// a single row or column as a VecView for the level 1 routines
+    pub fn vec_view(&self) -> Result<VecView<'a, T>, ListError> {
+        let (n, inc) = self.layout().as_vec()?;
+        Ok(VecView::from_parts(self.arr, n, self.offset, inc as isize))
+    }
+}
+
+impl<'a, T: Copy> ArrayViewMut<'a, T> {
+    fn layout(&self) -> Layout {
+        Layout { offset: self.offset, nr: self.nr, nc: self.nc, rs: self.rs, cs: self.cs }
+    }
+
+    fn with_layout(&mut self, l: Layout) -> ArrayViewMut<'_, T> {
+        ArrayViewMut { arr: &mut *self.arr, offset: l.offset, nr: l.nr, nc: l.nc, rs: l.rs, cs: l.cs }
+    }
+
+    /// [nr, nc]
+    pub fn shape(&self) -> Vec<usize> {
+        vec![self.nr, self.nc]
+    }
+
+    /// v[r, c]
+    pub fn get(&self, r: usize, c: usize) -> T {
+        self.arr[self.layout().pos(r, c)]
+    }
+
+    pub(crate) fn at(&self, r: usize, c: usize) -> &T {
+        &self.arr[self.layout().pos(r, c)]
+    }
+
+    pub(crate) fn at_mut(&mut self, r: usize, c: usize) -> &mut T {
+        let pos: usize = self.layout().pos(r, c);
+        &mut self.arr[pos]
+    }
+
+    /// read only view of the same elements
+    pub fn view(&self) -> ArrayView<'_, T> {
+        ArrayView { arr: &*self.arr, offset: self.offset, nr: self.nr, nc: self.nc, rs: self.rs, cs: self.cs }
+    }
+
+    /// row i as a 1 x nc view
+    pub fn row_mut(&mut self, i: usize) -> Result<ArrayViewMut<'_, T>, ListError> {
+        let l: Layout = self.layout().slice(i..i + 1, 0..self.nc)?;
+        Ok(self.with_layout(l))
+    }
+
+    /// column j as a nr x 1 view
+    pub fn col_mut(&mut self, j: usize) -> Result<ArrayViewMut<'_, T>, ListError> {
+        let l: Layout = self.layout().slice(0..self.nr, j..j + 1)?;
+        Ok(self.with_layout(l))
+    }
+
+    /// sub-block rows r0..r1, columns c0..c1
+    pub fn slice_mut(&mut self, (rows, cols): (Range<usize>, Range<usize>)) -> Result<ArrayViewMut<'_, T>, ListError> {
+        let l: Layout = self.layout().slice(rows, cols)?;
+        Ok(self.with_layout(l))
+    }
+
+    /// main diagonal as a min(nr, nc) x 1 view
+    pub fn diag_mut(&mut self) -> ArrayViewMut<'_, T> {
+        let l: Layout = self.layout().diag();
+        self.with_layout(l)
+    }
+
+    /// transposed view
+    pub fn t_mut(&mut self) -> ArrayViewMut<'_, T> {
+        let l: Layout = self.layout().t();
+        self.with_layout(l)
+    }
+
+    // every element, f(&mut v[r, c])
+    fn for_each(&mut self, mut f: impl FnMut(&mut T)) {
+        for r in 0..self.nr {
+            for c in 0..self.nc {
+                f(self.at_mut(r, c));
+            }
+        }
+    }
+
+    // every element, f(r, c, &mut v[r, c]), stop at the first error
+    fn try_for_each(&mut self, mut f: impl FnMut(usize, usize, &mut T) -> Result<(), ListError>) -> Result<(), ListError> {
+        for r in 0..self.nr {
+            for c in 0..self.nc {
+                f(r, c, self.at_mut(r, c))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> Array<T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
++ Sub<Output=T> + PartialOrd
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default
+{
+    /// view of an Array2D, Array1D is a 1 x n row
+    pub fn view(&self) -> Result<ArrayView<'_, T>, ListError> {
+        match self {
+            Array::Array1D { arr } => Ok(ArrayView { arr, offset: 0, nr: 1, nc: arr.len(), rs: arr.len(), cs: 1 }),
+            Array::Array2D { arr, nr, nc, put_val_by_row } => {
+                let s: Strided = Strided::new(*nr, *nc, *put_val_by_row);
+                Ok(ArrayView { arr, offset: 0, nr: *nr, nc: *nc, rs: s.rs, cs: s.cs })
+            },
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+
+    /// mutable view of an Array2D, Array1D is a 1 x n row
+    pub fn view_mut(&mut self) -> Result<ArrayViewMut<'_, T>, ListError> {
+        match self {
+            Array::Array1D { arr } => {
+                let n: usize = arr.len();
+                Ok(ArrayViewMut { arr, offset: 0, nr: 1, nc: n, rs: n, cs: 1 })
+            },
+            Array::Array2D { arr, nr, nc, put_val_by_row } => {
+                let s: Strided = Strided::new(*nr, *nc, *put_val_by_row);
+                Ok(ArrayViewMut { arr, offset: 0, nr: *nr, nc: *nc, rs: s.rs, cs: s.cs })
+            },
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+}
+
+impl<'a, T> ArrayView<'a, T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
++ Sub<Output=T> + PartialOrd
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default
+{
+    /// copy to a new Array2D put by row
+    pub fn to_array(&self) -> Array<T> {
+        let mut v: Vec<T> = Vec::with_capacity(self.nr * self.nc);
+        for r in 0..self.nr {
+            for c in 0..self.nc {
+                v.push(self.get(r, c));
+            }
+        }
+        Array::Array2D { arr: v.into_boxed_slice(), nr: self.nr, nc: self.nc, put_val_by_row: true }
+    }
+
+    /// matrix product as a new Array2D put by row
+    /// ```
+    /// use simple_blas::array::Array;
+    ///
+    /// let a = Array::new_array_2d(Box::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]), (2, 3), true).unwrap();
+    /// let v = a.view().unwrap();
+    ///
+    /// // first two columns times their transpose
+    /// let b = v.slice((0..2, 0..2)).unwrap();
+    /// let c = b.matmul(&b.t()).unwrap();
+    /// assert_eq!(c, Array::new_array_2d(Box::new([5.0, 14.0, 14.0, 41.0]), (2, 2), true).unwrap());
+    /// ```
+    pub fn matmul(&self, other: &ArrayView<'_, T>) -> Result<Array<T>, ListError> {
+        if self.nc != other.nr {return Err(ListError::ShapeMismatch { lhs: self.shape(), rhs: other.shape() });}
+        let (arr_a, sa) = self.strided();
+        let (arr_b, sb) = other.strided();
+        let sc: Strided = Strided::new(self.nr, other.nc, true);
+        let mut c: Vec<T> = vec![T::default(); self.nr * other.nc];
+        Array::gemm_kernel(None, arr_a, sa, arr_b, sb, T::default(), &mut c, sc);
+        Ok(Array::Array2D { arr: c.into_boxed_slice(), nr: self.nr, nc: other.nc, put_val_by_row: true })
+    }
+}
+
+impl<'a, T> ArrayViewMut<'a, T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T>
++ Sub<Output=T> + PartialOrd
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default
+{
+    /// copy to a new Array2D put by row
+    pub fn to_array(&self) -> Array<T> {
+        self.view().to_array()
+    }
+
+    /// add val to every element
+    pub fn add(&mut self, val: T) {
+        self.for_each(|x| *x += val);
+    }
+
+    /// multiply every element by val
+    pub fn mult(&mut self, val: T) {
+        self.for_each(|x| *x *= val);
+    }
+
+    /// copy other into this view, same shape
+    pub fn assign(&mut self, other: &ArrayView<'_, T>) -> Result<(), ListError> {
+        self.ele_op(other, |x, y| {*x = y; Ok(())})
+    }
+
+    /// element-wise add, same shape
+    pub fn madd(&mut self, other: &ArrayView<'_, T>) -> Result<(), ListError> {
+        self.ele_op(other, |x, y| {*x += y; Ok(())})
+    }
+
+    /// element-wise minus, same shape
+    pub fn mminus(&mut self, other: &ArrayView<'_, T>) -> Result<(), ListError> {
+        self.ele_op(other, |x, y| {*x -= y; Ok(())})
+    }
+
+    /// element-wise mult, same shape
+    pub fn ele_mult(&mut self, other: &ArrayView<'_, T>) -> Result<(), ListError> {
+        self.ele_op(other, |x, y| {*x *= y; Ok(())})
+    }
+
+    /// element-wise div, same shape
+    pub fn ele_div(&mut self, other: &ArrayView<'_, T>) -> Result<(), ListError> {
+        self.ele_op(other, |x, y| {
+            if y == T::default() {return Err(ListError::DivisionByZero);}
+            *x = *x / y;
+            Ok(())
+        })
+    }
+
+    /// C = alpha op(A) op(B) + beta C on views, self is C,
+    /// when beta is zero C is not read
+    /// ```
+    /// use simple_blas::array::{Array, Transpose};
+    ///
+    /// let a = Array::new_array_2d(Box::new([1.0, 2.0, 3.0, 4.0]), (2, 2), true).unwrap();
+    /// let mut c = Array::new_array_2d(Box::new([0.0; 9]), (3, 3), true).unwrap();
+    ///
+    /// // lower right block of C = A A'
+    /// let va = a.view().unwrap();
+    /// c.view_mut().unwrap().slice_mut((1..3, 1..3)).unwrap()
+    ///     .gemm(1.0, &va, Transpose::NoTrans, &va, Transpose::Trans, 0.0).unwrap();
+    /// assert_eq!(c[(2, 2)], 25.0);
+    /// assert_eq!(c[(0, 0)], 0.0);
+    /// ```
+    pub fn gemm(
+        &mut self,
+        alpha: T,
+        a: &ArrayView<'_, T>, trans_a: Transpose,
+        b: &ArrayView<'_, T>, trans_b: Transpose,
+        beta: T,
+    ) -> Result<(), ListError>
+    {
+        let (arr_a, sa) = a.strided();
+        let (arr_b, sb) = b.strided();
+        let (sa, sb) = (sa.op(trans_a), sb.op(trans_b));
+        let sc: Strided = self.layout().strided();
+        if sa.nc != sb.nr {
+            return Err(ListError::ShapeMismatch { lhs: vec![sa.nr, sa.nc], rhs: vec![sb.nr, sb.nc] });
+        }
+        if (sa.nr, sb.nc) != (sc.nr, sc.nc) {
+            return Err(ListError::ShapeMismatch { lhs: vec![sa.nr, sb.nc], rhs: vec![sc.nr, sc.nc] });
+        }
+
+        let offset: usize = self.offset;
+        Array::gemm_kernel(Some(alpha), arr_a, sa, arr_b, sb, beta, &mut self.arr[offset..], sc);
+        Ok(())
+    }
+
+    fn ele_op(
+        &mut self, other: &ArrayView<'_, T>,
+        op: impl Fn(&mut T, T) -> Result<(), ListError>,
+    ) -> Result<(), ListError>
+    {
+        if (self.nr, self.nc) != (other.nr, other.nc) {
+            return Err(ListError::ShapeMismatch { lhs: self.shape(), rhs: other.shape() });
+        }
+        self.try_for_each(|r, c, x| op(x, other.get(r, c)))
+    }
+}
+
+// factorizations copy the view to a new Array2D,
+// as the Array2D methods copy their input as well
+impl<'a, T> ArrayView<'a, T>
+where T: Add<Output=T> + Mul<Output=T> + Div<Output=T> + Sub<Output=T>
++ PartialEq + AddAssign + Copy + MulAssign + SubAssign
++ Default + From<f32> + PartialOrd + Sqrt
+{
+    /// mdet of the view
+    pub fn mdet(&self) -> Result<T, ListError> {
+        self.to_array().mdet()
+    }
+
+    /// inverse of the view
+    pub fn inverse(&self) -> Result<Array<T>, ListError> {
+        self.to_array().inverse()
+    }
+
+    /// mlu of the view
+    pub fn mlu(&self) -> Result<LuFactorization<T>, ListError> {
+        self.to_array().mlu()
+    }
+
+    /// mqr of the view
+    pub fn mqr(&self) -> Result<(Array<T>, Array<T>), ListError> {
+        self.to_array().mqr()
+    }
+
+    /// mcholesky of the view
+    pub fn mcholesky(&self) -> Result<Array<T>, ListError> {
+        self.to_array().mcholesky()
+    }
+
+    /// meig of the view
+    pub fn meig(&self) -> Result<Eigen<T>, ListError> {
+        self.to_array().meig()
+    }
+
+    /// msvd of the view
+    pub fn msvd(&self) -> Result<Svd<T>, ListError> {
+        self.to_array().msvd()
+    }
+
+    /// solve A x = b, A is the view
+    pub fn msolve(&self, b: &Array<T>) -> Result<Array<T>, ListError> {
+        self.to_array().msolve(b)
+    }
+
+    /// norm of the view as a matrix
+    pub fn norm(&self, kind: Norm) -> Result<T, ListError> {
+        self.to_array().norm(kind)
+    }
+}
@@ -0,0 +1,122 @@
+#[cfg(test)]
+pub mod tests {
+    use simple_blas::array::{Array, ListError};
+
+    fn vec1(v: &[f64]) -> Array<f64> {
+        Array::new_array_1d(v.to_vec().into_boxed_slice())
+    }
+
+    #[test]
+    fn level1_whole_vectors() -> Result<(), ListError> {
+        let x = vec1(&[1.0, -4.0, 2.0]);
+        let mut y = vec1(&[3.0, 1.0, -1.0]);
+
+        assert_eq!(x.dot(&y)?, -3.0);
+        assert_eq!(x.asum()?, 7.0);
+        assert_eq!(x.iamax()?, Some(1));
+        assert!((x.nrm2()? - 21.0_f64.sqrt()).abs() < 1e-12);
+
+        // y = 2 x + y
+        y.axpy(2.0, &x)?;
+        assert_eq!(y, vec1(&[5.0, -7.0, 3.0]));
+
+        y.scal(-1.0)?;
+        assert_eq!(y, vec1(&[-5.0, 7.0, -3.0]));
+
+        let mut z = vec1(&[0.0, 0.0, 0.0]);
+        z.copy(&x)?;
+        assert_eq!(z, x);
+
+        z.swap(&mut y)?;
+        assert_eq!(z, vec1(&[-5.0, 7.0, -3.0]));
+        assert_eq!(y, x);
+
+        Ok(())
+    }
+
+    #[test]
+    fn level1_strided_views() -> Result<(), ListError> {
+        let x = vec1(&[1.0, 0.0, 2.0, 0.0, 3.0, 0.0]);
+        let mut y = vec1(&[10.0, 20.0, 30.0]);
+
+        // [1, 2, 3], [3, 2, 1], [2, 2, 2]
+        let even = x.vec_view(3, 0, 2)?;
+        let back = x.vec_view(3, 0, -2)?;
+        let same = x.vec_view(3, 2, 0)?;
+        assert_eq!(even.dot(&back)?, 10.0);
+        assert_eq!(even.dot(&same)?, 12.0);
+        assert_eq!(back.iamax(), Some(0));
+        assert_eq!(even.asum(), 6.0);
+
+        // y walked backwards: y = [30, 20, 10] + [1, 2, 3]
+        y.vec_view_mut(3, 0, -1)?.axpy(1.0, &even)?;
+        assert_eq!(y, vec1(&[13.0, 22.0, 31.0]));
+
+        // odd elements of z = 2 * [1, 2, 3]
+        let mut z = vec1(&[0.0; 6]);
+        {
+            let mut odd = z.vec_view_mut(3, 1, 2)?;
+            odd.copy(&even)?;
+            odd.scal(2.0);
+            assert!((odd.view().nrm2() - 56.0_f64.sqrt()).abs() < 1e-12);
+        }
+        assert_eq!(z, vec1(&[0.0, 2.0, 0.0, 4.0, 0.0, 6.0]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn level1_rotations() -> Result<(), ListError> {
+        // zero b of (a, b)
+        for (a, b) in [(3.0_f64, 4.0), (-4.0, 3.0), (0.0, 2.0), (5.0, 0.0), (1e200, 1e200)] {
+            let (r, _z, c, s) = Array::rotg(a, b);
+            assert!((c * a + s * b - r).abs() <= 1e-12 * r.abs());
+            assert!((c * b - s * a).abs() <= 1e-12 * r.abs());
+            assert!((c * c + s * s - 1.0_f64).abs() < 1e-12);
+        }
+        assert_eq!(Array::rotg(0.0, 0.0), (0.0, 0.0, 1.0, 0.0));
+
+        // sign of r follows the larger one, z as in BLAS
+        let (r, z, _c, _s) = Array::rotg(-4.0_f64, 3.0);
+        assert_eq!(r, -5.0);
+        assert!((z + 0.6).abs() < 1e-12);
+        let (_r, z, c, _s) = Array::rotg(3.0_f64, 4.0);
+        assert!((z - 1.0 / c).abs() < 1e-12);
+
+        // rotate so y[0] becomes zero
+        let mut x = vec1(&[3.0, 1.0]);
+        let mut y = vec1(&[4.0, 2.0]);
+        let (r, _z, c, s) = Array::rotg(3.0, 4.0);
+        x.rot(&mut y, c, s)?;
+        assert!((x[0] - r).abs() < 1e-12 && y[0].abs() < 1e-12);
+        assert!((x[1] - 2.2).abs() < 1e-12 && (y[1] - 0.4).abs() < 1e-12);
+
+        Ok(())
+    }
+
+    #[test]
+    fn level1_errors() {
+        let x = vec1(&[1.0, 2.0, 3.0]);
+        let mut y = vec1(&[1.0, 2.0]);
+
+        if let Err(error) = x.dot(&y) {
+            assert_eq!(error, ListError::ShapeMismatch { lhs: vec![3], rhs: vec![2] })
+        };
+        if let Err(error) = y.axpy(1.0, &x) {
+            assert_eq!(error, ListError::ShapeMismatch { lhs: vec![2], rhs: vec![3] })
+        };
+        if let Err(error) = x.vec_view(2, 1, 2) {
+            assert_eq!(error, ListError::InvalidStride)
+        };
+        if let Err(error) = y.vec_view_mut(2, 0, 0) {
+            assert_eq!(error, ListError::InvalidStride)
+        };
+
+        let m = Array::new_array_2d(Box::new([1.0, 2.0, 3.0, 4.0]), (2, 2), true).unwrap();
+        if let Err(error) = m.nrm2() {
+            assert_eq!(error, ListError::MismatchedTypes)
+        };
+
+        assert_eq!(vec1(&[]).iamax(), Ok(None));
+    }
+}
@@ -0,0 +1,168 @@
+mod common;
+
+#[cfg(test)]
+pub mod tests {
+    use crate::common::{close, mat, vec1};
+    use simple_blas::array::{Array, Diag, ListError, Transpose, Uplo};
+
+    const TRANS: [Transpose; 2] = [Transpose::NoTrans, Transpose::Trans];
+    const UPLO: [Uplo; 2] = [Uplo::Upper, Uplo::Lower];
+    const DIAG: [Diag; 2] = [Diag::NonUnit, Diag::Unit];
+
+    // op(A)[r, c]
+    fn op_at(a: &Array<f64>, trans: Transpose, r: usize, c: usize) -> f64 {
+        if trans == Transpose::Trans {a[(c, r)]} else {a[(r, c)]}
+    }
+
+    // op(A) of the triangle with diag, as a full matrix value
+    fn tri_at(a: &Array<f64>, uplo: Uplo, trans: Transpose, diag: Diag, r: usize, c: usize) -> f64 {
+        let (i, j) = if trans == Transpose::Trans {(c, r)} else {(r, c)};
+        if i == j {return if diag == Diag::Unit {1.0} else {a[(i, j)]};}
+        let inside: bool = if uplo == Uplo::Upper {i < j} else {i > j};
+        if inside {a[(i, j)]} else {0.0}
+    }
+
+    #[test]
+    fn gemv_all_layouts() -> Result<(), ListError> {
+        for by_row in [true, false] {
+            for trans in TRANS {
+                let a = mat(3, 4, by_row, |r, c| (r * 4 + c) as f64 - 5.0);
+                let (m, n) = if trans == Transpose::Trans {(4, 3)} else {(3, 4)};
+                let x = vec1(n, |i| i as f64 + 1.0);
+                let y0 = vec1(m, |i| 2.0 - i as f64);
+
+                let mut y = y0.clone();
+                Array::gemv(2.0, &a, trans, &x, -0.5, &mut y)?;
+                let expect = vec1(m, |r| {
+                    2.0 * (0..n).map(|c| op_at(&a, trans, r, c) * x[c]).sum::<f64>() - 0.5 * y0[r]
+                });
+                assert!(close(&y, &expect));
+
+                // beta zero does not read y
+                let mut y = vec1(m, |_| f64::NAN);
+                Array::gemv(1.0, &a, trans, &x, 0.0, &mut y)?;
+                let expect = vec1(m, |r| (0..n).map(|c| op_at(&a, trans, r, c) * x[c]).sum::<f64>());
+                assert!(close(&y, &expect));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn ger_all_layouts() -> Result<(), ListError> {
+        for by_row in [true, false] {
+            let x = vec1(3, |i| i as f64 + 1.0);
+            let y = vec1(2, |i| i as f64 - 1.0);
+            let mut a = mat(3, 2, by_row, |r, c| (r + c) as f64);
+
+            Array::ger(3.0, &x, &y, &mut a)?;
+            assert!(close(&a, &mat(3, 2, true, |r, c| (r + c) as f64 + 3.0 * x[r] * y[c])));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn trmv_trsv_all_options() -> Result<(), ListError> {
+        let n: usize = 4;
+        for by_row in [true, false] {
+            // the other triangle is garbage and must not be read
+            let a = mat(n, n, by_row, |r, c| if r == c {2.0 + r as f64} else {(r * n + c) as f64 * 0.1 - 0.7});
+            for uplo in UPLO {
+                for trans in TRANS {
+                    for diag in DIAG {
+                        let b = vec1(n, |i| i as f64 - 1.5);
+
+                        let mut x = b.clone();
+                        Array::trmv(uplo, trans, diag, &a, &mut x)?;
+                        let expect = vec1(n, |r| (0..n).map(|c| tri_at(&a, uplo, trans, diag, r, c) * b[c]).sum::<f64>());
+                        assert!(close(&x, &expect));
+
+                        // solving gives b back
+                        Array::trsv(uplo, trans, diag, &a, &mut x)?;
+                        assert!(close(&x, &b));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn symv_all_layouts() -> Result<(), ListError> {
+        let n: usize = 3;
+        let sym = |r: usize, c: usize| (r.min(c) * 3 + r.max(c)) as f64;
+        for by_row in [true, false] {
+            for uplo in UPLO {
+                let a = mat(n, n, by_row, |r, c| {
+                    let inside: bool = if uplo == Uplo::Upper {r <= c} else {r >= c};
+                    if inside {sym(r, c)} else {100.0}
+                });
+                let x = vec1(n, |i| 1.0 - i as f64);
+                let mut y = vec1(n, |_| 1.0);
+
+                Array::symv(uplo, 2.0, &a, &x, 3.0, &mut y)?;
+                let expect = vec1(n, |r| 2.0 * (0..n).map(|c| sym(r, c) * x[c]).sum::<f64>() + 3.0);
+                assert!(close(&y, &expect));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn gemv_mismatched_dim() {
+        let a = mat(2, 3, true, |r, c| (r + c) as f64);
+        let x = vec1(2, |_| 1.0);
+        let mut y = vec1(2, |_| 1.0);
+
+        if let Err(error) = Array::gemv(1.0, &a, Transpose::NoTrans, &x, 0.0, &mut y) {
+            assert_eq!(error, ListError::ShapeMismatch { lhs: vec![2, 3], rhs: vec![2] })
+        };
+    }
+
+    #[test]
+    fn trsv_not_square() {
+        let a = mat(2, 3, true, |r, c| (r + c) as f64);
+        let mut y = vec1(2, |_| 1.0);
+
+        if let Err(error) = Array::trsv(Uplo::Upper, Transpose::NoTrans, Diag::NonUnit, &a, &mut y) {
+            assert_eq!(error, ListError::NotSquareMat)
+        };
+    }
+
+    #[test]
+    fn ger_mismatched_dim() {
+        let mut a = mat(2, 3, true, |r, c| (r + c) as f64);
+        let x = vec1(2, |_| 1.0);
+        let y = vec1(2, |_| 1.0);
+
+        if let Err(error) = Array::ger(1.0, &x, &y, &mut a) {
+            assert_eq!(error, ListError::ShapeMismatch { lhs: vec![2, 3], rhs: vec![2, 2] })
+        };
+    }
+
+    #[test]
+    fn trsv_singular() {
+        let singular = mat(2, 2, false, |r, c| if r == c && r == 1 {0.0} else {1.0});
+        let mut y = vec1(2, |_| 1.0);
+
+        if let Err(error) = Array::trsv(Uplo::Lower, Transpose::Trans, Diag::NonUnit, &singular, &mut y) {
+            assert_eq!(error, ListError::SingularMat)
+        };
+        // unit diagonal ignores the zero
+        assert!(Array::trsv(Uplo::Lower, Transpose::Trans, Diag::Unit, &singular, &mut y).is_ok());
+    }
+
+    #[test]
+    fn symv_not_arr_2d() {
+        let x = vec1(2, |_| 1.0);
+        let mut y = vec1(2, |_| 1.0);
+
+        if let Err(error) = Array::symv(Uplo::Upper, 1.0, &x, &x, 0.0, &mut y) {
+            assert_eq!(error, ListError::MismatchedTypes)
+        };
+    }
+}
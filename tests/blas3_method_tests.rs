@@ -0,0 +1,200 @@
+mod common;
+
+#[cfg(test)]
+pub mod tests {
+    use crate::common::{close, mat};
+    use simple_blas::array::{Array, Diag, ListError, Side, Transpose, Uplo};
+
+    const SIDE: [Side; 2] = [Side::Left, Side::Right];
+    const TRANS: [Transpose; 2] = [Transpose::NoTrans, Transpose::Trans];
+    const UPLO: [Uplo; 2] = [Uplo::Upper, Uplo::Lower];
+    const DIAG: [Diag; 2] = [Diag::NonUnit, Diag::Unit];
+
+    // plain product of two full matrices
+    fn product(a: &Array<f64>, b: &Array<f64>) -> Array<f64> {
+        let (m, k, n) = (a.shape()[0], a.shape()[1], b.shape()[1]);
+        mat(m, n, true, |r, c| (0..k).map(|p| a[(r, p)] * b[(p, c)]).sum())
+    }
+
+    // op(A) of the uplo triangle with diag as a full matrix
+    fn tri_full(a: &Array<f64>, uplo: Uplo, trans: Transpose, diag: Diag) -> Array<f64> {
+        let n: usize = a.shape()[0];
+        mat(n, n, true, |r, c| {
+            let (i, j) = if trans == Transpose::Trans {(c, r)} else {(r, c)};
+            if i == j {return if diag == Diag::Unit {1.0} else {a[(i, j)]};}
+            let inside: bool = if uplo == Uplo::Upper {i < j} else {i > j};
+            if inside {a[(i, j)]} else {0.0}
+        })
+    }
+
+    #[test]
+    fn trmm_trsm_all_options() -> Result<(), ListError> {
+        let n: usize = 4;
+        let m: usize = 3;
+        for by_row_a in [true, false] {
+            // the other triangle is garbage and must not be read
+            let a = mat(n, n, by_row_a, |r, c| if r == c {2.0 + r as f64} else {(r * n + c) as f64 * 0.1 - 0.7});
+            for by_row_b in [true, false] {
+                for side in SIDE {
+                    for uplo in UPLO {
+                        for trans in TRANS {
+                            for diag in DIAG {
+                                let (nr, nc) = if side == Side::Left {(n, m)} else {(m, n)};
+                                let b = mat(nr, nc, by_row_b, |r, c| r as f64 - c as f64 * 0.5 + 1.0);
+                                let t = tri_full(&a, uplo, trans, diag);
+
+                                let mut x = b.clone();
+                                Array::trmm(side, uplo, trans, diag, 2.0, &a, &mut x)?;
+                                let mut expect = if side == Side::Left {product(&t, &b)} else {product(&b, &t)};
+                                expect.mult(2.0)?;
+                                assert!(close(&x, &expect));
+
+                                // solving gives b back
+                                Array::trsm(side, uplo, trans, diag, 0.5, &a, &mut x)?;
+                                assert!(close(&x, &b));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn syrk_syr2k_all_options() -> Result<(), ListError> {
+        for by_row in [true, false] {
+            for trans in TRANS {
+                for uplo in UPLO {
+                    // op(A), op(B) are 3 x 2
+                    let (nr, nc) = if trans == Transpose::Trans {(2, 3)} else {(3, 2)};
+                    let a = mat(nr, nc, by_row, |r, c| (r * 2 + c) as f64 - 1.5);
+                    let b = mat(nr, nc, !by_row, |r, c| 1.0 + r as f64 * c as f64);
+                    let c0 = mat(3, 3, by_row, |r, c| (r + 2 * c) as f64);
+
+                    let opa = if trans == Transpose::Trans {a.transposed()?} else {a.clone()};
+                    let opb = if trans == Transpose::Trans {b.transposed()?} else {b.clone()};
+                    // only the uplo triangle is written
+                    let keep = |full: &Array<f64>| mat(3, 3, true, |r, c| {
+                        let inside: bool = if uplo == Uplo::Upper {r <= c} else {r >= c};
+                        if inside {full[(r, c)]} else {c0[(r, c)]}
+                    });
+
+                    let mut c = c0.clone();
+                    Array::syrk(uplo, trans, 2.0, &a, 0.5, &mut c)?;
+                    let full = mat(3, 3, true, |r, col| {
+                        2.0 * (0..2).map(|p| opa[(r, p)] * opa[(col, p)]).sum::<f64>() + 0.5 * c0[(r, col)]
+                    });
+                    assert!(close(&c, &keep(&full)));
+
+                    let mut c = c0.clone();
+                    Array::syr2k(uplo, trans, 1.0, &a, &b, 0.0, &mut c)?;
+                    let full = mat(3, 3, true, |r, col| {
+                        (0..2).map(|p| opa[(r, p)] * opb[(col, p)] + opb[(r, p)] * opa[(col, p)]).sum::<f64>()
+                    });
+                    assert!(close(&c, &keep(&full)));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn symm_all_options() -> Result<(), ListError> {
+        let n: usize = 3;
+        let sym = |r: usize, c: usize| (r.min(c) * 3 + r.max(c)) as f64;
+        let s = mat(n, n, true, sym);
+        for by_row in [true, false] {
+            for side in SIDE {
+                for uplo in UPLO {
+                    let a = mat(n, n, by_row, |r, c| {
+                        let inside: bool = if uplo == Uplo::Upper {r <= c} else {r >= c};
+                        if inside {sym(r, c)} else {100.0}
+                    });
+                    let (nr, nc) = if side == Side::Left {(n, 2)} else {(2, n)};
+                    let b = mat(nr, nc, !by_row, |r, c| r as f64 + c as f64 * 0.25);
+                    let c0 = mat(nr, nc, by_row, |_, _| 1.0);
+
+                    let mut c = c0.clone();
+                    Array::symm(side, uplo, 2.0, &a, &b, -1.0, &mut c)?;
+                    let mut expect = if side == Side::Left {product(&s, &b)} else {product(&b, &s)};
+                    expect.mult(2.0)?;
+                    expect.mminus(&c0)?;
+                    assert!(close(&c, &expect));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn minv_and_qr_after_port() -> Result<(), ListError> {
+        for by_row in [true, false] {
+            let a = mat(4, 4, by_row, |r, c| if r == c {5.0} else {(r * 4 + c) as f64 * 0.3 - 2.0});
+            let mut inv = a.clone();
+            inv.minv()?;
+            assert!(close(&product(&a, &inv), &mat(4, 4, true, |r, c| if r == c {1.0} else {0.0})));
+
+            // wide matrix, the columns past nr are q' a
+            let w = mat(2, 4, by_row, |r, c| (r + 1) as f64 * (c as f64 - 1.5) + if r == c {3.0} else {0.0});
+            let (q, r) = w.mqr()?;
+            assert!(close(&product(&q, &r), &w));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn trsm_not_square() {
+        let a = mat(2, 3, true, |r, c| (r + c) as f64);
+        let mut b = mat(2, 2, true, |_, _| 1.0);
+
+        if let Err(error) = Array::trsm(Side::Left, Uplo::Upper, Transpose::NoTrans, Diag::NonUnit, 1.0, &a, &mut b) {
+            assert_eq!(error, ListError::NotSquareMat)
+        };
+    }
+
+    #[test]
+    fn trmm_mismatched_dim() {
+        let sq = mat(3, 3, true, |r, c| if r == c {1.0} else {0.0});
+        let mut b = mat(2, 2, true, |_, _| 1.0);
+
+        if let Err(error) = Array::trmm(Side::Right, Uplo::Upper, Transpose::NoTrans, Diag::NonUnit, 1.0, &sq, &mut b) {
+            assert_eq!(error, ListError::ShapeMismatch { lhs: vec![3, 3], rhs: vec![2, 2] })
+        };
+    }
+
+    #[test]
+    fn trsm_singular() {
+        let singular = mat(2, 2, false, |r, c| if r == c && r == 0 {0.0} else {1.0});
+        let mut b = mat(2, 2, true, |_, _| 1.0);
+
+        if let Err(error) = Array::trsm(Side::Right, Uplo::Lower, Transpose::Trans, Diag::NonUnit, 1.0, &singular, &mut b) {
+            assert_eq!(error, ListError::SingularMat)
+        };
+    }
+
+    #[test]
+    fn syrk_mismatched_dim() {
+        let a = mat(2, 3, true, |r, c| (r + c) as f64);
+        let mut c = mat(3, 3, true, |_, _| 0.0);
+
+        if let Err(error) = Array::syrk(Uplo::Upper, Transpose::NoTrans, 1.0, &a, 0.0, &mut c) {
+            assert_eq!(error, ListError::ShapeMismatch { lhs: vec![2, 2], rhs: vec![3, 3] })
+        };
+    }
+
+    #[test]
+    fn symm_not_square() {
+        let a = mat(2, 3, true, |r, c| (r + c) as f64);
+        let b = mat(2, 2, true, |_, _| 1.0);
+        let mut c = b.clone();
+
+        if let Err(error) = Array::symm(Side::Left, Uplo::Upper, 1.0, &a, &b, 0.0, &mut c) {
+            assert_eq!(error, ListError::NotSquareMat)
+        };
+    }
+}
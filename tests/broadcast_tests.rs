@@ -0,0 +1,93 @@
+#[cfg(test)]
+pub mod tests {
+    use simple_blas::array::{Array, ListError};
+
+    #[test]
+    fn broadcast_row_vector() -> Result<(), ListError> {
+        for by_row in [true, false] {
+            let mut a: Array<i32> = Array::new_array_2d(
+                Box::new([1, 2, 3, 4, 5, 6]), (2, 3), true
+            )?;
+            if !by_row {
+                // same matrix stored by column
+                a = Array::new_array_2d(Box::new([1, 4, 2, 5, 3, 6]), (2, 3), false)?;
+            }
+
+            // Array1D is a row
+            a.madd(&Array::new_array_1d(Box::new([10, 20, 30])))?;
+            assert_eq!(a, Array::new_array_2d(Box::new([11, 22, 33, 14, 25, 36]), (2, 3), true)?);
+
+            // 1 x n Array2D in the other layout
+            a.mminus(&Array::new_array_2d(Box::new([10, 20, 30]), (1, 3), !by_row)?)?;
+            assert_eq!(a, Array::new_array_2d(Box::new([1, 2, 3, 4, 5, 6]), (2, 3), true)?);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn broadcast_column_vector() -> Result<(), ListError> {
+        let mut a: Array<f64> = Array::new_array_2d(
+            Box::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]), (2, 3), false
+        )?;
+        let col: Array<f64> = Array::new_array_2d(Box::new([2.0, 4.0]), (2, 1), true)?;
+
+        a.ele_mult(&col)?;
+        assert_eq!(a, Array::new_array_2d(Box::new([2.0, 8.0, 6.0, 16.0, 10.0, 24.0]), (2, 3), false)?);
+
+        a.ele_div(&col)?;
+        assert_eq!(a, Array::new_array_2d(Box::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]), (2, 3), false)?);
+
+        let zero: Array<f64> = Array::new_array_2d(Box::new([1.0, 0.0]), (2, 1), true)?;
+        if let Err(error) = a.ele_div(&zero) {
+            assert_eq!(error, ListError::DivisionByZero);
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn broadcast_scalar_and_outer() -> Result<(), ListError> {
+        // scalar with matrix becomes matrix
+        let mut s: Array<i32> = Array::new_scalar(10);
+        s.mminus(&Array::new_array_2d(Box::new([1, 2, 3, 4]), (2, 2), false)?)?;
+        assert_eq!(s, Array::new_array_2d(Box::new([9, 8, 7, 6]), (2, 2), false)?);
+
+        let mut s: Array<i32> = Array::new_scalar(2);
+        s.ele_mult(&Array::new_scalar(3))?;
+        assert_eq!(s, Array::new_scalar(6));
+
+        // vector with scalar and with length 1 vector
+        let mut v: Array<i32> = Array::new_array_1d(Box::new([1, 2, 3]));
+        v.madd(&Array::new_scalar(1))?;
+        v.ele_mult(&Array::new_array_1d(Box::new([2])))?;
+        assert_eq!(v, Array::new_array_1d(Box::new([4, 6, 8])));
+
+        // column + row gives the outer sum
+        let mut col: Array<i32> = Array::new_array_2d(Box::new([0, 10]), (2, 1), true)?;
+        col.madd(&Array::new_array_1d(Box::new([1, 2, 3])))?;
+        assert_eq!(col, Array::new_array_2d(Box::new([1, 2, 3, 11, 12, 13]), (2, 3), true)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn broadcast_shape_mismatch() -> Result<(), ListError> {
+        let mut a: Array<i32> = Array::new_array_2d(Box::new([1, 2, 3, 4, 5, 6]), (2, 3), true)?;
+
+        if let Err(error) = a.madd(&Array::new_array_1d(Box::new([1, 2]))) {
+            assert_eq!(error, ListError::ShapeMismatch { lhs: vec![2, 3], rhs: vec![2] });
+        };
+
+        let b: Array<i32> = Array::new_array_2d(Box::new([1, 2, 3, 4, 5, 6]), (3, 2), true)?;
+        if let Err(error) = a.ele_mult(&b) {
+            assert_eq!(error, ListError::ShapeMismatch { lhs: vec![2, 3], rhs: vec![3, 2] });
+        };
+
+        if let Err(error) = a.madd(&Array::new_null()) {
+            assert_eq!(error, ListError::MismatchedTypes);
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,287 @@
+#[cfg(test)]
+pub mod tests {
+    use simple_blas::array::{Array, Ldl, ListError};
+
+    fn spd(by_row: bool) -> Result<Array<f64>, ListError> {
+        Array::new_array_2d(
+            Box::new([
+                4.0, 12.0, -16.0,
+                12.0, 37.0, -43.0,
+                -16.0, -43.0, 98.0,
+            ]),
+            (3, 3),
+            by_row
+        )
+    }
+
+    #[test]
+    fn mcholesky_arr_2d() -> Result<(), ListError> {
+        for by_row in [true, false] {
+            let a = spd(by_row)?;
+            let l = a.mcholesky()?;
+
+            let real: Array<f64> = Array::new_array_2d(
+                Box::new([
+                    2.0, 0.0, 0.0,
+                    6.0, 1.0, 0.0,
+                    -8.0, 5.0, 3.0,
+                ]),
+                (3, 3),
+                true
+            )?;
+
+            let d: f64 = Array::compute_dist(&l, &real)?;
+            assert!(d < 1e-10);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mcholesky_symmetric_up_to_rounding() -> Result<(), ListError> {
+        let a: Array<f64> = Array::new_array_2d(
+            Box::new([
+                2.0, 0.1 + 0.2,
+                0.3, 1.0,
+            ]),
+            (2, 2),
+            true
+        )?;
+
+        let l = a.mcholesky()?;
+        assert_eq!(l[(0, 1)], 0.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mcholesky_not_finite() -> Result<(), ListError> {
+        // NaN or Inf on the diagonal, then off the diagonal
+        for v in [f64::NAN, f64::INFINITY] {
+            for vals in [[v, 0.0, 0.0, 1.0], [1.0, v, v, 1.0]] {
+                let a: Array<f64> = Array::new_array_2d(Box::new(vals), (2, 2), true)?;
+                assert_eq!(a.mcholesky(), Err(ListError::NotPositiveDefinite));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mcholesky_errors() -> Result<(), ListError> {
+        let a: Array<f64> = Array::new_array_2d(
+            Box::new([1.0, 2.0, 3.0, 4.0]), (2, 2), true
+        )?;
+        if let Err(error) = a.mcholesky() {
+            assert_eq!(error, ListError::NotSymmetricMat);
+        };
+
+        // eigen values 3, -1
+        let a: Array<f64> = Array::new_array_2d(
+            Box::new([1.0, 2.0, 2.0, 1.0]), (2, 2), true
+        )?;
+        if let Err(error) = a.mcholesky() {
+            assert_eq!(error, ListError::NotPositiveDefinite);
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn cholesky_solve_arr_1d_2d() -> Result<(), ListError> {
+        for by_row in [true, false] {
+            let a = spd(by_row)?;
+            let l = a.mcholesky()?;
+
+            let b: Array<f64> = Array::new_array_1d(Box::new([1.0, -2.0, 3.0]));
+            let x1 = Array::cholesky_solve(&l, &b)?;
+            let x2 = a.msolve(&b)?;
+            for i in 0..3 {
+                assert!((x1[i] - x2[i]).abs() < 1e-10);
+            }
+
+            let b: Array<f64> = Array::new_array_2d(
+                Box::new([
+                    1.0, 0.0,
+                    -2.0, 1.0,
+                    3.0, 5.0,
+                ]),
+                (3, 2),
+                by_row
+            )?;
+            let x1 = Array::cholesky_solve(&l, &b)?;
+            let x2 = a.msolve(&b)?;
+            let d: f64 = Array::compute_dist(&x1, &x2)?;
+            assert!(d < 1e-10);
+        }
+
+        Ok(())
+    }
+
+    // L D L'
+    fn ldl_product(ldl: &Ldl<f64>, n: usize) -> Result<Array<f64>, ListError> {
+        let mut res: Vec<f64> = vec![0.0; n * n];
+        for r in 0..n {
+            for c in 0..n {
+                for i in 0..n {
+                    for j in 0..n {
+                        res[r * n + c] += ldl.l[(r, i)] * ldl.d[(i, j)] * ldl.l[(c, j)];
+                    }
+                }
+            }
+        }
+        Array::new_array_2d(res.into_boxed_slice(), (n as isize, n as isize), true)
+    }
+
+    // P A P'
+    fn sym_permute(a: &Array<f64>, p: &[(usize, usize)], n: usize) -> Result<Array<f64>, ListError> {
+        let mut order: Vec<usize> = (0..n).collect();
+        for &(i, j) in p.iter() {order.swap(i, j);}
+
+        let mut res: Vec<f64> = vec![0.0; n * n];
+        for r in 0..n {
+            for c in 0..n {
+                res[r * n + c] = a[(order[r], order[c])];
+            }
+        }
+        Array::new_array_2d(res.into_boxed_slice(), (n as isize, n as isize), true)
+    }
+
+    #[test]
+    fn mldl_indefinite() -> Result<(), ListError> {
+        let a: Array<f64> = Array::new_array_2d(
+            Box::new([
+                1.0, 2.0, 3.0,
+                2.0, 1.0, 4.0,
+                3.0, 4.0, -2.0,
+            ]),
+            (3, 3),
+            false
+        )?;
+
+        let ldl = a.mldl()?;
+        println!("{:?}", ldl);
+
+        // row 2 is swapped to 1 for a 2 x 2 block
+        assert_eq!(ldl.p, vec![(1, 2)]);
+        assert!(ldl.d[(1, 0)] != 0.0);
+
+        let res = ldl_product(&ldl, 3)?;
+        let dist: f64 = Array::compute_dist(&sym_permute(&a, &ldl.p, 3)?, &res)?;
+        assert!(dist < 1e-10);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mldl_semi_definite() -> Result<(), ListError> {
+        // v v' + w w', rank 2
+        let a: Array<f64> = Array::new_array_2d(
+            Box::new([
+                1.0, 1.0, 0.0,
+                1.0, 1.0, 0.0,
+                0.0, 0.0, 4.0,
+            ]),
+            (3, 3),
+            true
+        )?;
+
+        if let Err(error) = a.mcholesky() {
+            assert_eq!(error, ListError::NotPositiveDefinite);
+        };
+
+        let ldl = a.mldl()?;
+        assert!(ldl.p.is_empty());
+        assert_eq!((ldl.d[(0, 0)], ldl.d[(1, 1)], ldl.d[(2, 2)]), (1.0, 0.0, 4.0));
+
+        let res = ldl_product(&ldl, 3)?;
+        let dist: f64 = Array::compute_dist(&a, &res)?;
+        assert!(dist < 1e-10);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mldl_zero_diagonal() -> Result<(), ListError> {
+        // no leading principal minor is usable without pivoting
+        let a: Array<f64> = Array::new_array_2d(
+            Box::new([0.0, 1.0, 1.0, 0.0]), (2, 2), true
+        )?;
+
+        let ldl = a.mldl()?;
+        assert_eq!(ldl.d, a);
+        assert!(ldl.p.is_empty());
+
+        let res = ldl_product(&ldl, 2)?;
+        assert!(Array::compute_dist(&a, &res)? < 1e-10);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mldl_pivoted() -> Result<(), ListError> {
+        for by_row in [true, false] {
+            let a: Array<f64> = Array::new_array_2d(
+                Box::new([
+                    0.0, 1.0, 2.0, 3.0,
+                    1.0, 0.0, 4.0, 5.0,
+                    2.0, 4.0, 0.0, 6.0,
+                    3.0, 5.0, 6.0, 0.0,
+                ]),
+                (4, 4),
+                by_row
+            )?;
+
+            let ldl = a.mldl()?;
+            println!("{:?}", ldl);
+            assert!(!ldl.p.is_empty());
+
+            // L is unit lower triangular
+            for r in 0..4 {
+                assert_eq!(ldl.l[(r, r)], 1.0);
+                for c in (r + 1)..4 {assert_eq!(ldl.l[(r, c)], 0.0);}
+            }
+
+            let res = ldl_product(&ldl, 4)?;
+            let dist: f64 = Array::compute_dist(&sym_permute(&a, &ldl.p, 4)?, &res)?;
+            assert!(dist < 1e-10);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mldl_symmetric_up_to_rounding() -> Result<(), ListError> {
+        let a: Array<f64> = Array::new_array_2d(
+            Box::new([
+                2.0, 0.1 + 0.2,
+                0.3, 1.0,
+            ]),
+            (2, 2),
+            true
+        )?;
+
+        let ldl = a.mldl()?;
+        assert_eq!(ldl.d[(0, 0)], 2.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mldl_not_symmetric() -> Result<(), ListError> {
+        let a: Array<f64> = Array::new_array_2d(
+            Box::new([
+                2.0, 0.31,
+                0.3, 1.0,
+            ]),
+            (2, 2),
+            true
+        )?;
+
+        if let Err(error) = a.mldl() {
+            assert_eq!(error, ListError::NotSymmetricMat);
+        };
+
+        Ok(())
+    }
+}
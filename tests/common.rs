@@ -1 +1,55 @@
-pub fn setup() {}
\ No newline at end of file
+#![allow(dead_code)]
+
+use std::mem::discriminant;
+
+use simple_blas::array::Array;
+
+pub fn setup() {}
+
+// a[r, c] = f(r, c) in the given layout
+pub fn mat(nr: usize, nc: usize, by_row: bool, f: impl Fn(usize, usize) -> f64) -> Array<f64> {
+    let mut v: Vec<f64> = vec![0.0; nr * nc];
+    for r in 0..nr {
+        for c in 0..nc {
+            v[if by_row {r * nc + c} else {c * nr + r}] = f(r, c);
+        }
+    }
+    Array::new_array_2d(v.into_boxed_slice(), (nr as isize, nc as isize), by_row).unwrap()
+}
+
+// x[i] = f(i)
+pub fn vec1(n: usize, f: impl Fn(usize) -> f64) -> Array<f64> {
+    Array::new_array_1d((0..n).map(f).collect::<Vec<f64>>().into_boxed_slice())
+}
+
+// every element within 1e-10,
+// panics when the variants or shapes differ
+pub fn close(x: &Array<f64>, y: &Array<f64>) -> bool {
+    if discriminant(x) != discriminant(y) {
+        panic!("close of different variants, shapes {:?} and {:?}", x.shape(), y.shape());
+    }
+    if x.shape() != y.shape() {
+        panic!("close of different shapes {:?} and {:?}", x.shape(), y.shape());
+    }
+    match (x, y) {
+        (Array::Scalar(a), Array::Scalar(b)) => (a - b).abs() < 1e-10,
+        (Array::Array1D { arr: a }, Array::Array1D { arr: b })
+        => a.iter().zip(b.iter()).all(|(u, v)| (u - v).abs() < 1e-10),
+        (Array::Array2D { .. }, Array::Array2D { .. })
+        => Array::compute_dist(x, y).unwrap() < 1e-10,
+        (Array::ArrayND { shape, .. }, Array::ArrayND { .. }) => {
+            // every index in C order
+            let mut idx: Vec<usize> = vec![0; shape.len()];
+            for _ in 0..shape.iter().product::<usize>() {
+                if (x.get_nd(&idx).unwrap() - y.get_nd(&idx).unwrap()).abs() >= 1e-10 {return false;}
+                for k in (0..shape.len()).rev() {
+                    idx[k] += 1;
+                    if idx[k] < shape[k] {break;}
+                    idx[k] = 0;
+                }
+            }
+            true
+        },
+        _ => panic!("close of {:?} arrays is not supported", x.shape()),
+    }
+}
@@ -0,0 +1,537 @@
+#[cfg(test)]
+pub mod tests {
+    use simple_blas::array::{Array, ListError};
+
+    #[test]
+    fn meig_not_arr_2d() {
+        let arr = Array::new_array_1d(Box::new([1.0, 2.0, 3.0]));
+        if let Err(error) = arr.meig() {
+            assert_eq!(error, ListError::MismatchedTypes);
+        };
+    }
+
+    #[test]
+    fn meig_not_square() -> Result<(), ListError> {
+        let arr = Array::new_array_2d(
+            Box::new([
+                1.0, 2.0, 3.0,
+                4.0, 5.0, 6.0,
+            ]),
+            (2, 3),
+            true
+        )?;
+
+        if let Err(error) = arr.meig() {
+            assert_eq!(error, ListError::EigenMismatchedDim);
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn meig_arr_2d_1() -> Result<(), ListError> {
+        let arr = Array::new_array_2d(
+            Box::new([
+                1.23, 0.05,
+                0.05, 5.09,
+            ]),
+            (2, 2),
+            true
+        )?;
+
+        let eig = arr.meig()?;
+        println!("{:?}", eig);
+
+        // A v = lambda v
+        for c in 0..2 {
+            let lambda: f64 = eig.values[c];
+            for r in 0..2 {
+                let mut av: f64 = 0.0;
+                for i in 0..2 {
+                    av += arr[(r, i)] * eig.vectors[(i, c)];
+                }
+                assert!((av - lambda * eig.vectors[(r, c)]).abs() < 1e-10);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn meig_arr_2d_2() -> Result<(), ListError> {
+        let arr = Array::new_array_2d(
+            Box::new([
+                1.01, 5.07, 7.21,
+                0.0,  6.07, 3.12,
+                0.0,  0.0,  5.20,
+            ]),
+            (3, 3),
+            false
+        )?;
+
+        let eig = arr.meig()?;
+
+        let real: Array<f64> = Array::new_array_1d(Box::new([6.07, 5.20, 1.01]));
+        for i in 0..3 {
+            assert!((eig.values[i] - real[i]).abs() < 1e-10);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn meig_rotation() -> Result<(), ListError> {
+        // rotate 90 degree, eigen values are +i, -i
+        let arr = Array::new_array_2d(
+            Box::new([
+                0.0, -1.0,
+                1.0,  0.0,
+            ]),
+            (2, 2),
+            true
+        )?;
+
+        let eig = arr.meig()?;
+        println!("{:?}", eig);
+
+        let real_re: [f64; 2] = [0.0, 0.0];
+        let real_im: [f64; 2] = [1.0, -1.0];
+        for i in 0..2 {
+            assert!((eig.values[i] - real_re[i]).abs() < 1e-10);
+            assert!((eig.values_im[i] - real_im[i]).abs() < 1e-10);
+        }
+
+        // A (x + iy) = i (x + iy)
+        // A x = -y, A y = x
+        for r in 0..2 {
+            let mut ax: f64 = 0.0;
+            let mut ay: f64 = 0.0;
+            for i in 0..2 {
+                ax += arr[(r, i)] * eig.vectors[(i, 0)];
+                ay += arr[(r, i)] * eig.vectors[(i, 1)];
+            }
+            assert!((ax + eig.vectors[(r, 1)]).abs() < 1e-10);
+            assert!((ay - eig.vectors[(r, 0)]).abs() < 1e-10);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn meig_companion() -> Result<(), ListError> {
+        // companion matrix of x^3 - 1
+        // roots: 1, -0.5 + sqrt(3)/2 i, -0.5 - sqrt(3)/2 i
+        let arr = Array::new_array_2d(
+            Box::new([
+                0.0, 0.0, 1.0,
+                1.0, 0.0, 0.0,
+                0.0, 1.0, 0.0,
+            ]),
+            (3, 3),
+            true
+        )?;
+
+        let eig = arr.meig()?;
+        println!("{:?}", eig);
+
+        let h: f64 = 3.0_f64.sqrt() / 2.0;
+        let real_re: [f64; 3] = [1.0, -0.5, -0.5];
+        let real_im: [f64; 3] = [0.0, h, -h];
+        for i in 0..3 {
+            assert!((eig.values[i] - real_re[i]).abs() < 1e-10);
+            assert!((eig.values_im[i] - real_im[i]).abs() < 1e-10);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn meig_mixed_spectrum() -> Result<(), ListError> {
+        // block diagonal
+        // [2 -3; 3 2] has eigen values 2 + 3i, 2 - 3i
+        // [5 1; 0 -1] has eigen values 5, -1
+        let arr = Array::new_array_2d(
+            Box::new([
+                2.0, -3.0, 0.0,  0.0,
+                3.0,  2.0, 0.0,  0.0,
+                0.0,  0.0, 5.0,  1.0,
+                0.0,  0.0, 0.0, -1.0,
+            ]),
+            (4, 4),
+            false
+        )?;
+
+        let eig = arr.meig()?;
+        println!("{:?}", eig);
+
+        let real_re: [f64; 4] = [5.0, 2.0, 2.0, -1.0];
+        let real_im: [f64; 4] = [0.0, -3.0, 3.0, 0.0];
+        let mut found: [bool; 4] = [false; 4];
+        for i in 0..4 {
+            for j in 0..4 {
+                if (eig.values[i] - real_re[j]).abs() < 1e-10 
+                && (eig.values_im[i] - real_im[j]).abs() < 1e-10 {
+                    found[j] = true;
+                }
+            }
+        }
+        assert_eq!(found, [true; 4]);
+
+        Ok(())
+    }
+
+    // max |A v - lambda v| over all real eigen pairs
+    fn real_eigen_residual(arr: &Array<f64>, values: &Array<f64>, vectors: &Array<f64>, n: usize) -> f64 {
+        let mut res: f64 = 0.0;
+        for c in 0..n {
+            let lambda: f64 = values[c];
+            for r in 0..n {
+                let mut av: f64 = 0.0;
+                for i in 0..n {
+                    av += arr[(r, i)] * vectors[(i, c)];
+                }
+                res = res.max((av - lambda * vectors[(r, c)]).abs());
+            }
+        }
+        res
+    }
+
+    #[test]
+    fn meig_vectors_symmetric() -> Result<(), ListError> {
+        let arr = Array::new_array_2d(
+            Box::new([
+                1.5,  2.19, 0.27,
+                2.19, 1.01, 0.9,
+                0.27, 0.9,  1.01,
+            ]),
+            (3, 3),
+            true
+        )?;
+
+        let eig = arr.meig()?;
+        let res: f64 = real_eigen_residual(&arr, &eig.values, &eig.vectors, 3);
+        println!("{:e}", res);
+        assert!(res < 1e-10);
+
+        Ok(())
+    }
+
+    #[test]
+    fn meig_vectors_non_symmetric() -> Result<(), ListError> {
+        for by_row in [true, false] {
+            let arr = Array::new_array_2d(
+                Box::new([
+                    1.01,  2.02,  3.79,  0.05,
+                    5.07,  6.07,  11.95, 1.23,
+                    7.21,  3.12,  5.20,  1.17,
+                    7.719, 5.508, 9.919, 11.21,
+                ]),
+                (4, 4),
+                by_row
+            )?;
+
+            let eig = arr.meig()?;
+            for i in 0..4 {
+                assert_eq!(eig.values_im[i], 0.0);
+            }
+
+            let res: f64 = real_eigen_residual(&arr, &eig.values, &eig.vectors, 4);
+            println!("{:e}", res);
+            assert!(res < 1e-10);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn meig_vectors_complex() -> Result<(), ListError> {
+        // companion matrix of x^3 - 2x^2 + 3x - 4
+        let arr = Array::new_array_2d(
+            Box::new([
+                2.0, -3.0, 4.0,
+                1.0,  0.0, 0.0,
+                0.0,  1.0, 0.0,
+            ]),
+            (3, 3),
+            true
+        )?;
+
+        let eig = arr.meig()?;
+        println!("{:?}", eig);
+
+        // column 0 is real eigen vector
+        // column 1, 2 are x, y of (re, +im)
+        let (re, im): (f64, f64) = (eig.values[1], eig.values_im[1]);
+        assert!(eig.values_im[0] == 0.0 && im > 0.0);
+
+        // A (x + iy) = (re + i im)(x + iy)
+        // A x = re x - im y
+        // A y = im x + re y
+        for r in 0..3 {
+            let mut ax: f64 = 0.0;
+            let mut ay: f64 = 0.0;
+            let mut av: f64 = 0.0;
+            for i in 0..3 {
+                av += arr[(r, i)] * eig.vectors[(i, 0)];
+                ax += arr[(r, i)] * eig.vectors[(i, 1)];
+                ay += arr[(r, i)] * eig.vectors[(i, 2)];
+            }
+            let (x, y): (f64, f64) = (eig.vectors[(r, 1)], eig.vectors[(r, 2)]);
+            assert!((av - eig.values[0] * eig.vectors[(r, 0)]).abs() < 1e-10);
+            assert!((ax - (re * x - im * y)).abs() < 1e-10);
+            assert!((ay - (im * x + re * y)).abs() < 1e-10);
+        }
+
+        Ok(())
+    }
+
+    // A v = lambda v for every column,
+    // a conjugate pair (re, +im), (re, -im) at column c and c+1
+    fn eigen_residual_pairs(arr: &Array<f64>, eig: &simple_blas::array::Eigen<f64>, n: usize) {
+        let mut c: usize = 0;
+        while c < n {
+            let (re, im): (f64, f64) = (eig.values[c], eig.values_im[c]);
+            for r in 0..n {
+                let mut ax: f64 = 0.0;
+                let mut ay: f64 = 0.0;
+                for i in 0..n {
+                    ax += arr[(r, i)] * eig.vectors[(i, c)];
+                    if im != 0.0 {ay += arr[(r, i)] * eig.vectors[(i, c + 1)];}
+                }
+                let x: f64 = eig.vectors[(r, c)];
+                let y: f64 = if im != 0.0 {eig.vectors[(r, c + 1)]} else {0.0};
+                assert!((ax - (re * x - im * y)).abs() < 1e-10);
+                assert!((ay - (im * x + re * y)).abs() < 1e-10);
+            }
+
+            if im == 0.0 {
+                c += 1;
+            } else {
+                assert!(im > 0.0);
+                assert_eq!(eig.values[c + 1], re);
+                assert_eq!(eig.values_im[c + 1], -im);
+                c += 2;
+            }
+        }
+    }
+
+    #[test]
+    fn meig_conjugate_pairs_same_real_part() -> Result<(), ListError> {
+        // [[0, -1], [1, 0]] + [[0, -2], [2, 0]], spectrum +-i, +-2i
+        let arr = Array::new_array_2d(
+            Box::new([
+                0.0, -1.0, 0.0,  0.0,
+                1.0,  0.0, 0.0,  0.0,
+                0.0,  0.0, 0.0, -2.0,
+                0.0,  0.0, 2.0,  0.0,
+            ]),
+            (4, 4),
+            true
+        )?;
+
+        let eig = arr.meig()?;
+        println!("{:?}", eig);
+
+        let real_re: [f64; 4] = [0.0; 4];
+        let real_im: [f64; 4] = [2.0, -2.0, 1.0, -1.0];
+        for i in 0..4 {
+            assert!((eig.values[i] - real_re[i]).abs() < 1e-10);
+            assert!((eig.values_im[i] - real_im[i]).abs() < 1e-10);
+        }
+        eigen_residual_pairs(&arr, &eig, 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn meig_conjugate_pair_last() -> Result<(), ListError> {
+        // spectrum 0, +-i
+        let arr = Array::new_array_2d(
+            Box::new([
+                0.0, 0.0,  0.0,
+                0.0, 0.0, -1.0,
+                0.0, 1.0,  0.0,
+            ]),
+            (3, 3),
+            false
+        )?;
+
+        let eig = arr.meig()?;
+        println!("{:?}", eig);
+
+        let real_re: [f64; 3] = [0.0; 3];
+        let real_im: [f64; 3] = [1.0, -1.0, 0.0];
+        for i in 0..3 {
+            assert!((eig.values[i] - real_re[i]).abs() < 1e-10);
+            assert!((eig.values_im[i] - real_im[i]).abs() < 1e-10);
+        }
+        eigen_residual_pairs(&arr, &eig, 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn meig_nan() -> Result<(), ListError> {
+        let arr = Array::new_array_2d(
+            Box::new([
+                1.0, f64::NAN,
+                0.0, 2.0,
+            ]),
+            (2, 2),
+            true
+        )?;
+
+        if let Err(error) = arr.meig() {
+            assert_eq!(error, ListError::EigenNotConverged);
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn msym_eig_not_finite() -> Result<(), ListError> {
+        for v in [f64::NAN, f64::INFINITY] {
+            let arr = Array::new_array_2d(
+                Box::new([
+                    1.0, v,
+                    v,   2.0,
+                ]),
+                (2, 2),
+                true
+            )?;
+
+            match arr.msym_eig() {
+                Err(error) => assert_eq!(error, ListError::EigenNotConverged),
+                Ok(_) => panic!("msym_eig of a non-finite matrix"),
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn msym_eig_not_symmetric() -> Result<(), ListError> {
+        let arr = Array::new_array_2d(
+            Box::new([
+                1.0, 2.0,
+                3.0, 4.0,
+            ]),
+            (2, 2),
+            true
+        )?;
+
+        if let Err(error) = arr.msym_eig() {
+            assert_eq!(error, ListError::NotSymmetricMat);
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn msym_eig_symmetric_up_to_rounding() -> Result<(), ListError> {
+        let arr: Array<f64> = Array::new_array_2d(
+            Box::new([
+                2.0, 0.1 + 0.2,
+                0.3, 1.0,
+            ]),
+            (2, 2),
+            true
+        )?;
+
+        let eig = arr.msym_eig()?;
+        assert_eq!(eig.values.shape(), vec![2]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn msym_eig_arr_2d_1() -> Result<(), ListError> {
+        let arr: Array<f64> = Array::new_array_2d(
+            Box::new([
+                1.5,  2.19, 0.27,
+                2.19, 1.01, 0.9,
+                0.27, 0.9,  1.01,
+            ]),
+            (3, 3),
+            false
+        )?;
+
+        let eig = arr.msym_eig()?;
+        println!("{:?}", eig);
+
+        // same spectrum as meig, in ascending order
+        let eig2 = arr.meig()?;
+        for i in 0..3 {
+            assert!((eig.values[i] - eig2.values[2 - i]).abs() < 1e-10);
+        }
+
+        let res: f64 = real_eigen_residual(&arr, &eig.values, &eig.vectors, 3);
+        assert!(res < 1e-10);
+
+        Ok(())
+    }
+
+    #[test]
+    fn msym_eig_arr_2d_2() -> Result<(), ListError> {
+        // covariance like matrix with repeated eigen value
+        let arr = Array::new_array_2d(
+            Box::new([
+                4.0, 1.0, 1.0, 1.0, 1.0,
+                1.0, 4.0, 1.0, 1.0, 1.0,
+                1.0, 1.0, 4.0, 1.0, 1.0,
+                1.0, 1.0, 1.0, 4.0, 1.0,
+                1.0, 1.0, 1.0, 1.0, 4.0,
+            ]),
+            (5, 5),
+            true
+        )?;
+
+        let eig = arr.msym_eig()?;
+        println!("{:?}", eig);
+
+        let real: [f64; 5] = [3.0, 3.0, 3.0, 3.0, 8.0];
+        for (i, val) in real.iter().enumerate() {
+            assert!((eig.values[i] - val).abs() < 1e-10);
+        }
+
+        let res: f64 = real_eigen_residual(&arr, &eig.values, &eig.vectors, 5);
+        assert!(res < 1e-10);
+
+        // V'V = I
+        for i in 0..5 {
+            for j in 0..5 {
+                let mut dot: f64 = 0.0;
+                for k in 0..5 {
+                    dot += eig.vectors[(k, i)] * eig.vectors[(k, j)];
+                }
+                let real: f64 = if i == j {1.0} else {0.0};
+                assert!((dot - real).abs() < 1e-10);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn msym_eig_diagonal() -> Result<(), ListError> {
+        let arr = Array::new_array_2d(
+            Box::new([
+                3.0, 0.0, 0.0,
+                0.0, -1.0, 0.0,
+                0.0, 0.0, 2.0,
+            ]),
+            (3, 3),
+            true
+        )?;
+
+        let eig = arr.msym_eig()?;
+        assert_eq!(eig.n_iter, 0);
+
+        let real: [f64; 3] = [-1.0, 2.0, 3.0];
+        for (i, &val) in real.iter().enumerate() {
+            assert_eq!(eig.values[i], val);
+        }
+
+        Ok(())
+    }
+}
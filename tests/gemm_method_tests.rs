@@ -0,0 +1,131 @@
+#[cfg(test)]
+pub mod tests {
+    use simple_blas::array::{Array, ListError, Transpose};
+
+    // deterministic values in [-1, 1)
+    fn values(len: usize, seed: u64) -> Vec<f64> {
+        let mut x: u64 = seed;
+        (0..len).map(|_| {
+            x = x.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            ((x >> 33) as f64) / ((1u64 << 31) as f64) - 1.0
+        }).collect()
+    }
+
+    // x[r, c] of nr x nc matrix stored by row
+    fn get(x: &[f64], r: usize, c: usize, nc: usize) -> f64 {
+        x[r * nc + c]
+    }
+
+    // stored by row, give an Array in the wanted layout
+    fn to_array(x: &[f64], nr: usize, nc: usize, by_row: bool) -> Array<f64> {
+        let mut v: Vec<f64> = vec![0.0; nr * nc];
+        for r in 0..nr {
+            for c in 0..nc {
+                if by_row {v[r * nc + c] = x[r * nc + c];} else {v[c * nr + r] = x[r * nc + c];}
+            }
+        }
+        Array::new_array_2d(v.into_boxed_slice(), (nr as isize, nc as isize), by_row).unwrap()
+    }
+
+    fn check_gemm(m: usize, n: usize, k: usize) -> Result<(), ListError> {
+        let (alpha, beta) = (1.5, -0.5);
+        let c0 = values(m * n, 3);
+
+        for trans_a in [Transpose::NoTrans, Transpose::Trans] {
+            for trans_b in [Transpose::NoTrans, Transpose::Trans] {
+                // stored shapes of A and B
+                let (ra, ca) = if trans_a == Transpose::NoTrans {(m, k)} else {(k, m)};
+                let (rb, cb) = if trans_b == Transpose::NoTrans {(k, n)} else {(n, k)};
+                let a = values(ra * ca, 1);
+                let b = values(rb * cb, 2);
+
+                // reference
+                let mut real: Vec<f64> = vec![0.0; m * n];
+                for r in 0..m {
+                    for c in 0..n {
+                        let mut sum: f64 = 0.0;
+                        for p in 0..k {
+                            let av = if trans_a == Transpose::NoTrans {get(&a, r, p, ca)} else {get(&a, p, r, ca)};
+                            let bv = if trans_b == Transpose::NoTrans {get(&b, p, c, cb)} else {get(&b, c, p, cb)};
+                            sum += av * bv;
+                        }
+                        real[r * n + c] = alpha * sum + beta * c0[r * n + c];
+                    }
+                }
+                let real = to_array(&real, m, n, true);
+
+                for by_row_a in [true, false] {
+                    for by_row_b in [true, false] {
+                        for by_row_c in [true, false] {
+                            let aa = to_array(&a, ra, ca, by_row_a);
+                            let bb = to_array(&b, rb, cb, by_row_b);
+                            let mut cc = to_array(&c0, m, n, by_row_c);
+
+                            Array::gemm(alpha, &aa, trans_a, &bb, trans_b, beta, &mut cc)?;
+                            let d: f64 = Array::compute_dist(&cc, &real)?;
+                            assert!(d < 1e-9, "{} {} {} {:?} {:?} {}", m, n, k, trans_a, trans_b, d);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn gemm_all_layouts_small() -> Result<(), ListError> {
+        for (m, n, k) in [(1, 1, 1), (2, 3, 4), (5, 7, 3), (9, 17, 11), (4, 8, 1)] {
+            check_gemm(m, n, k)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn gemm_all_layouts_across_blocks() -> Result<(), ListError> {
+        // k over one KC block, m over one MC block
+        check_gemm(70, 13, 300)?;
+        // n over one NC block
+        check_gemm(3, 1030, 2)?;
+        Ok(())
+    }
+
+    #[test]
+    fn gemm_beta_zero_ignores_c() -> Result<(), ListError> {
+        let a: Array<f32> = Array::new_array_2d(Box::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]), (2, 3), true)?;
+        let b: Array<f32> = Array::new_array_2d(Box::new([1.0, 0.0, 0.0, 1.0, 1.0, 1.0]), (3, 2), true)?;
+        let mut c: Array<f32> = Array::new_array_2d(Box::new([f32::NAN; 4]), (2, 2), true)?;
+
+        Array::gemm(1.0, &a, Transpose::NoTrans, &b, Transpose::NoTrans, 0.0, &mut c)?;
+        assert_eq!(c, Array::new_array_2d(Box::new([4.0, 5.0, 10.0, 11.0]), (2, 2), true)?);
+
+        // integer works too
+        let a: Array<i32> = Array::new_array_2d(Box::new([1, 2, 3, 4]), (2, 2), false)?;
+        let mut c: Array<i32> = Array::new_array_2d(Box::new([1, 1, 1, 1]), (2, 2), true)?;
+        Array::gemm(2, &a, Transpose::NoTrans, &a, Transpose::Trans, 1, &mut c)?;
+        // a = [[1, 3], [2, 4]], a a' = [[10, 14], [14, 20]]
+        assert_eq!(c, Array::new_array_2d(Box::new([21, 29, 29, 41]), (2, 2), true)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn gemm_errors() -> Result<(), ListError> {
+        let a: Array<f64> = Array::new_array_2d(Box::new([1.0; 6]), (2, 3), true)?;
+        let mut c: Array<f64> = Array::new_array_2d(Box::new([0.0; 4]), (2, 2), true)?;
+
+        if let Err(error) = Array::gemm(1.0, &a, Transpose::NoTrans, &a, Transpose::NoTrans, 0.0, &mut c) {
+            assert_eq!(error, ListError::ShapeMismatch { lhs: vec![2, 3], rhs: vec![2, 3] });
+        };
+        // A A' is 2 x 2
+        Array::gemm(1.0, &a, Transpose::NoTrans, &a, Transpose::Trans, 0.0, &mut c)?;
+        assert_eq!(c, Array::new_array_2d(Box::new([3.0; 4]), (2, 2), true)?);
+
+        let v: Array<f64> = Array::new_array_1d(Box::new([1.0, 2.0]));
+        if let Err(error) = Array::gemm(1.0, &v, Transpose::NoTrans, &a, Transpose::NoTrans, 0.0, &mut c) {
+            assert_eq!(error, ListError::MismatchedTypes);
+        };
+
+        Ok(())
+    }
+}
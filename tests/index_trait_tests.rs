@@ -39,6 +39,7 @@ pub mod tests {
  
     #[test]
     #[should_panic]
+    #[allow(clippy::no_effect)]
     fn arr_1d_index_bound_check() {
         let arr = Array::new_array_1d(Box::new([0, 1, 2, 3]));
         // unaccess index
@@ -58,6 +59,7 @@ pub mod tests {
 
     #[test]
     #[should_panic]
+    #[allow(clippy::no_effect)]
     fn null_index() {
         // other variant cannot use Index
         let null = Array::<i32>::new_null();
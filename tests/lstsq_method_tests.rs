@@ -0,0 +1,163 @@
+#[cfg(test)]
+pub mod tests {
+    use simple_blas::array::{Array, ListError};
+
+    // polynomial fit c0 + c1 t + c2 t^2 on t = 0..5
+    fn vandermonde(by_row: bool) -> Result<Array<f64>, ListError> {
+        let mut v: Vec<f64> = vec![0.0; 18];
+        for r in 0..6 {
+            let t: f64 = r as f64;
+            let row: [f64; 3] = [1.0, t, t * t];
+            for c in 0..3 {
+                if by_row {v[r * 3 + c] = row[c];} else {v[c * 6 + r] = row[c];}
+            }
+        }
+        Array::new_array_2d(v.into_boxed_slice(), (6, 3), by_row)
+    }
+
+    #[test]
+    fn mlstsq_exact_fit() -> Result<(), ListError> {
+        for by_row in [true, false] {
+            let a = vandermonde(by_row)?;
+            // y = 1 - 2 t + 0.5 t^2
+            let b: Array<f64> = Array::new_array_1d(
+                (0..6).map(|t| 1.0 - 2.0 * t as f64 + 0.5 * (t * t) as f64).collect()
+            );
+
+            let res = a.mlstsq(&b)?;
+            assert_eq!(res.rank, 3);
+            assert!((res.x[0] - 1.0).abs() < 1e-10);
+            assert!((res.x[1] + 2.0).abs() < 1e-10);
+            assert!((res.x[2] - 0.5).abs() < 1e-10);
+            assert!(res.residuals[0] < 1e-10);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mlstsq_normal_equations() -> Result<(), ListError> {
+        for by_row in [true, false] {
+            let a = vandermonde(by_row)?;
+            let y: [f64; 6] = [1.0, 0.3, -2.0, 4.1, 0.7, 2.2];
+            let b: Array<f64> = Array::new_array_2d(
+                Box::new([
+                    y[0], 1.0,
+                    y[1], 0.0,
+                    y[2], 2.0,
+                    y[3], -1.0,
+                    y[4], 0.5,
+                    y[5], 3.0,
+                ]),
+                (6, 2),
+                true
+            )?;
+
+            let res = a.mlstsq(&b)?;
+            assert_eq!(res.rank, 3);
+
+            // A'(A x - b) = 0 and residual matched
+            for k in 0..2 {
+                let mut r: Vec<f64> = vec![0.0; 6];
+                for i in 0..6 {
+                    r[i] = -b[(i, k)];
+                    for j in 0..3 {r[i] += a[(i, j)] * res.x[(j, k)];}
+                }
+                for j in 0..3 {
+                    let g: f64 = (0..6).map(|i| a[(i, j)] * r[i]).sum();
+                    assert!(g.abs() < 1e-9);
+                }
+                let norm: f64 = r.iter().map(|v| v * v).sum::<f64>().sqrt();
+                assert!((norm - res.residuals[k]).abs() < 1e-10);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mlstsq_rank_deficient() -> Result<(), ListError> {
+        // third column = first + second
+        let a: Array<f64> = Array::new_array_2d(
+            Box::new([
+                1.0, 0.0, 1.0,
+                0.0, 1.0, 1.0,
+                1.0, 1.0, 2.0,
+                2.0, 1.0, 3.0,
+            ]),
+            (4, 3),
+            true
+        )?;
+        let b: Array<f64> = Array::new_array_1d(Box::new([1.0, 2.0, 3.0, 4.0]));
+
+        let res = a.mlstsq(&b)?;
+        assert_eq!(res.rank, 2);
+
+        // minimum norm solution is orthogonal to null space (1, 1, -1)
+        assert!((res.x[0] + res.x[1] - res.x[2]).abs() < 1e-10);
+
+        // normal equations still hold
+        for j in 0..3 {
+            let mut g: f64 = 0.0;
+            for i in 0..4 {
+                let mut r: f64 = -b[i];
+                for k in 0..3 {r += a[(i, k)] * res.x[k];}
+                g += a[(i, j)] * r;
+            }
+            assert!(g.abs() < 1e-9);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mlstsq_nearly_rank_deficient() -> Result<(), ListError> {
+        // R diagonal of unpivoted QR is 1e-8, 1e-8,
+        // but the singular values are about 1 and 1e-16
+        for by_row in [true, false] {
+            let a: Array<f64> = Array::new_array_2d(
+                Box::new(if by_row {[1e-8, 1.0, 0.0, 1e-8, 0.0, 0.0]} else {[1e-8, 0.0, 0.0, 1.0, 1e-8, 0.0]}),
+                (3, 2),
+                by_row
+            )?;
+            let b: Array<f64> = Array::new_array_1d(Box::new([1.0, 1.0, 1.0]));
+
+            let res = a.mlstsq(&b)?;
+            assert_eq!(res.rank, 1);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mlstsq_under_determined() -> Result<(), ListError> {
+        for by_row in [true, false] {
+            // x + y + z = 3
+            let a: Array<f64> = Array::new_array_2d(Box::new([1.0, 1.0, 1.0]), (1, 3), by_row)?;
+            let b: Array<f64> = Array::new_array_1d(Box::new([3.0]));
+
+            let res = a.mlstsq(&b)?;
+            assert_eq!(res.rank, 1);
+            for i in 0..3 {
+                assert!((res.x[i] - 1.0).abs() < 1e-10);
+            }
+            assert!(res.residuals[0] < 1e-10);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mlstsq_errors() -> Result<(), ListError> {
+        let a = vandermonde(true)?;
+        let b: Array<f64> = Array::new_array_1d(Box::new([1.0, 2.0]));
+        assert_eq!(a.mlstsq(&b).err(), Some(ListError::ShapeMismatch { lhs: vec![6, 3], rhs: vec![2] }));
+
+        let a: Array<f64> = Array::new_array_1d(Box::new([1.0, 2.0]));
+        if let Err(error) = a.mlstsq(&b) {
+            assert_eq!(error, ListError::MismatchedTypes);
+        };
+
+        Ok(())
+    }
+}
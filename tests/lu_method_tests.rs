@@ -0,0 +1,116 @@
+#[cfg(test)]
+pub mod tests {
+    use simple_blas::array::{Array, ListError};
+
+    fn mat_a(by_row: bool) -> Result<Array<f64>, ListError> {
+        Array::new_array_2d(
+            Box::new([
+                1.11, 22.18, 5.78,
+                3.34, 4.83, 11.49,
+                -1.23, 14.07, 19.37
+            ]),
+            (3, 3),
+            by_row
+        )
+    }
+
+    #[test]
+    fn mlu_not_square() -> Result<(), ListError> {
+        let a: Array<f64> = Array::new_array_2d(
+            Box::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]), (2, 3), true
+        )?;
+
+        if let Err(error) = a.mlu() {
+            assert_eq!(error, ListError::NotSquareMat);
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn mlu_p_a_eq_l_u() -> Result<(), ListError> {
+        for by_row in [true, false] {
+            let a = mat_a(by_row)?;
+            let lu = a.mlu()?;
+
+            // P A
+            let mut pa = lu.p();
+            pa.mmult(&a)?;
+
+            // L U
+            let mut l = lu.l();
+            l.mmult(&lu.u())?;
+
+            let d: f64 = Array::compute_dist(&pa, &l)?;
+            assert!(d < 1e-10);
+            assert!(!lu.pivots().is_empty());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mlu_det_and_inverse() -> Result<(), ListError> {
+        for by_row in [true, false] {
+            let a = mat_a(by_row)?;
+            let lu = a.mlu()?;
+
+            let det: f64 = a.mdet()?;
+            assert!((lu.det() - det).abs() < 1e-8);
+
+            let inv = lu.inverse()?;
+            let mut a_inv = mat_a(by_row)?;
+            a_inv.minv()?;
+            let d: f64 = Array::compute_dist(&inv, &a_inv)?;
+            assert!(d < 1e-10);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mlu_solve_many() -> Result<(), ListError> {
+        let a = mat_a(true)?;
+        let lu = a.mlu()?;
+
+        for k in 0..3 {
+            let real: [f64; 3] = [k as f64, 1.0 - k as f64, 0.5];
+            let mut b: Vec<f64> = vec![0.0; 3];
+            for r in 0..3 {
+                for c in 0..3 {
+                    b[r] += a[(r, c)] * real[c];
+                }
+            }
+
+            let x = lu.solve(&Array::new_array_1d(b.into_boxed_slice()))?;
+            for i in 0..3 {
+                assert!((x[i] - real[i]).abs() < 1e-10);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mlu_singular() -> Result<(), ListError> {
+        let a: Array<f64> = Array::new_array_2d(
+            Box::new([
+                1.0, 2.0, 3.0,
+                2.0, 4.0, 6.0,
+                1.0, 0.0, 1.0,
+            ]),
+            (3, 3),
+            true
+        )?;
+
+        let lu = a.mlu()?;
+        assert!(lu.is_singular());
+        assert_eq!(lu.det(), 0.0);
+
+        if let Err(error) = lu.inverse() {
+            assert_eq!(error, ListError::SingularMat);
+        };
+
+        Ok(())
+    }
+}
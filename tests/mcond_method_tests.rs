@@ -0,0 +1,106 @@
+#[cfg(test)]
+pub mod tests {
+    use simple_blas::array::{Array, ListError, Norm};
+
+    fn hilbert(n: usize, by_row: bool) -> Result<Array<f64>, ListError> {
+        let mut v: Vec<f64> = vec![0.0; n * n];
+        for r in 0..n {
+            for c in 0..n {
+                v[r * n + c] = 1.0 / (r + c + 1) as f64;
+            }
+        }
+        Array::new_array_2d(v.into_boxed_slice(), (n as isize, n as isize), by_row)
+    }
+
+    #[test]
+    fn mcond_arr_2d() -> Result<(), ListError> {
+        for by_row in [true, false] {
+            let a: Array<f64> = Array::new_array_2d(
+                Box::new([1.0, 2.0, 3.0, 4.0]), (2, 2), by_row
+            )?;
+
+            // A^-1 = [[-2, 1], [1.5, -0.5]] or its transpose
+            assert!((a.mcond(Norm::L1)? - 21.0).abs() < 1e-10);
+            assert!((a.mcond(Norm::Inf)? - 21.0).abs() < 1e-10);
+            assert!((a.mcond(Norm::Frobenius)? - 15.0).abs() < 1e-10);
+
+            let svd = a.msvd()?;
+            assert!((a.mcond(Norm::L2)? - svd.s[0] / svd.s[1]).abs() < 1e-10);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mcond_empty() -> Result<(), ListError> {
+        let a: Array<f64> = Array::new_array_2d(Box::new([]), (0, 0), true)?;
+
+        for norm in [Norm::L1, Norm::L2, Norm::Inf, Norm::Frobenius, Norm::Spectral] {
+            if let Err(error) = a.mcond(norm) {
+                assert_eq!(error, ListError::EmptyArray);
+            } else {
+                panic!("mcond of a 0 x 0 matrix");
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mrcond_estimate() -> Result<(), ListError> {
+        let mats: Vec<(Vec<f64>, isize)> = vec![
+            (vec![1.11, 22.18, 5.78, 3.34, 4.83, 11.49, -1.23, 14.07, 19.37], 3),
+            (vec![4.0, -2.0, 1.0, 0.5, -2.0, 4.0, -2.0, 1.0, 1.0, -2.0, 4.0, 3.0, 0.5, 1.0, 3.0, 6.0], 4),
+        ];
+
+        for (v, n) in mats {
+            for by_row in [true, false] {
+                let a: Array<f64> = Array::new_array_2d(v.clone().into_boxed_slice(), (n, n), by_row)?;
+                let exact: f64 = 1.0 / a.mcond(Norm::L1)?;
+                let est: f64 = a.mrcond()?;
+
+                // ||A^-1|| is estimated from below
+                assert!(est >= exact * (1.0 - 1e-10));
+                assert!(est <= 3.0 * exact);
+            }
+        }
+
+        let a = hilbert(8, true)?;
+        assert!(a.mrcond()? < 1e-9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn minv_rcond_threshold() -> Result<(), ListError> {
+        let mut a = hilbert(8, false)?;
+        if let Err(error) = a.minv_rcond(1e-6) {
+            assert_eq!(error, ListError::IllConditioned);
+        };
+
+        let mut a = hilbert(3, true)?;
+        a.minv_rcond(1e-6)?;
+        let mut real = hilbert(3, true)?;
+        real.minv()?;
+        let d: f64 = Array::compute_dist(&a, &real)?;
+        assert!(d < 1e-8);
+
+        let mut a: Array<f64> = Array::new_array_2d(Box::new([1.0, 2.0, 2.0, 4.0]), (2, 2), true)?;
+        assert_eq!(a.mcond(Norm::L1)?, f64::INFINITY);
+        assert!(a.mcond(Norm::L2)? > 1e15);
+        assert_eq!(a.mrcond()?, 0.0);
+        if let Err(error) = a.minv_rcond(1e-6) {
+            assert_eq!(error, ListError::SingularMat);
+        };
+
+        let mut a: Array<f64> = Array::new_array_2d(Box::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]), (2, 3), true)?;
+        if let Err(error) = a.mcond(Norm::L1) {
+            assert_eq!(error, ListError::NotSquareMat);
+        };
+        if let Err(error) = a.minv_rcond(1e-6) {
+            assert_eq!(error, ListError::MatrixInvDimError);
+        };
+
+        Ok(())
+    }
+}
@@ -97,5 +97,29 @@ pub mod tests {
         Ok(())
     }
 
-    
-}
\ No newline at end of file
+    #[test]
+    fn mat_det_array_2d_sign() -> Result<(), ListError>{
+
+        // row and column swaps change the sign
+
+        let arr1: Array<f64> = Array::new_array_2d(
+            Box::new([0.0, 1.0, 
+                      1.0, 0.0]), (2,2), true
+        )?;
+
+        let det: f64 = arr1.mdet()?;
+        assert_eq!(det, -1.0);
+
+        let arr2: Array<f64> = Array::new_array_2d(
+            Box::new([1.11, 22.18, 5.78,
+                      3.34, 4.83, 11.49,
+                     -1.23, 14.07, 19.37]), (3,3), true
+        )?;
+
+        let det: f64 = arr2.mdet()?;
+        let true_val: f64 = -1518.052556;
+        assert!( (det - true_val).abs() < 1e-8 );
+
+        Ok(())
+    }
+}
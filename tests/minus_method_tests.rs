@@ -32,7 +32,7 @@ pub mod tests {
         let other_arr_1d: Array<i32> = 
             Array::new_array_1d(Box::new([1, 2, 3, 4, 5, 6]));
         if let Err(err) = arr_1d.mminus(&other_arr_1d) {
-            assert_eq!(err, ListError::DifferentLength1D)
+            assert_eq!(err, ListError::ShapeMismatch { lhs: vec![3], rhs: vec![6] })
         };
 
         Ok(())
@@ -87,4 +87,24 @@ pub mod tests {
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn mmult_speed_up_method_2d_array_2() -> Result<(), ListError> {
+        // 2x3 mult 3x4
+        let mut arr1 = 
+            Array::new_array_2d(
+                Box::new([1, 2, 3, 4, 5, 6]), (2, 3), true)?;
+
+        let arr2 = 
+            Array::new_array_2d(
+                Box::new([1, 0, 2, 0, 1, 1, 1, 1, 0, 0, 0, 1]), (3, 4), false)?;
+
+        arr1.mmult_speed(&arr2)?;
+
+        assert_eq!(arr1, Array::new_array_2d(
+            Box::new([7, 5, 3, 3, 16, 11, 9, 6]), (2, 4), true)?
+        );
+
+        Ok(())
+    }
+}
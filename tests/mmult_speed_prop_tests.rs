@@ -0,0 +1,52 @@
+#[cfg(test)]
+pub mod tests {
+    use proptest::prelude::*;
+    use simple_blas::array::{Array, ExecContext};
+
+    // (m, k, n) with m x k and k x n values, integer entries keep the products exact
+    fn mat_pair() -> impl Strategy<Value = ((usize, usize, usize), Vec<i64>, Vec<i64>)> {
+        (1..12_usize, 1..12_usize, 1..12_usize).prop_flat_map(|(m, k, n)| (
+            Just((m, k, n)),
+            prop::collection::vec(-50..50_i64, m * k),
+            prop::collection::vec(-50..50_i64, k * n),
+        ))
+    }
+
+    proptest! {
+        // mmult_speed equals mmult for every shape and layout,
+        // min_work 0 forces the parallel path
+        #[test]
+        fn mmult_speed_same_as_mmult(
+            ((m, k, n), va, vb) in mat_pair(),
+            by_row_a in any::<bool>(),
+            by_row_b in any::<bool>(),
+            parallel in any::<bool>(),
+        ) {
+            let ctx = if parallel {ExecContext::global().min_work(0)} else {ExecContext::serial()};
+
+            let a = Array::new_array_2d(va.into_boxed_slice(), (m as isize, k as isize), by_row_a).unwrap();
+            let b = Array::new_array_2d(vb.into_boxed_slice(), (k as isize, n as isize), by_row_b).unwrap();
+
+            let mut c1 = a.clone();
+            c1.mmult(&b).unwrap();
+            let mut c2 = a.clone();
+            c2.mmult_speed_with(&ctx, &b).unwrap();
+
+            prop_assert_eq!(c2.shape(), vec![m, n]);
+            prop_assert_eq!(c1, c2);
+        }
+
+        // k mismatch is an error, self is unchanged
+        #[test]
+        fn mmult_speed_mismatched_dim(
+            (m, k, n) in (1..8_usize, 1..8_usize, 1..8_usize),
+            by_row in any::<bool>(),
+        ) {
+            let mut a = Array::new_array_2d(vec![1_i64; m * k].into_boxed_slice(), (m as isize, k as isize), by_row).unwrap();
+            let b = Array::new_array_2d(vec![1_i64; (k + 1) * n].into_boxed_slice(), ((k + 1) as isize, n as isize), by_row).unwrap();
+
+            prop_assert!(a.mmult_speed(&b).is_err());
+            prop_assert_eq!(a.shape(), vec![m, k]);
+        }
+    }
+}
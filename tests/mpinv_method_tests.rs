@@ -0,0 +1,125 @@
+#[cfg(test)]
+pub mod tests {
+    use simple_blas::array::{Array, ListError};
+
+    // row major copy of a
+    fn copy(a: &Array<f64>) -> Result<Array<f64>, ListError> {
+        match a {
+            Array::Array2D { nr, nc, .. } => {
+                let mut v: Vec<f64> = vec![];
+                for r in 0..*nr {
+                    for c in 0..*nc {v.push(a[(r, c)]);}
+                }
+                Array::new_array_2d(v.into_boxed_slice(), (*nr as isize, *nc as isize), true)
+            },
+            _ => Err(ListError::MismatchedTypes),
+        }
+    }
+
+    fn mult(a: &Array<f64>, b: &Array<f64>) -> Result<Array<f64>, ListError> {
+        let mut res = copy(a)?;
+        res.mmult(b)?;
+        Ok(res)
+    }
+
+    // A A+ A = A, A+ A A+ = A+, (A A+)' = A A+, (A+ A)' = A+ A
+    fn check_penrose(a: &Array<f64>) -> Result<(), ListError> {
+        let a_pinv = a.mpinv(None)?;
+
+        let a_ap = mult(a, &a_pinv)?;
+        let ap_a = mult(&a_pinv, a)?;
+
+        let d: f64 = Array::compute_dist(&mult(&a_ap, a)?, a)?;
+        assert!(d < 1e-10);
+
+        let d: f64 = Array::compute_dist(&mult(&ap_a, &a_pinv)?, &a_pinv)?;
+        assert!(d < 1e-10);
+
+        let mut t = copy(&a_ap)?;
+        t.transpose()?;
+        let d: f64 = Array::compute_dist(&t, &a_ap)?;
+        assert!(d < 1e-10);
+
+        let mut t = copy(&ap_a)?;
+        t.transpose()?;
+        let d: f64 = Array::compute_dist(&t, &ap_a)?;
+        assert!(d < 1e-10);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mpinv_penrose_full_rank() -> Result<(), ListError> {
+        for by_row in [true, false] {
+            let a: Array<f64> = Array::new_array_2d(
+                Box::new([
+                    1.0, 2.0, 3.0,
+                    -4.0, 5.5, 6.0,
+                    7.0, 8.0, -9.5,
+                    0.5, 1.0, 2.0,
+                ]),
+                (4, 3),
+                by_row
+            )?;
+            check_penrose(&a)?;
+
+            // wide
+            let a: Array<f64> = Array::new_array_2d(
+                Box::new([1.0, 2.0, 3.0, 4.0, 0.0, -1.0, 2.5, 3.0]),
+                (2, 4),
+                by_row
+            )?;
+            check_penrose(&a)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mpinv_penrose_rank_deficient() -> Result<(), ListError> {
+        for by_row in [true, false] {
+            // rank 2
+            let a: Array<f64> = Array::new_array_2d(
+                Box::new([
+                    1.0, 2.0, 3.0, 4.0,
+                    2.0, 4.0, 6.0, 8.0,
+                    1.0, 0.0, 1.0, 0.0,
+                ]),
+                (3, 4),
+                by_row
+            )?;
+            check_penrose(&a)?;
+        }
+
+        let zero: Array<f64> = Array::new_array_2d(Box::new([0.0; 6]), (2, 3), true)?;
+        let a_pinv = zero.mpinv(None)?;
+        assert_eq!(a_pinv, Array::new_array_2d(Box::new([0.0; 6]), (3, 2), true)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mpinv_eq_minv() -> Result<(), ListError> {
+        let mut a: Array<f64> = Array::new_array_2d(
+            Box::new([4.0, 7.0, 2.0, 6.0]), (2, 2), false
+        )?;
+        let a_pinv = a.mpinv(None)?;
+        a.minv()?;
+        let d: f64 = Array::compute_dist(&a, &a_pinv)?;
+        assert!(d < 1e-10);
+
+        // rcond cuts the small singular value
+        let a: Array<f64> = Array::new_array_2d(
+            Box::new([1.0, 0.0, 0.0, 1e-8]), (2, 2), true
+        )?;
+        let a_pinv = a.mpinv(Some(1e-6))?;
+        assert_eq!(a_pinv[(1, 1)], 0.0);
+        assert!((a_pinv[(0, 0)] - 1.0).abs() < 1e-12);
+
+        if let Err(error) = Array::Scalar(1.0).mpinv(None) {
+            assert_eq!(error, ListError::MismatchedTypes);
+        };
+
+        Ok(())
+    }
+}
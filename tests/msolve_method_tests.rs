@@ -0,0 +1,148 @@
+#[cfg(test)]
+pub mod tests {
+    use simple_blas::array::{Array, ListError};
+
+    #[test]
+    fn msolve_mismatched() -> Result<(), ListError> {
+        let a: Array<f64> = Array::new_array_2d(
+            Box::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]), (2, 3), true
+        )?;
+        let b: Array<f64> = Array::new_array_1d(Box::new([1.0, 2.0]));
+
+        if let Err(error) = a.msolve(&b) {
+            assert_eq!(error, ListError::NotSquareMat);
+        };
+
+        let a: Array<f64> = Array::new_array_2d(
+            Box::new([1.0, 2.0, 3.0, 4.0]), (2, 2), true
+        )?;
+        let b: Array<f64> = Array::new_array_1d(Box::new([1.0, 2.0, 3.0]));
+
+        assert_eq!(a.msolve(&b), Err(ListError::ShapeMismatch { lhs: vec![2, 2], rhs: vec![3] }));
+
+        if let Err(error) = a.msolve(&Array::new_scalar(1.0)) {
+            assert_eq!(error, ListError::MismatchedTypes);
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn msolve_singular() -> Result<(), ListError> {
+        let a: Array<f64> = Array::new_array_2d(
+            Box::new([
+                1.0, 2.0, 3.0,
+                4.0, 5.0, 6.0,
+                0.0, 0.0, 0.0,
+            ]),
+            (3, 3),
+            true
+        )?;
+        let b: Array<f64> = Array::new_array_1d(Box::new([1.0, 2.0, 3.0]));
+
+        if let Err(error) = a.msolve(&b) {
+            assert_eq!(error, ListError::SingularMat);
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn msolve_arr_1d() -> Result<(), ListError> {
+        for by_row in [true, false] {
+            let a: Array<f64> = Array::new_array_2d(
+                Box::new([
+                    1.11, 22.18, 5.78,
+                    3.34, 4.83, 11.49,
+                    -1.23, 14.07, 19.37
+                ]),
+                (3, 3),
+                by_row
+            )?;
+            let real: [f64; 3] = [1.5, -2.0, 0.25];
+
+            // b = A x
+            let mut b: Vec<f64> = vec![0.0; 3];
+            for r in 0..3 {
+                for c in 0..3 {
+                    b[r] += a[(r, c)] * real[c];
+                }
+            }
+            let b: Array<f64> = Array::new_array_1d(b.into_boxed_slice());
+
+            let x = a.msolve(&b)?;
+            for i in 0..3 {
+                assert!((x[i] - real[i]).abs() < 1e-10);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn msolve_arr_2d() -> Result<(), ListError> {
+        let a: Array<f64> = Array::new_array_2d(
+            Box::new([
+                1.11, 22.18, 5.78,
+                3.34, 4.83, 11.49,
+                -1.23, 14.07, 19.37
+            ]),
+            (3, 3),
+            false
+        )?;
+
+        let real: Array<f64> = Array::new_array_2d(
+            Box::new([
+                1.0, 2.0,
+                -3.0, 0.5,
+                0.0, 7.0,
+            ]),
+            (3, 2),
+            true
+        )?;
+
+        // B = A X
+        let mut b: Array<f64> = Array::new_array_2d(
+            Box::new([
+                1.11, 22.18, 5.78,
+                3.34, 4.83, 11.49,
+                -1.23, 14.07, 19.37
+            ]),
+            (3, 3),
+            false
+        )?;
+        b.mmult(&real)?;
+
+        let x = a.msolve(&b)?;
+        let d: f64 = Array::compute_dist(&x, &real)?;
+        assert!(d < 1e-10);
+
+        // A A^-1 = I
+        let eye: Array<f64> = Array::new_array_2d(
+            Box::new([
+                1.0, 0.0, 0.0,
+                0.0, 1.0, 0.0,
+                0.0, 0.0, 1.0,
+            ]),
+            (3, 3),
+            false
+        )?;
+
+        let mut inv = a.msolve(&eye)?;
+        let mut a_inv: Array<f64> = Array::new_array_2d(
+            Box::new([
+                1.11, 22.18, 5.78,
+                3.34, 4.83, 11.49,
+                -1.23, 14.07, 19.37
+            ]),
+            (3, 3),
+            false
+        )?;
+        a_inv.minv()?;
+        inv.mmult(&eye)?;
+        let d: f64 = Array::compute_dist(&inv, &a_inv)?;
+        assert!(d < 1e-10);
+
+        Ok(())
+    }
+}
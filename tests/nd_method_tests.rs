@@ -0,0 +1,213 @@
+mod common;
+
+#[cfg(test)]
+pub mod tests {
+    use crate::common::close;
+    use simple_blas::array::{Array, ListError};
+
+    // a[i, j, k] = f(i, j, k) in C or F order
+    fn nd3(shape: [usize; 3], c_order: bool, f: impl Fn(usize, usize, usize) -> f64) -> Array<f64> {
+        let mut v: Vec<f64> = vec![0.0; shape.iter().product()];
+        for i in 0..shape[0] {
+            for j in 0..shape[1] {
+                for k in 0..shape[2] {
+                    let p: usize = if c_order {(i * shape[1] + j) * shape[2] + k} else {i + shape[0] * (j + shape[1] * k)};
+                    v[p] = f(i, j, k);
+                }
+            }
+        }
+        Array::new_array_nd(v.into_boxed_slice(), &shape, c_order).unwrap()
+    }
+
+    #[test]
+    fn new_and_index_both_orders() -> Result<(), ListError> {
+        let f = |i: usize, j: usize, k: usize| (i * 100 + j * 10 + k) as f64;
+        let c = nd3([2, 3, 4], true, f);
+        let fo = nd3([2, 3, 4], false, f);
+        assert_eq!(c, fo);
+        assert_eq!(c.shape(), vec![2, 3, 4]);
+        assert_eq!(c[[1, 2, 3]], 123.0);
+        assert_eq!(fo.get_nd(&[1, 0, 2])?, 102.0);
+
+        let mut m = fo.clone();
+        m[[0, 1, 1]] = -1.0;
+        assert_eq!(m.get_nd(&[0, 1, 1])?, -1.0);
+        assert!(m != fo);
+
+        Ok(())
+    }
+
+    #[test]
+    fn conversion_with_2d() -> Result<(), ListError> {
+        for by_row in [true, false] {
+            let v: Vec<f64> = (0..6).map(|x| x as f64).collect();
+            let a = Array::new_array_2d(v.into_boxed_slice(), (2, 3), by_row)?;
+            let nd = a.to_array_nd()?;
+            assert_eq!(nd.shape(), vec![2, 3]);
+            for r in 0..2 {
+                for c in 0..3 {
+                    assert_eq!(nd[[r, c]], a[(r, c)]);
+                }
+            }
+            assert_eq!(nd.to_array_2d()?, a);
+
+            // permuted axes are copied back in C order
+            let mut t = nd.clone();
+            t.permute_axes(&[1, 0])?;
+            assert_eq!(t.to_array_2d()?, a.transposed()?);
+        }
+
+        let x = Array::new_array_1d(Box::new([1.0, 2.0]));
+        assert_eq!(x.to_array_nd()?, Array::new_array_nd(Box::new([1.0, 2.0]), &[2], true)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn permute_and_reshape() -> Result<(), ListError> {
+        let f = |i: usize, j: usize, k: usize| (i * 100 + j * 10 + k) as f64;
+        for c_order in [true, false] {
+            let mut a = nd3([2, 3, 4], c_order, f);
+            a.permute_axes(&[2, 0, 1])?;
+            assert_eq!(a.shape(), vec![4, 2, 3]);
+            assert_eq!(a[[3, 1, 2]], f(1, 2, 3));
+
+            // reshape reads in C order of the permuted axes
+            a.reshape(&[4, 6])?;
+            assert_eq!(a[[1, 4]], f(1, 1, 1));
+            a.reshape(&[24])?;
+            assert_eq!(a[[23]], f(1, 2, 3));
+            assert_eq!(a[[6]], f(0, 0, 1));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn axis_reductions() -> Result<(), ListError> {
+        let f = |i: usize, j: usize, k: usize| (i * 100 + j * 10 + k) as f64;
+        for c_order in [true, false] {
+            let a = nd3([2, 3, 4], c_order, f);
+
+            let s = a.sum(Some(1))?;
+            assert_eq!(s.shape(), vec![2, 4]);
+            for i in 0..2 {
+                for k in 0..4 {
+                    assert_eq!(s[[i, k]], (0..3).map(|j| f(i, j, k)).sum::<f64>());
+                }
+            }
+
+            assert_eq!(a.max(Some(0))?, nd3([1, 3, 4], true, |_, j, k| f(1, j, k)).reduce_axis(0, |x, _| x)?);
+            assert_eq!(a.min(Some(2))?[[1, 2]], f(1, 2, 0));
+
+            // down to a Scalar
+            let v = Array::new_array_nd(Box::new([1.0, 5.0, 2.0]), &[3], c_order)?;
+            assert_eq!(v.max(Some(0))?, Array::new_scalar(5.0));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn batched_mmult() -> Result<(), ListError> {
+        let fa = |b: usize, r: usize, c: usize| (b * 7 + r * 3 + c) as f64 * 0.5 - 2.0;
+        let fb = |b: usize, r: usize, c: usize| (b + r * c) as f64 - 1.0;
+        for c_order in [true, false] {
+            let a = nd3([3, 2, 4], c_order, fa);
+            let b = nd3([3, 4, 5], !c_order, fb);
+
+            let c = a.mmult_batched(&b)?;
+            assert_eq!(c.shape(), vec![3, 2, 5]);
+            let expect = nd3([3, 2, 5], true, |k, r, col| (0..4).map(|p| fa(k, r, p) * fb(k, p, col)).sum());
+            assert!(close(&c, &expect));
+
+            // mmult on ArrayND is batched
+            let mut m = a.clone();
+            m.mmult(&b)?;
+            assert_eq!(m, expect);
+
+            // a matrix is used for every batch
+            let mut single = nd3([1, 2, 4], c_order, |_, r, c| fa(0, r, c));
+            single.reshape(&[2, 4])?;
+            let expect0 = nd3([3, 2, 5], true, |k, r, col| (0..4).map(|p| fa(0, r, p) * fb(k, p, col)).sum());
+            assert_eq!(single.mmult_batched(&b)?, expect0);
+
+            // a permuted operand reads through its strides
+            let mut at = nd3([3, 4, 2], c_order, |k, r, c| fa(k, c, r));
+            at.permute_axes(&[0, 2, 1])?;
+            assert_eq!(at.mmult_batched(&b)?, expect);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn batched_mmult_empty_inner_axis() -> Result<(), ListError> {
+        // C is 2 x 3 x 4 of zeros, the batches of A and B are empty
+        for c_order in [true, false] {
+            let a = Array::<f64>::new_array_nd(Box::new([]), &[2, 3, 0], c_order)?;
+            let b = Array::<f64>::new_array_nd(Box::new([]), &[2, 0, 4], c_order)?;
+            assert_eq!(a.mmult_batched(&b)?, Array::new_array_nd(Box::new([0.0; 24]), &[2, 3, 4], true)?);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn new_array_nd_mismatched_dim() {
+        assert_eq!(Array::new_array_nd(Box::new([1.0; 5]), &[2, 3], true), Err(ListError::MismatchedDim));
+    }
+
+    #[test]
+    fn get_nd_out_of_bounds() {
+        let c = nd3([2, 3, 4], true, |_, _, _| 0.0);
+        assert_eq!(c.get_nd(&[2, 0, 0]), Err(ListError::OutOfBounds));
+    }
+
+    #[test]
+    fn get_nd_mismatched_dim() {
+        let c = nd3([2, 3, 4], false, |_, _, _| 0.0);
+        assert_eq!(c.get_nd(&[0, 0]), Err(ListError::MismatchedDim));
+    }
+
+    #[test]
+    fn reshape_mismatched_dim() {
+        let mut b = nd3([2, 3, 4], true, |_, _, _| 0.0);
+        assert_eq!(b.reshape(&[5, 5]), Err(ListError::MismatchedDim));
+    }
+
+    #[test]
+    fn permute_axes_mismatched_dim() {
+        // repeated axis, then too few axes
+        let mut b = nd3([2, 3, 4], false, |_, _, _| 0.0);
+        assert_eq!(b.permute_axes(&[0, 0, 1]), Err(ListError::MismatchedDim));
+        assert_eq!(b.permute_axes(&[0, 1]), Err(ListError::MismatchedDim));
+    }
+
+    #[test]
+    fn axis_out_of_bounds() {
+        let a = nd3([2, 3, 4], true, |_, _, _| 0.0);
+        assert_eq!(a.sum(Some(3)), Err(ListError::OutOfBounds));
+    }
+
+    #[test]
+    fn axis_empty_array() {
+        // an empty axis is the same error either way
+        let e = Array::<f64>::new_array_nd(Box::new([]), &[0, 3], false).unwrap();
+        assert_eq!(e.reduce_axis(0, |x, _| x), Err(ListError::EmptyArray));
+        assert_eq!(e.max(Some(0)), Err(ListError::EmptyArray));
+    }
+
+    #[test]
+    fn mmult_batched_shape_mismatch() {
+        let a = nd3([3, 2, 4], true, |_, _, _| 1.0);
+        assert_eq!(a.mmult_batched(&a), Err(ListError::ShapeMismatch { lhs: vec![3, 2, 4], rhs: vec![3, 2, 4] }));
+    }
+
+    #[test]
+    fn mmult_batched_batch_mismatch() {
+        let a = nd3([3, 2, 4], true, |_, _, _| 1.0);
+        let b = nd3([2, 4, 5], false, |_, _, _| 1.0);
+        assert_eq!(a.mmult_batched(&b), Err(ListError::ShapeMismatch { lhs: vec![3, 2, 4], rhs: vec![2, 4, 5] }));
+    }
+}
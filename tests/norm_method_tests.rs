@@ -0,0 +1,79 @@
+#[cfg(test)]
+pub mod tests {
+    use simple_blas::array::{Array, ListError, Norm};
+
+    #[test]
+    fn norm_arr_1d() -> Result<(), ListError> {
+        let v: Array<f64> = Array::new_array_1d(Box::new([1.0, -2.0, 2.0, 0.0]));
+
+        assert_eq!(v.norm(Norm::L1)?, 5.0);
+        assert_eq!(v.norm(Norm::L2)?, 3.0);
+        assert_eq!(v.norm(Norm::Frobenius)?, 3.0);
+        assert_eq!(v.norm(Norm::Inf)?, 2.0);
+        assert!((v.norm(Norm::P(3.0))? - 17.0_f64.powf(1.0 / 3.0)).abs() < 1e-12);
+        assert!((v.norm(Norm::P(1.0))? - 5.0).abs() < 1e-12);
+
+        if let Err(error) = v.norm(Norm::P(0.5)) {
+            assert_eq!(error, ListError::InvalidNorm);
+        };
+
+        let s: Array<f64> = Array::new_scalar(-2.5);
+        assert_eq!(s.norm(Norm::L2)?, 2.5);
+
+        if let Err(error) = Array::<f64>::new_null().norm(Norm::L2) {
+            assert_eq!(error, ListError::MismatchedTypes);
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn norm_l2_no_overflow() -> Result<(), ListError> {
+        let v: Array<f64> = Array::new_array_1d(Box::new([3e200, 4e200]));
+        assert!((v.norm(Norm::L2)? / 5e200 - 1.0).abs() < 1e-12);
+
+        let v: Array<f32> = Array::new_array_1d(Box::new([3e-30, -4e-30]));
+        assert!((v.norm(Norm::L2)? / 5e-30 - 1.0).abs() < 1e-6);
+
+        let v: Array<f64> = Array::new_array_1d(Box::new([0.0, 0.0]));
+        assert_eq!(v.norm(Norm::L2)?, 0.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn norm_arr_2d() -> Result<(), ListError> {
+        for by_row in [true, false] {
+            let a: Array<f64> = Array::new_array_2d(
+                Box::new([
+                    1.0, 2.0, 3.0,
+                    -4.0, 5.0, -6.0,
+                ]),
+                (2, 3),
+                by_row
+            )?;
+
+            let (col, row) = if by_row {(9.0, 15.0)} else {(11.0, 12.0)};
+            assert_eq!(a.norm(Norm::L1)?, col);
+            assert_eq!(a.norm(Norm::Inf)?, row);
+            assert!((a.norm(Norm::Frobenius)? - 91.0_f64.sqrt()).abs() < 1e-12);
+
+            // singular values from svd
+            let svd = a.msvd()?;
+            assert!((a.norm(Norm::L2)? - svd.s[0]).abs() < 1e-10);
+            assert!((a.norm(Norm::Spectral)? - svd.s[0]).abs() < 1e-10);
+            assert!((a.norm(Norm::Nuclear)? - svd.s[0] - svd.s[1]).abs() < 1e-10);
+
+            // entrywise p = 2 is frobenius
+            assert!((a.norm(Norm::P(2.0))? - 91.0_f64.sqrt()).abs() < 1e-12);
+        }
+
+        let a: Array<f64> = Array::new_array_2d(
+            Box::new([3.0, 0.0, 0.0, -4.0]), (2, 2), true
+        )?;
+        assert!((a.norm(Norm::Spectral)? - 4.0).abs() < 1e-12);
+        assert!((a.norm(Norm::Nuclear)? - 7.0).abs() < 1e-12);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+#[cfg(test)]
+pub mod tests {
+    use simple_blas::array::{Array, ListError};
+
+    fn mat(v: [f64; 4], by_row: bool) -> Array<f64> {
+        Array::new_array_2d(Box::new(v), (2, 2), by_row).unwrap()
+    }
+
+    #[test]
+    fn ops_array_array() {
+        let a = mat([1.0, 2.0, 3.0, 4.0], true);
+        let b = mat([4.0, 2.0, 3.0, 1.0], false);
+
+        assert_eq!(&a + &b, mat([5.0, 5.0, 5.0, 5.0], true));
+        assert_eq!(&a - &b, mat([-3.0, -1.0, 1.0, 3.0], true));
+        assert_eq!(&a * &b, mat([4.0, 6.0, 6.0, 4.0], true));
+        assert_eq!(&a / &b, mat([0.25, 2.0 / 3.0, 1.5, 4.0], true));
+        assert_eq!(-&a, mat([-1.0, -2.0, -3.0, -4.0], true));
+
+        // owned operands
+        let c = a.clone() + b.clone();
+        assert_eq!(c, mat([5.0, 5.0, 5.0, 5.0], true));
+        let c = a.clone() - &b;
+        assert_eq!(c, mat([-3.0, -1.0, 1.0, 3.0], true));
+        assert_eq!(-a.clone(), mat([-1.0, -2.0, -3.0, -4.0], true));
+
+        // inputs untouched
+        assert_eq!(a, mat([1.0, 2.0, 3.0, 4.0], true));
+    }
+
+    #[test]
+    fn ops_scalar() {
+        let a = mat([1.0, 2.0, 3.0, 4.0], false);
+
+        assert_eq!(&a + 1.0, mat([2.0, 3.0, 4.0, 5.0], false));
+        assert_eq!(&a * 2.0, mat([2.0, 4.0, 6.0, 8.0], false));
+        assert_eq!(2.0 * &a, mat([2.0, 4.0, 6.0, 8.0], false));
+        assert_eq!(10.0 - &a, mat([9.0, 8.0, 7.0, 6.0], false));
+        assert_eq!(12.0 / a.clone(), mat([12.0, 6.0, 4.0, 3.0], false));
+        assert_eq!(a.clone() / 2.0, mat([0.5, 1.0, 1.5, 2.0], false));
+
+        let v: Array<f32> = Array::new_array_1d(Box::new([1.0, 2.0]));
+        assert_eq!(1.0_f32 + v, Array::new_array_1d(Box::new([2.0, 3.0])));
+    }
+
+    #[test]
+    fn ops_assign() {
+        let mut a = mat([1.0, 2.0, 3.0, 4.0], true);
+        let b = mat([1.0, 1.0, 1.0, 1.0], true);
+
+        a += &b;
+        a *= 2.0;
+        a -= b;
+        a /= mat([1.0, 3.0, 5.0, 7.0], false);
+        assert_eq!(a, mat([3.0, 5.0, 7.0, 9.0], true) / mat([1.0, 3.0, 5.0, 7.0], false));
+
+        let mut v: Array<i32> = Array::new_array_1d(Box::new([1, 2, 3]));
+        v += 1;
+        v -= Array::new_scalar(2);
+        assert_eq!(v, Array::new_array_1d(Box::new([0, 1, 2])));
+    }
+
+    #[test]
+    fn ops_mdot_formula() -> Result<(), ListError> {
+        let a = mat([1.0, 2.0, 3.0, 4.0], true);
+        let b = mat([0.0, 1.0, 1.0, 0.0], false);
+        let c = mat([1.0, 1.0, 1.0, 1.0], true);
+
+        // A B + C
+        let d = a.mdot(&b) + &c;
+        assert_eq!(d, mat([3.0, 2.0, 5.0, 4.0], true));
+
+        let e = a.checked_matmul(&b)?.checked_add(&c)?;
+        assert_eq!(d, e);
+
+        Ok(())
+    }
+
+    #[test]
+    fn ops_checked_same_as_operators() {
+        let a = mat([1.0, 2.0, 3.0, 4.0], true);
+        let b = mat([2.0, 1.0, 1.0, 2.0], false);
+
+        assert_eq!(a.checked_add(&b), Ok(&a + &b));
+        assert_eq!(a.checked_sub(&b), Ok(&a - &b));
+        assert_eq!(a.checked_mul(&b), Ok(&a * &b));
+        assert_eq!(a.checked_div(&b), Ok(&a / &b));
+        assert_eq!(a.checked_matmul(&b), Ok(a.mdot(&b)));
+    }
+
+    #[test]
+    fn ops_checked_shape_mismatch() {
+        let a = mat([1.0, 2.0, 3.0, 4.0], true);
+        let v: Array<f64> = Array::new_array_1d(Box::new([1.0, 2.0, 3.0]));
+        assert_eq!(a.checked_add(&v), Err(ListError::ShapeMismatch { lhs: vec![2, 2], rhs: vec![3] }));
+    }
+
+    #[test]
+    fn ops_checked_division_by_zero() {
+        let a = mat([1.0, 2.0, 3.0, 4.0], true);
+        assert_eq!(a.checked_div(&mat([1.0, 0.0, 1.0, 1.0], true)), Err(ListError::DivisionByZero));
+    }
+
+    #[test]
+    fn ops_checked_matmul_mismatched_types() {
+        let a = mat([1.0, 2.0, 3.0, 4.0], true);
+        let v: Array<f64> = Array::new_array_1d(Box::new([1.0, 2.0, 3.0]));
+        assert_eq!(a.checked_matmul(&v), Err(ListError::MismatchedTypes));
+    }
+
+    #[test]
+    #[should_panic(expected = "Array + scalar failed: MismatchedTypes")]
+    fn ops_null_panic() {
+        let _ = Array::<f64>::new_null() + 1.0;
+    }
+
+    #[test]
+    #[should_panic(expected = "Array + Array failed: ShapeMismatch")]
+    fn ops_add_panic() {
+        let a = mat([1.0, 2.0, 3.0, 4.0], true);
+        let v: Array<f64> = Array::new_array_1d(Box::new([1.0, 2.0, 3.0]));
+        let _ = a + v;
+    }
+
+    #[test]
+    #[should_panic(expected = "Array / scalar failed: DivisionByZero")]
+    fn ops_div_scalar_panic() {
+        let _ = mat([1.0, 2.0, 3.0, 4.0], true) / 0.0;
+    }
+
+    #[test]
+    #[should_panic(expected = "Array mdot Array failed: MatrixMultMismatchedDim")]
+    fn ops_mdot_panic() {
+        let a = mat([1.0, 2.0, 3.0, 4.0], true);
+        let b: Array<f64> = Array::new_array_2d(Box::new([1.0, 2.0, 3.0]), (3, 1), true).unwrap();
+        let _ = a.mdot(&b);
+    }
+}
@@ -174,5 +174,90 @@ pub mod test {
 
         Ok(())
     }
-}
 
+    // apply column swaps of qr_pivoted to A
+    fn permute_cols(a: &Array<f64>, p: &[(usize, usize)], nr: usize, nc: usize) -> Result<Array<f64>, ListError> {
+        let mut v: Vec<f64> = vec![0.0; nr * nc];
+        for r in 0..nr {
+            for c in 0..nc {v[r * nc + c] = a[(r, c)];}
+        }
+        for &(i, j) in p {
+            for r in 0..nr {v.swap(r * nc + i, r * nc + j);}
+        }
+        Array::new_array_2d(v.into_boxed_slice(), (nr as isize, nc as isize), true)
+    }
+
+    #[test]
+    fn qr_pivoted_a_p_eq_q_r() -> Result<(), ListError> {
+        for by_row in [true, false] {
+            for (v, dim) in [
+                (vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.5, 7.0, 8.0, 10.0, -1.0, 0.5, 2.0], (4, 3)),
+                (vec![1.0, 2.0, 3.0, 4.0, -4.0, 5.0, 6.5, 7.0], (2, 4)),
+            ] {
+                let arr: Array<f64> = Array::new_array_2d(v.into_boxed_slice(), dim, by_row)?;
+                let (nr, nc) = (dim.0 as usize, dim.1 as usize);
+                let k = nr.min(nc);
+
+                let qrp = arr.mqr_pivoted(None)?;
+                assert_eq!(qrp.rank, k);
+
+                // |r[i, i]| non increasing
+                for i in 1..k {
+                    assert!(qrp.r[(i, i)].abs() <= qrp.r[(i - 1, i - 1)].abs());
+                }
+
+                // Q'Q = I
+                for i in 0..k {
+                    for j in 0..k {
+                        let s: f64 = (0..nr).map(|r| qrp.q[(r, i)] * qrp.q[(r, j)]).sum();
+                        let e: f64 = if i == j {1.0} else {0.0};
+                        assert!((s - e).abs() < 1e-10);
+                    }
+                }
+
+                let ap = permute_cols(&arr, &qrp.p, nr, nc)?;
+                let mut q = qrp.q;
+                q.mmult(&qrp.r)?;
+                let d: f64 = Array::compute_dist(&ap, &q)?;
+                assert!(d < 1e-10);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn qr_pivoted_rank_deficient() -> Result<(), ListError> {
+        // third column = first - second, fourth = 2 * first
+        let arr: Array<f64> = Array::new_array_2d(
+            Box::new([
+                1.0, 2.0, -1.0, 2.0,
+                3.0, 1.0, 2.0, 6.0,
+                0.0, 4.0, -4.0, 0.0,
+                2.0, 2.0, 0.0, 4.0,
+                5.0, -1.0, 6.0, 10.0,
+            ]),
+            (5, 4),
+            true
+        )?;
+
+        let qrp = arr.mqr_pivoted(None)?;
+        assert_eq!(qrp.rank, 2);
+        assert!(qrp.r[(2, 2)].abs() < 1e-10);
+
+        // looser tolerance drops more
+        let qrp = arr.mqr_pivoted(Some(0.9))?;
+        assert_eq!(qrp.rank, 1);
+
+        let zero: Array<f64> = Array::new_array_2d(Box::new([0.0; 6]), (3, 2), true)?;
+        let qrp = zero.mqr_pivoted(None)?;
+        assert_eq!(qrp.rank, 0);
+        assert!(qrp.p.is_empty());
+
+        if let Err(error) = Array::Scalar(1.0).mqr_pivoted(None) {
+            assert_eq!(error, ListError::MismatchedTypes);
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,231 @@
+#[cfg(test)]
+pub mod tests {
+    use simple_blas::array::{Array, ExecContext, ListError, Parallelism};
+    use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    // deterministic values in [-1, 1)
+    fn rand_mat(nr: usize, nc: usize, by_row: bool, seed: u64) -> Array<f64> {
+        let mut state: u64 = seed;
+        let v: Vec<f64> = (0..nr * nc).map(|_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            ((state >> 11) as f64 / (1u64 << 53) as f64) * 2.0 - 1.0
+        }).collect();
+        Array::new_array_2d(v.into_boxed_slice(), (nr as isize, nc as isize), by_row).unwrap()
+    }
+
+    // every kind of context, min_work 0 forces the parallel path
+    fn contexts() -> Vec<ExecContext> {
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(3).build().unwrap();
+        vec![
+            ExecContext::serial(),
+            ExecContext::global().min_work(0),
+            ExecContext::with_pool(Arc::new(pool)).min_work(0),
+        ]
+    }
+
+    // f64 counting the *= run on a thread of the traced pool
+    // and on any other thread, only traced_pool uses it
+    #[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+    struct Traced(f64);
+
+    static IN_POOL: AtomicUsize = AtomicUsize::new(0);
+    static OUTSIDE: AtomicUsize = AtomicUsize::new(0);
+
+    impl Add for Traced { type Output = Traced; fn add(self, o: Traced) -> Traced {Traced(self.0 + o.0)} }
+    impl Sub for Traced { type Output = Traced; fn sub(self, o: Traced) -> Traced {Traced(self.0 - o.0)} }
+    impl Mul for Traced { type Output = Traced; fn mul(self, o: Traced) -> Traced {Traced(self.0 * o.0)} }
+    impl Div for Traced { type Output = Traced; fn div(self, o: Traced) -> Traced {Traced(self.0 / o.0)} }
+    impl AddAssign for Traced { fn add_assign(&mut self, o: Traced) {self.0 += o.0} }
+    impl SubAssign for Traced { fn sub_assign(&mut self, o: Traced) {self.0 -= o.0} }
+    impl MulAssign for Traced {
+        fn mul_assign(&mut self, o: Traced) {
+            let traced: bool = std::thread::current().name().is_some_and(|name| name.starts_with("traced-"));
+            if traced {&IN_POOL} else {&OUTSIDE}.fetch_add(1, Ordering::SeqCst);
+            self.0 *= o.0;
+        }
+    }
+
+    #[test]
+    fn exec_context_settings() {
+        let ctx = ExecContext::serial().min_work(10);
+        assert!(matches!(ctx.parallelism(), Parallelism::Serial));
+        assert_eq!(ctx.min_work_threshold(), 10);
+        assert!(matches!(ExecContext::default().parallelism(), Parallelism::Global));
+    }
+
+    #[test]
+    fn traced_pool() -> Result<(), ListError> {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(2)
+            .thread_name(|i| format!("traced-{i}"))
+            .build()
+            .unwrap();
+        let pooled = ExecContext::with_pool(Arc::new(pool));
+        let a = Array::new_array_2d(vec![Traced(2.0); 64].into_boxed_slice(), (8, 8), true)?;
+
+        // serial: every *= on the calling thread
+        let mut x = a.clone();
+        x.ele_mult_speed_with(&ExecContext::serial(), &a)?;
+        assert_eq!((IN_POOL.load(Ordering::SeqCst), OUTSIDE.load(Ordering::SeqCst)), (0, 64));
+
+        // below min_work: serial as well
+        let mut x = a.clone();
+        x.ele_mult_speed_with(&pooled, &a)?;
+        assert_eq!((IN_POOL.load(Ordering::SeqCst), OUTSIDE.load(Ordering::SeqCst)), (0, 128));
+
+        // pool: every *= on a thread of the pool
+        let mut x = a.clone();
+        x.ele_mult_speed_with(&pooled.clone().min_work(0), &a)?;
+        assert_eq!((IN_POOL.load(Ordering::SeqCst), OUTSIDE.load(Ordering::SeqCst)), (64, 128));
+        assert_eq!(x, Array::new_array_2d(vec![Traced(4.0); 64].into_boxed_slice(), (8, 8), true)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mmult_speed_same_as_mmult() -> Result<(), ListError> {
+        for ctx in contexts() {
+            for (by_row1, by_row2) in [(true, true), (true, false), (false, true), (false, false)] {
+                let a = rand_mat(24, 24, by_row1, 1);
+                let b = rand_mat(24, 24, by_row2, 2);
+
+                let mut c1 = a.clone();
+                c1.mmult(&b)?;
+                let mut c2 = a.clone();
+                c2.mmult_speed_with(&ctx, &b)?;
+                assert!(Array::compute_dist(&c1, &c2)? < 1e-10);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn element_wise_speed_same_as_serial() -> Result<(), ListError> {
+        for ctx in contexts() {
+            for (by_row1, by_row2) in [(true, true), (true, false), (false, true)] {
+                let a = rand_mat(17, 9, by_row1, 3);
+                let b = rand_mat(17, 9, by_row2, 4);
+
+                assert_eq!(a.added(&b)?, { let mut x = a.clone(); x.madd_speed_with(&ctx, &b)?; x });
+                assert_eq!(a.subtracted(&b)?, { let mut x = a.clone(); x.mminus_speed_with(&ctx, &b)?; x });
+                assert_eq!(a.ele_multiplied(&b)?, { let mut x = a.clone(); x.ele_mult_speed_with(&ctx, &b)?; x });
+                assert_eq!(a.ele_divided(&b)?, { let mut x = a.clone(); x.ele_div_speed_with(&ctx, &b)?; x });
+            }
+
+            // longer than one chunk
+            let v1 = Array::new_array_1d((0..10000).map(|i| i as f64).collect::<Vec<f64>>().into_boxed_slice());
+            let v2 = Array::new_array_1d((0..10000).map(|i| (i + 1) as f64).collect::<Vec<f64>>().into_boxed_slice());
+            let mut x = v1.clone();
+            x.madd_speed_with(&ctx, &v2)?;
+            assert_eq!(x, v1.added(&v2)?);
+
+            // broadcast falls back to serial
+            let row = Array::new_array_1d(Box::new([1.0, 2.0]));
+            let mut m = Array::new_array_2d(Box::new([1.0, 2.0, 3.0, 4.0]), (2, 2), true)?;
+            m.madd_speed_with(&ctx, &row)?;
+            assert_eq!(m, Array::new_array_2d(Box::new([2.0, 4.0, 4.0, 6.0]), (2, 2), true)?);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn element_wise_speed_errors() {
+        let mut a = Array::new_array_1d(Box::new([1.0, 2.0, 3.0]));
+        let b = Array::new_array_1d(Box::new([1.0, 0.0, 3.0]));
+        if let Err(error) = a.ele_div_speed(&b) {
+            assert_eq!(error, ListError::DivisionByZero)
+        };
+
+        let c = Array::new_array_1d(Box::new([1.0, 2.0]));
+        if let Err(error) = a.madd_speed(&c) {
+            assert_eq!(error, ListError::ShapeMismatch { lhs: vec![3], rhs: vec![2] })
+        };
+    }
+
+    #[test]
+    fn mlu_speed_same_as_mlu() -> Result<(), ListError> {
+        for ctx in contexts() {
+            for by_row in [true, false] {
+                let a = rand_mat(31, 31, by_row, 5);
+                let lu1 = a.mlu()?;
+                let lu2 = a.mlu_speed_with(&ctx)?;
+
+                assert_eq!(lu1.pivots(), lu2.pivots());
+                assert_eq!(lu1.det(), lu2.det());
+                assert!(Array::compute_dist(&lu1.inverse()?, &lu2.inverse()?)? < 1e-10);
+            }
+        }
+
+        if let Err(error) = rand_mat(3, 2, true, 0).mlu_speed() {
+            assert_eq!(error, ListError::NotSquareMat)
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn mqr_householder_speed_same_as_serial() -> Result<(), ListError> {
+        for ctx in contexts() {
+            for by_row in [true, false] {
+                let a = rand_mat(29, 17, by_row, 6);
+                let (q1, r1) = a.mqr_householder()?;
+                let (q2, r2) = a.mqr_householder_speed_with(&ctx)?;
+
+                assert_eq!(q1, q2);
+                assert!(Array::compute_dist(&r1, &r2)? < 1e-12);
+            }
+        }
+
+        if let Err(error) = rand_mat(2, 3, true, 0).mqr_householder_speed() {
+            assert_eq!(error, ListError::MatrixQRHouseHolderDimError)
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn meig_speed_same_as_meig() -> Result<(), ListError> {
+        for ctx in contexts() {
+            for by_row in [true, false] {
+                let a = rand_mat(12, 12, by_row, 7);
+                let e1 = a.meig()?;
+                let e2 = a.meig_speed_with(&ctx)?;
+
+                assert_eq!(e1.values, e2.values);
+                assert_eq!(e1.values_im, e2.values_im);
+                assert!(Array::compute_dist(&e1.vectors, &e2.vectors)? < 1e-10);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn meig_speed_conjugate_pairs() -> Result<(), ListError> {
+        // spectrum +-i, +-2i
+        let a = Array::new_array_2d(
+            Box::new([
+                0.0, -1.0, 0.0,  0.0,
+                1.0,  0.0, 0.0,  0.0,
+                0.0,  0.0, 0.0, -2.0,
+                0.0,  0.0, 2.0,  0.0,
+            ]),
+            (4, 4),
+            true
+        )?;
+
+        for ctx in contexts() {
+            let e1 = a.meig()?;
+            let e2 = a.meig_speed_with(&ctx)?;
+
+            assert_eq!(e1.values_im, e2.values_im);
+            assert!(Array::compute_dist(&e1.vectors, &e2.vectors)? < 1e-10);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+mod common;
+
+#[cfg(test)]
+pub mod tests {
+    use crate::common::{close, mat, vec1};
+    use simple_blas::array::{Array, ListError, Summation};
+
+    #[test]
+    fn reductions_both_layouts() -> Result<(), ListError> {
+        let f = |r: usize, c: usize| (r * 4 + c) as f64 - 5.0;
+        for by_row in [true, false] {
+            let a = mat(3, 4, by_row, f);
+
+            assert_eq!(a.sum(None)?, Array::new_scalar(6.0));
+            assert_eq!(a.sum(Some(0))?, Array::new_array_1d(Box::new([-3.0, 0.0, 3.0, 6.0])));
+            assert_eq!(a.sum(Some(1))?, Array::new_array_1d(Box::new([-14.0, 2.0, 18.0])));
+            assert_eq!(a.sum_with(Some(1), Summation::Kahan)?, a.sum(Some(1))?);
+
+            assert_eq!(a.prod(Some(1))?, Array::new_array_1d(Box::new([120.0, 0.0, 360.0])));
+            assert_eq!(a.min(None)?, Array::new_scalar(-5.0));
+            assert_eq!(a.max(Some(0))?, Array::new_array_1d(Box::new([3.0, 4.0, 5.0, 6.0])));
+            assert_eq!(a.min(Some(1))?, Array::new_array_1d(Box::new([-5.0, -1.0, 3.0])));
+
+            assert!(close(&a.mean(None)?, &Array::new_scalar(0.5)));
+            assert!(close(&a.mean(Some(0))?, &Array::new_array_1d(Box::new([-1.0, 0.0, 1.0, 2.0]))));
+            // each column is x - 4, x, x + 4
+            assert!(close(&a.var(Some(0))?, &Array::new_array_1d(Box::new([32.0 / 3.0; 4]))));
+            assert!(close(&a.std(Some(1))?, &Array::new_array_1d(Box::new([1.25_f64.sqrt(); 3]))));
+            assert!(close(&a.var(None)?, &Array::new_scalar((0..12).map(|x| (x as f64 - 5.5).powi(2)).sum::<f64>() / 12.0)));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn arg_and_cumulative() -> Result<(), ListError> {
+        let f = |r: usize, c: usize| [[3.0, 1.0, 4.0], [1.0, 5.0, 9.0]][r][c];
+        for by_row in [true, false] {
+            let a = mat(2, 3, by_row, f);
+
+            // first of the ties, index in row-major order
+            assert_eq!(a.argmin(None)?, Array::new_scalar(1));
+            assert_eq!(a.argmax(None)?, Array::new_scalar(5));
+            assert_eq!(a.argmin(Some(0))?, Array::new_array_1d(Box::new([1, 0, 0])));
+            assert_eq!(a.argmax(Some(1))?, Array::new_array_1d(Box::new([2, 2])));
+
+            assert_eq!(a.cumsum(None)?, Array::new_array_1d(Box::new([3.0, 4.0, 8.0, 9.0, 14.0, 23.0])));
+            assert_eq!(a.cumprod(None)?, Array::new_array_1d(Box::new([3.0, 3.0, 12.0, 12.0, 60.0, 540.0])));
+
+            // along an axis the layout is kept
+            let c = a.cumsum(Some(0))?;
+            assert_eq!(c, mat(2, 3, true, |r, col| if r == 0 {f(0, col)} else {f(0, col) + f(1, col)}));
+            assert_eq!(c.shape(), vec![2, 3]);
+            if let Array::Array2D { put_val_by_row, .. } = c {
+                assert_eq!(put_val_by_row, by_row);
+            }
+            assert_eq!(a.cumprod(Some(1))?, mat(2, 3, true, |r, col| (0..=col).map(|k| f(r, k)).product()));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn summation_accuracy() -> Result<(), ListError> {
+        // 0.1 is not exact, the plain loop drifts
+        let n: usize = 100_000;
+        let a = vec1(n, |_| 0.1);
+        let naive: f64 = (0..n).fold(0.0, |s, _| s + 0.1);
+        let exact: f64 = 10_000.0;
+
+        let pairwise = match a.sum(None)? {Array::Scalar(x) => x, _ => f64::NAN};
+        let kahan = match a.sum_with(None, Summation::Kahan)? {Array::Scalar(x) => x, _ => f64::NAN};
+        assert!((pairwise - exact).abs() < (naive - exact).abs());
+        assert!((kahan - exact).abs() <= 1e-9);
+        assert!((naive - exact).abs() > 1e-9);
+
+        // cumsum is compensated as well
+        if let Array::Array1D { arr } = a.cumsum(None)? {
+            assert!((arr[n - 1] - exact).abs() <= 1e-9);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn scalar_1d_and_nd() -> Result<(), ListError> {
+        let s = Array::new_scalar(2.5);
+        assert_eq!(s.sum(None)?, Array::new_scalar(2.5));
+        assert_eq!(s.argmax(None)?, Array::new_scalar(0));
+
+        let x = Array::new_array_1d(Box::new([2.0, -1.0, 7.0]));
+        assert_eq!(x.sum(Some(0))?, Array::new_scalar(8.0));
+        assert_eq!(x.cumsum(Some(0))?, Array::new_array_1d(Box::new([2.0, 1.0, 8.0])));
+
+        // integers work for the plain reductions
+        let i = Array::new_array_2d(Box::new([1, 2, 3, 4]), (2, 2), false)?;
+        assert_eq!(i.prod(None)?, Array::new_scalar(24));
+        assert_eq!(i.max(Some(1))?, Array::new_array_1d(Box::new([3, 4])));
+
+        let mut nd = Array::new_array_nd((0..24).map(|x| x as f64).collect::<Vec<f64>>().into_boxed_slice(), &[2, 3, 4], true)?;
+        nd.permute_axes(&[1, 2, 0])?;
+        let m = nd.mean(Some(2))?;
+        assert_eq!(m.shape(), vec![3, 4]);
+        assert_eq!(m[[1, 2]], 6.0 + 6.0);
+        assert_eq!(nd.argmax(None)?, Array::new_scalar(23));
+
+        Ok(())
+    }
+
+    #[test]
+    fn stats_out_of_bounds() {
+        let a = mat(2, 3, true, |r, c| (r + c) as f64);
+        if let Err(error) = a.sum(Some(2)) {
+            assert_eq!(error, ListError::OutOfBounds)
+        };
+    }
+
+    #[test]
+    fn stats_null() {
+        if let Err(error) = Array::<f64>::new_null().max(None) {
+            assert_eq!(error, ListError::MismatchedTypes)
+        };
+    }
+
+    #[test]
+    fn stats_empty_array() {
+        let empty = mat(0, 3, true, |_, _| 0.0);
+        assert_eq!(empty.sum(Some(0)).unwrap(), Array::new_array_1d(Box::new([0.0; 3])));
+        if let Err(error) = empty.mean(None) {
+            assert_eq!(error, ListError::EmptyArray)
+        };
+        if let Err(error) = empty.argmin(Some(0)) {
+            assert_eq!(error, ListError::EmptyArray)
+        };
+    }
+}
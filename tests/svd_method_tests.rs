@@ -0,0 +1,226 @@
+#[cfg(test)]
+pub mod tests {
+    use simple_blas::array::{Array, ListError, Norm, Svd};
+
+    fn dim(mat: &Array<f64>) -> (usize, usize) {
+        match mat {
+            Array::Array2D { nr, nc, .. } => (*nr, *nc),
+            _ => panic!("not a matrix"),
+        }
+    }
+
+    // max |A - U S V'|
+    fn reconstruct_error(arr: &Array<f64>, svd: &Svd<f64>) -> f64 {
+        let (nr, nc) = dim(arr);
+        let k: usize = if nr < nc {nr} else {nc};
+
+        let mut err: f64 = 0.0;
+        for r in 0..nr {
+            for c in 0..nc {
+                let mut sum: f64 = 0.0;
+                for i in 0..k {
+                    sum += svd.u[(r, i)] * svd.s[i] * svd.vt[(i, c)];
+                }
+                err = err.max((arr[(r, c)] - sum).abs());
+            }
+        }
+        err
+    }
+
+    // max |M'M - I| for columns (or rows) of M
+    fn orthogonal_error(mat: &Array<f64>, by_column: bool) -> f64 {
+        let (nr, nc) = dim(mat);
+        let (n, k) = if by_column {(nr, nc)} else {(nc, nr)};
+        let at = |i: usize, j: usize| if by_column {mat[(i, j)]} else {mat[(j, i)]};
+
+        let mut err: f64 = 0.0;
+        for i in 0..k {
+            for j in 0..k {
+                let mut dot: f64 = 0.0;
+                for r in 0..n {
+                    dot += at(r, i) * at(r, j);
+                }
+                let real: f64 = if i == j {1.0} else {0.0};
+                err = err.max((dot - real).abs());
+            }
+        }
+        err
+    }
+
+    #[test]
+    fn msvd_not_arr_2d() {
+        let arr: Array<f64> = Array::new_scalar(1.0);
+        if let Err(error) = arr.msvd() {
+            assert_eq!(error, ListError::MismatchedTypes);
+        };
+    }
+
+    #[test]
+    fn msvd_arr_2d_square() -> Result<(), ListError> {
+        for by_row in [true, false] {
+            let arr: Array<f64> = Array::new_array_2d(
+                Box::new([
+                    1.11, 22.18, 5.78,
+                    3.34, 4.83, 11.49,
+                    -1.23, 14.07, 19.37
+                ]),
+                (3, 3),
+                by_row
+            )?;
+
+            let svd = arr.msvd()?;
+            println!("{:?}", svd);
+
+            // singular values are descending and non-negative
+            assert!(svd.s[0] >= svd.s[1] && svd.s[1] >= svd.s[2] && svd.s[2] >= 0.0);
+
+            // |det| = product of singular values
+            let det: f64 = arr.mdet()?;
+            assert!((det.abs() - svd.s[0] * svd.s[1] * svd.s[2]).abs() < 1e-8);
+
+            assert!(reconstruct_error(&arr, &svd) < 1e-10);
+            assert!(orthogonal_error(&svd.u, true) < 1e-10);
+            assert!(orthogonal_error(&svd.vt, false) < 1e-10);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn msvd_arr_2d_tall() -> Result<(), ListError> {
+        let arr: Array<f64> = Array::new_array_2d(
+            Box::new([
+                1.0, 1.9,
+                2.0, 2.1,
+                3.0, 3.3,
+                4.0, 4.5,
+            ]),
+            (4, 2),
+            true
+        )?;
+
+        let svd = arr.msvd()?;
+        assert_eq!((dim(&svd.u), dim(&svd.vt)), ((4, 2), (2, 2)));
+        assert!(reconstruct_error(&arr, &svd) < 1e-10);
+        assert!(orthogonal_error(&svd.u, true) < 1e-10);
+        assert!(orthogonal_error(&svd.vt, false) < 1e-10);
+
+        let svd = arr.msvd_full()?;
+        assert_eq!((dim(&svd.u), dim(&svd.vt)), ((4, 4), (2, 2)));
+        assert!(reconstruct_error(&arr, &svd) < 1e-10);
+        assert!(orthogonal_error(&svd.u, true) < 1e-10);
+
+        Ok(())
+    }
+
+    #[test]
+    fn msvd_arr_2d_wide() -> Result<(), ListError> {
+        let arr: Array<f64> = Array::new_array_2d(
+            Box::new([
+                1.01, 2.02, 3.03,
+                -4.05, 5.01, 6.97
+            ]),
+            (2, 3),
+            false
+        )?;
+
+        let svd = arr.msvd()?;
+        assert_eq!((dim(&svd.u), dim(&svd.vt)), ((2, 2), (2, 3)));
+        assert!(reconstruct_error(&arr, &svd) < 1e-10);
+        assert!(orthogonal_error(&svd.u, true) < 1e-10);
+        assert!(orthogonal_error(&svd.vt, false) < 1e-10);
+
+        let svd = arr.msvd_full()?;
+        assert_eq!((dim(&svd.u), dim(&svd.vt)), ((2, 2), (3, 3)));
+        assert!(reconstruct_error(&arr, &svd) < 1e-10);
+        assert!(orthogonal_error(&svd.vt, false) < 1e-10);
+
+        Ok(())
+    }
+
+    #[test]
+    fn msvd_arr_2d_rank_deficient() -> Result<(), ListError> {
+        // rank 2, third column = first + second
+        let arr: Array<f64> = Array::new_array_2d(
+            Box::new([
+                1.0, 2.0, 3.0,
+                4.0, 5.0, 9.0,
+                7.0, 8.0, 15.0,
+                1.0, 0.0, 1.0,
+            ]),
+            (4, 3),
+            true
+        )?;
+
+        let svd = arr.msvd()?;
+        println!("{:?}", svd.s);
+        assert!(svd.s[1] > 1e-3);
+        assert!(svd.s[2] < 1e-10);
+        assert!(reconstruct_error(&arr, &svd) < 1e-10);
+        assert!(orthogonal_error(&svd.u, true) < 1e-10);
+        assert!(orthogonal_error(&svd.vt, false) < 1e-10);
+
+        Ok(())
+    }
+
+    #[test]
+    fn msvd_not_finite() -> Result<(), ListError> {
+        for v in [f64::NAN, f64::INFINITY] {
+            let arr: Array<f64> = Array::new_array_2d(Box::new([1.0, v, 0.0, 2.0]), (2, 2), true)?;
+
+            // every method on top of the svd
+            let errors: Vec<Option<ListError>> = vec![
+                arr.msvd().err(),
+                arr.mpinv(None).err(),
+                arr.mcond(Norm::L2).err(),
+                arr.norm(Norm::Nuclear).err(),
+                arr.norm(Norm::Spectral).err(),
+            ];
+            for error in errors {
+                assert_eq!(error, Some(ListError::SvdNotConverged));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn msvd_arr_2d_zero() -> Result<(), ListError> {
+        let arr: Array<f64> = Array::new_array_2d(Box::new([0.0; 6]), (3, 2), true)?;
+
+        let svd = arr.msvd()?;
+        assert_eq!((svd.s[0], svd.s[1]), (0.0, 0.0));
+        assert!(orthogonal_error(&svd.u, true) < 1e-10);
+        assert!(orthogonal_error(&svd.vt, false) < 1e-10);
+
+        Ok(())
+    }
+
+    #[test]
+    fn msvd_arr_2d_zero_diagonal() -> Result<(), ListError> {
+        // shift matrix, zero on the diagonal of bidiagonal form
+        let arr: Array<f64> = Array::new_array_2d(
+            Box::new([
+                0.0, 1.0, 0.0,
+                0.0, 0.0, 2.0,
+                0.0, 0.0, 0.0,
+            ]),
+            (3, 3),
+            true
+        )?;
+
+        let svd = arr.msvd()?;
+        let real: [f64; 3] = [2.0, 1.0, 0.0];
+        for (i, val) in real.iter().enumerate() {
+            assert!((svd.s[i] - val).abs() < 1e-10);
+        }
+        assert!(reconstruct_error(&arr, &svd) < 1e-10);
+
+        let arr: Array<f64> = Array::new_array_2d(Box::new([-5.0]), (1, 1), true)?;
+        let svd = arr.msvd()?;
+        assert_eq!(svd.s[0], 5.0);
+        assert!(reconstruct_error(&arr, &svd) < 1e-10);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+pub mod tests {
+    use simple_blas::array::{Array, ListError};
+    use std::thread;
+
+    fn mat(v: [f64; 4], by_row: bool) -> Array<f64> {
+        Array::new_array_2d(Box::new(v), (2, 2), by_row).unwrap()
+    }
+
+    #[test]
+    fn value_methods_keep_inputs() -> Result<(), ListError> {
+        let a = mat([1.0, 2.0, 3.0, 4.0], true);
+        let b = mat([2.0, 0.0, 1.0, 2.0], false);
+
+        assert_eq!(a.added(&b)?, mat([3.0, 3.0, 3.0, 6.0], true));
+        assert_eq!(a.subtracted(&b)?, mat([-1.0, 1.0, 3.0, 2.0], true));
+        assert_eq!(a.ele_multiplied(&b)?, mat([2.0, 2.0, 0.0, 8.0], true));
+        assert_eq!(a.ele_divided(&mat([1.0, 2.0, 3.0, 4.0], false))?, mat([1.0, 2.0 / 3.0, 1.5, 1.0], true));
+        assert_eq!(a.scaled(0.5)?, mat([0.5, 1.0, 1.5, 2.0], true));
+        assert_eq!(a.transposed()?, mat([1.0, 3.0, 2.0, 4.0], true));
+
+        // b = [[2, 1], [0, 2]]
+        assert_eq!(a.matmul(&b)?, mat([2.0, 5.0, 6.0, 11.0], true));
+
+        let a_inv = a.inverse()?;
+        let d: f64 = Array::compute_dist(&a_inv, &mat([-2.0, 1.0, 1.5, -0.5], true))?;
+        assert!(d < 1e-10);
+
+        // nothing changed
+        assert_eq!(a, mat([1.0, 2.0, 3.0, 4.0], true));
+        assert_eq!(b, mat([2.0, 1.0, 0.0, 2.0], true));
+
+        Ok(())
+    }
+
+    #[test]
+    fn value_methods_errors() {
+        let a = mat([1.0, 2.0, 2.0, 4.0], true);
+        assert_eq!(a.inverse(), Err(ListError::SingularMat));
+
+        let v: Array<f64> = Array::new_array_1d(Box::new([1.0, 2.0, 3.0]));
+        assert_eq!(a.matmul(&v), Err(ListError::MismatchedTypes));
+        assert_eq!(v.transposed(), Err(ListError::MismatchedTypes));
+        assert_eq!(a.added(&v), Err(ListError::ShapeMismatch { lhs: vec![2, 2], rhs: vec![3] }));
+    }
+
+    #[test]
+    fn value_methods_shared_across_threads() -> Result<(), ListError> {
+        let a = mat([1.0, 2.0, 3.0, 4.0], true);
+        let b = mat([0.0, 1.0, 1.0, 0.0], true);
+
+        let (ab, a_inv) = thread::scope(|s| {
+            let h1 = s.spawn(|| a.matmul(&b));
+            let h2 = s.spawn(|| a.inverse());
+            (h1.join().unwrap(), h2.join().unwrap())
+        });
+
+        assert_eq!(ab?, mat([2.0, 1.0, 4.0, 3.0], true));
+        let d: f64 = Array::compute_dist(&a_inv?.matmul(&a)?, &mat([1.0, 0.0, 0.0, 1.0], true))?;
+        assert!(d < 1e-10);
+
+        Ok(())
+    }
+}
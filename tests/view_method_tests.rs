@@ -0,0 +1,216 @@
+mod common;
+
+#[cfg(test)]
+pub mod tests {
+    use crate::common::{close, mat};
+    use simple_blas::array::{Array, ListError, Norm, Transpose};
+
+    #[test]
+    fn row_col_slice_diag_t() -> Result<(), ListError> {
+        let f = |r: usize, c: usize| (r * 10 + c) as f64;
+        for by_row in [true, false] {
+            let a = mat(3, 4, by_row, f);
+            let v = a.view()?;
+            assert_eq!(v.shape(), vec![3, 4]);
+
+            assert_eq!(v.row(2)?.to_array(), mat(1, 4, true, |_, c| f(2, c)));
+            assert_eq!(v.col(1)?.to_array(), mat(3, 1, true, |r, _| f(r, 1)));
+            assert_eq!(v.slice((1..3, 1..4))?.to_array(), mat(2, 3, true, |r, c| f(r + 1, c + 1)));
+            assert_eq!(v.diag().to_array(), mat(3, 1, true, |r, _| f(r, r)));
+            assert_eq!(v.t().to_array(), mat(4, 3, true, |r, c| f(c, r)));
+
+            // views of views
+            let s = v.slice((1..3, 1..4))?;
+            assert_eq!(s.t().row(2)?.to_array(), mat(1, 2, true, |_, c| f(c + 1, 3)));
+            assert_eq!(s.diag()[(1, 0)], f(2, 2));
+            assert_eq!(s.slice((0..0, 0..2))?.shape(), vec![0, 2]);
+        }
+
+        // Array1D is a single row
+        let x = Array::new_array_1d(Box::new([1.0, 2.0, 3.0]));
+        assert_eq!(x.view()?.shape(), vec![1, 3]);
+        assert_eq!(x.view()?.col(2)?[(0, 0)], 3.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn view_mut_arithmetic() -> Result<(), ListError> {
+        for by_row in [true, false] {
+            let mut a = mat(3, 3, by_row, |r, c| (r * 3 + c) as f64);
+            let b = mat(2, 2, !by_row, |r, c| (r + c) as f64 + 1.0);
+            let vb = b.view()?;
+            {
+                let mut v = a.view_mut()?;
+                v.row_mut(0)?.add(100.0);
+                v.col_mut(2)?.mult(2.0);
+                v.diag_mut()[(1, 0)] = -1.0;
+                v.slice_mut((1..3, 0..2))?.madd(&vb)?;
+            }
+            let expect = mat(3, 3, true, |r, c| {
+                let mut x: f64 = (r * 3 + c) as f64;
+                if r == 0 {x += 100.0;}
+                if c == 2 {x *= 2.0;}
+                if (r, c) == (1, 1) {x = -1.0;}
+                if r >= 1 && c < 2 {x += (r - 1 + c) as f64 + 1.0;}
+                x
+            });
+            assert_eq!(a, expect);
+
+            // transposed block of itself
+            let mut c = mat(2, 2, by_row, |r, c| (r * 2 + c) as f64);
+            let d = c.clone();
+            let vd = d.view()?;
+            let mut vc = c.view_mut()?;
+            vc.t_mut().mminus(&vd)?;
+            vc.ele_mult(&vd)?;
+            vc.slice_mut((0..2, 1..2))?.ele_div(&vd.col(1)?)?;
+            vc.row_mut(0)?.assign(&vd.row(1)?)?;
+            assert_eq!(c, mat(2, 2, true, |r, col| if r == 0 {(2 + col) as f64} else if col == 0 {2.0} else {0.0}));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn gemm_and_matmul_on_views() -> Result<(), ListError> {
+        for by_row in [true, false] {
+            let a = mat(4, 5, by_row, |r, c| r as f64 - c as f64 * 0.5);
+            let b = mat(5, 3, !by_row, |r, c| (r * 3 + c) as f64 * 0.25);
+            let (va, vb) = (a.view()?, b.view()?);
+
+            // 2 x 3 block of A times a 3 x 2 block of B
+            let sa = va.slice((1..3, 2..5))?;
+            let sb = vb.slice((0..3, 1..3))?;
+            let expect = mat(2, 2, true, |r, c| (0..3).map(|p| a[(r + 1, p + 2)] * b[(p, c + 1)]).sum());
+            assert!(close(&sa.matmul(&sb)?, &expect));
+
+            // written into the middle of C, the rest is untouched
+            let mut c = mat(4, 4, by_row, |_, _| 1.0);
+            c.view_mut()?.slice_mut((1..3, 2..4))?
+                .gemm(2.0, &sb, Transpose::Trans, &sa, Transpose::Trans, 1.0)?;
+            let full = mat(4, 4, true, |r, col| {
+                if (1..3).contains(&r) && (2..4).contains(&col) {2.0 * expect[(col - 2, r - 1)] + 1.0} else {1.0}
+            });
+            assert!(close(&c, &full));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn vec_view_of_row_and_col() -> Result<(), ListError> {
+        for by_row in [true, false] {
+            let a = mat(3, 3, by_row, |r, c| (r * 3 + c) as f64);
+            let v = a.view()?;
+            // row 1 . col 2 = 3 * 2 + 4 * 5 + 5 * 8
+            let x = v.row(1)?.vec_view()?;
+            let y = v.col(2)?.vec_view()?;
+            assert_eq!(x.dot(&y)?, 66.0);
+            assert_eq!(v.diag().vec_view()?.asum(), 12.0);
+
+            if let Err(error) = v.vec_view() {
+                assert_eq!(error, ListError::MismatchedTypes)
+            };
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn factorizations_accept_views() -> Result<(), ListError> {
+        let f = |r: usize, c: usize| if r == c {4.0 + r as f64} else {(r + c) as f64 * 0.5};
+        for by_row in [true, false] {
+            // the inner 3 x 3 block is the matrix
+            let big = mat(5, 5, by_row, |r, c| if (1..4).contains(&r) && (1..4).contains(&c) {f(r - 1, c - 1)} else {99.0});
+            let v = big.view()?.slice((1..4, 1..4))?;
+            let a = mat(3, 3, true, f);
+
+            assert!((v.mdet()? - a.mdet()?).abs() < 1e-9);
+            assert!(close(&v.inverse()?, &a.inverse()?));
+            assert!(close(&v.mcholesky()?, &a.mcholesky()?));
+            let (mut q, r) = v.mqr()?;
+            q.mmult(&r)?;
+            assert!(close(&q, &a));
+            assert!((v.norm(Norm::Frobenius)? - a.norm(Norm::Frobenius)?).abs() < 1e-9);
+
+            let b = mat(3, 1, true, |r, _| r as f64 + 1.0);
+            assert!(close(&v.msolve(&b)?, &a.msolve(&b)?));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn view_row_out_of_bounds() {
+        let a = mat(2, 3, true, |r, c| (r + c) as f64);
+        let v = a.view().unwrap();
+
+        if let Err(error) = v.row(2) {
+            assert_eq!(error, ListError::OutOfBounds)
+        };
+    }
+
+    #[test]
+    fn view_slice_out_of_bounds() {
+        let a = mat(2, 3, true, |r, c| (r + c) as f64);
+        let v = a.view().unwrap();
+
+        if let Err(error) = v.slice((0..1, 2..4)) {
+            assert_eq!(error, ListError::OutOfBounds)
+        };
+    }
+
+    #[test]
+    fn view_matmul_mismatched_dim() {
+        let a = mat(2, 3, true, |r, c| (r + c) as f64);
+        let v = a.view().unwrap();
+
+        assert_eq!(v.matmul(&v), Err(ListError::ShapeMismatch { lhs: vec![2, 3], rhs: vec![2, 3] }));
+    }
+
+    #[test]
+    fn view_madd_shape_mismatch() {
+        let a = mat(2, 3, true, |r, c| (r + c) as f64);
+        let v = a.view().unwrap();
+        let mut b = a.clone();
+        let mut vb = b.view_mut().unwrap();
+
+        if let Err(error) = vb.madd(&v.t()) {
+            assert_eq!(error, ListError::ShapeMismatch { lhs: vec![2, 3], rhs: vec![3, 2] })
+        };
+    }
+
+    #[test]
+    fn view_ele_div_by_zero() {
+        let a = mat(2, 3, true, |r, c| (r + c) as f64);
+        let v = a.view().unwrap();
+        let mut b = a.clone();
+        let mut vb = b.view_mut().unwrap();
+
+        if let Err(error) = vb.ele_div(&v) {
+            assert_eq!(error, ListError::DivisionByZero)
+        };
+    }
+
+    #[test]
+    fn view_gemm_mismatched_dim() {
+        let a = mat(2, 3, true, |r, c| (r + c) as f64);
+        let v = a.view().unwrap();
+        let mut b = a.clone();
+        let mut vb = b.view_mut().unwrap();
+
+        assert_eq!(
+            vb.gemm(1.0, &v, Transpose::NoTrans, &v, Transpose::NoTrans, 0.0),
+            Err(ListError::ShapeMismatch { lhs: vec![2, 3], rhs: vec![2, 3] })
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_view_panics() {
+        let a = mat(3, 3, true, |r, c| (r + c) as f64);
+        let v = a.view().unwrap().slice((0..2, 0..2)).unwrap();
+        let _ = v[(2, 0)];
+    }
+}